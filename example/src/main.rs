@@ -83,10 +83,7 @@ fn main() -> Result<(), PanicOnError> {
     let fields: Vec<Field> =
         SerdeArrowSchema::from_samples(&examples, TracingOptions::default().guess_dates(true))?
             .try_into()?;
-    let arrays = serde_arrow::to_arrow2(&fields, &examples)?;
-
-    let schema = Schema::from(fields);
-    let chunk = Chunk::new(arrays);
+    let (schema, chunk) = serde_arrow::to_arrow2_chunk(&fields, &examples)?;
 
     write_batches("example.ipc", schema, &[chunk])?;
 
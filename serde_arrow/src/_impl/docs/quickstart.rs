@@ -115,6 +115,9 @@
 //! # #[cfg(not(has_arrow))] fn main() { }
 //! ```
 //!
+//! Decimals with a precision of more than 38 digits can be stored using the
+//! `Decimal256(precision, scale)` data type.
+//!
 //! ## Dictionary encoding for strings
 //!
 //! To encode strings with repeated values via a dictionary, the data type of
@@ -187,6 +190,15 @@
 //! - `type = 1`: `Struct { 0: u32, 1: u32 }`
 //! - `type = 2`: `Struct { a: f32, b: f32 }`
 //!
+//! Optional enums, e.g., `Option<MyEnum>`, are mapped to nullable unions with
+//! an additional trailing child `null` of type `Null` that encodes missing
+//! values. This child is marked with the `NullVariant` strategy, which
+//! distinguishes it from unit variants that are also mapped to `Null` children.
+//!
+//! Traced unions are dense. To write sparse unions, where each child array has
+//! the length of the union, set the data type of the field to
+//! `"Union(Sparse)"`. Both modes are supported in deserialization.
+//!
 //! ## Convert from arrow2 to arrow arrays
 //!
 //! Both `arrow` and `arrow2` use the Arrow memory format. Hence, it is possible
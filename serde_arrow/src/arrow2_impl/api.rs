@@ -5,8 +5,13 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    _impl::arrow2::{array::Array, datatypes::Field},
+    _impl::arrow2::{
+        array::Array,
+        chunk::Chunk,
+        datatypes::{Field, Schema},
+    },
     internal::{
+        batching::{BatchBackend, BatchBuilder},
         error::Result,
         schema::{GenericField, SerdeArrowSchema},
         serialization_ng::ArrayBuilder,
@@ -58,7 +63,10 @@ use crate::{
 /// # Ok(())
 /// # }
 /// ```
-pub struct Arrow2Builder(ArrayBuilder);
+pub struct Arrow2Builder {
+    fields: Vec<Field>,
+    builder: ArrayBuilder,
+}
 
 impl std::fmt::Debug for Arrow2Builder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -74,19 +82,22 @@ impl Arrow2Builder {
     ///
     pub fn new(fields: &[Field]) -> Result<Self> {
         let schema = SerdeArrowSchema::from_arrow2_fields(fields)?;
-        Ok(Self(ArrayBuilder::new(&schema)?))
+        Ok(Self {
+            fields: fields.to_vec(),
+            builder: ArrayBuilder::new(&schema)?,
+        })
     }
 
     /// Add a single record to the arrays
     ///
     pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
-        self.0.extend(&[item])
+        self.builder.extend(&[item])
     }
 
     /// Add multiple records to the arrays
     ///
     pub fn extend<T: Serialize + ?Sized>(&mut self, items: &T) -> Result<()> {
-        self.0.extend(items)
+        self.builder.extend(items)
     }
 
     /// Build the arrays from the rows pushed to far.
@@ -94,7 +105,168 @@ impl Arrow2Builder {
     /// This operation will reset the underlying buffers and start a new batch.
     ///
     pub fn build_arrays(&mut self) -> Result<Vec<Box<dyn Array>>> {
-        self.0.build_arrow2_arrays()
+        self.builder.build_arrow2_arrays()
+    }
+
+    /// Build a chunk from the rows pushed to far, together with its schema.
+    ///
+    /// The schema is built from the fields passed to [`Arrow2Builder::new`].
+    /// Both can be passed directly to the arrow2 IO writers. This operation
+    /// will reset the underlying buffers and start a new batch.
+    ///
+    pub fn build_chunk(&mut self) -> Result<(Schema, Chunk<Box<dyn Array>>)> {
+        let chunk = Chunk::try_new(self.build_arrays()?)?;
+        Ok((Schema::from(self.fields.clone()), chunk))
+    }
+}
+
+/// Build arrow2 chunks with a limited number of rows or bytes (*requires one of
+/// the `arrow2-*` features*)
+///
+/// The batching mode of [`Arrow2Builder`]: records are pushed one by one and a
+/// chunk is completed, whenever the number of rows or the approximate number of
+/// bytes reach the configured limits. Completed chunks are either returned from
+/// [`push`][Arrow2BatchBuilder::push] and [`extend`][Arrow2BatchBuilder::extend]
+/// or passed to the callback set via
+/// [`with_callback`][Arrow2BatchBuilder::with_callback]. The remaining rows are
+/// built into a final chunk by [`flush`][Arrow2BatchBuilder::flush].
+///
+/// The number of bytes is estimated from the buffers filled so far. It does
+/// not include any allocation overhead.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2 as arrow2;
+/// use arrow2::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::Arrow2BatchBuilder;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let mut builder = Arrow2BatchBuilder::new(&[
+///     Field::new("a", DataType::Float32, true),
+///     Field::new("b", DataType::UInt64, false),
+/// ])?
+/// .with_max_rows(2);
+///
+/// let mut chunks = Vec::new();
+/// for b in 0..5 {
+///     chunks.extend(builder.push(&Record { a: None, b })?);
+/// }
+/// chunks.extend(builder.flush()?);
+///
+/// # assert_eq!(chunks.len(), 3);
+/// # assert_eq!(chunks[0].len(), 2);
+/// # assert_eq!(chunks[2].len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Arrow2BatchBuilder<'a> {
+    inner: BatchBuilder<'a, Arrow2Builder>,
+}
+
+impl<'a> std::fmt::Debug for Arrow2BatchBuilder<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Arrow2BatchBuilder<...>")
+    }
+}
+
+impl<'a> Arrow2BatchBuilder<'a> {
+    /// Build a new Arrow2BatchBuilder for the given fields
+    ///
+    /// Without any limits, all records are collected until
+    /// [`flush`][Arrow2BatchBuilder::flush] is called.
+    ///
+    pub fn new(fields: &[Field]) -> Result<Self> {
+        Ok(Self {
+            inner: BatchBuilder::new(Arrow2Builder::new(fields)?),
+        })
+    }
+
+    /// Complete a chunk once it contains the given number of rows
+    ///
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.inner.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Complete a chunk once its buffers contain approximately the given
+    /// number of bytes
+    ///
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.inner.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Pass completed chunks to the callback instead of returning them
+    ///
+    /// The callback may borrow from its environment for the lifetime of the
+    /// builder.
+    ///
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(Chunk<Box<dyn Array>>) -> Result<()> + Send + 'a,
+    {
+        self.inner.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// The schema of the built chunks
+    ///
+    /// The schema is built from the fields passed to
+    /// [`Arrow2BatchBuilder::new`].
+    ///
+    pub fn schema(&self) -> Schema {
+        Schema::from(self.inner.backend.fields.clone())
+    }
+
+    /// Add a single record and return the completed chunks, if any
+    ///
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+        self.inner.extend([item])
+    }
+
+    /// Add multiple records and return the completed chunks, if any
+    ///
+    /// In contrast to [`Arrow2Builder::extend`], the records are given as an
+    /// iterator to allow completing chunks in between records. If a record
+    /// fails to serialize, the rows not yet completed into a chunk are
+    /// discarded. Chunks completed before the error are returned by the next
+    /// call to [`push`][Arrow2BatchBuilder::push],
+    /// [`extend`][Arrow2BatchBuilder::extend] or
+    /// [`flush`][Arrow2BatchBuilder::flush].
+    ///
+    pub fn extend<I>(&mut self, items: I) -> Result<Vec<Chunk<Box<dyn Array>>>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        self.inner.extend(items)
+    }
+
+    /// Build a chunk from the remaining rows and return all completed chunks
+    ///
+    /// Returns an empty vector if no rows were pushed since the last chunk or
+    /// if the chunks were passed to the callback.
+    ///
+    pub fn flush(&mut self) -> Result<Vec<Chunk<Box<dyn Array>>>> {
+        self.inner.flush()
+    }
+}
+
+impl BatchBackend for Arrow2Builder {
+    type Batch = Chunk<Box<dyn Array>>;
+
+    fn array_builder(&mut self) -> &mut ArrayBuilder {
+        &mut self.builder
+    }
+
+    fn build_batch(&mut self) -> Result<Chunk<Box<dyn Array>>> {
+        Ok(Chunk::try_new(self.build_arrays()?)?)
     }
 }
 
@@ -142,6 +314,49 @@ where
     builder.build_arrays()
 }
 
+/// Build an arrow2 chunk and its schema from the given items (*requires one of
+/// the `arrow2-*` features*)
+///
+/// The same as [`to_arrow2`], but the arrays are wrapped in a chunk and
+/// returned together with a schema built from the given fields. Both can be
+/// passed directly to the arrow2 IO writers.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2},
+///     // ...
+/// ];
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let (schema, chunk) = serde_arrow::to_arrow2_chunk(&fields, &items)?;
+///
+/// assert_eq!(schema.fields.len(), 2);
+/// assert_eq!(chunk.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_arrow2_chunk<T>(fields: &[Field], items: &T) -> Result<(Schema, Chunk<Box<dyn Array>>)>
+where
+    T: Serialize + ?Sized,
+{
+    let mut builder = Arrow2Builder::new(fields)?;
+    builder.extend(items)?;
+    builder.build_chunk()
+}
+
 /// Deserialize items from the given arrow2 arrays  (*requires* one of the
 /// `arrow2-*` features)
 ///
@@ -198,6 +413,100 @@ where
         mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
     }
 
-    let interpreter = deserialization::compile_deserialization(num_items, &mappings, buffers)?;
-    deserialize_from_source(interpreter)
+    let program = deserialization::compile_deserialization(num_items, &mappings, buffers)?;
+    let mut state = program.new_state();
+    deserialize_from_source(program.interpreter(&mut state))
+}
+
+/// Deserialize items from an arrow2 chunk with the given schema (*requires*
+/// one of the `arrow2-*` features)
+///
+/// The same as [`from_arrow2`], but the fields are taken from the schema, e.g.,
+/// as returned by the arrow2 IO readers.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let items = &[Record { a: Some(1.0), b: 2}];
+/// # let (schema, chunk) = serde_arrow::to_arrow2_chunk(&fields, &items)?;
+/// #
+/// let items: Vec<Record> = serde_arrow::from_arrow2_chunk(&schema, &chunk)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_chunk<'de, T, A>(schema: &'de Schema, chunk: &'de Chunk<A>) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    from_arrow2(&schema.fields, chunk.arrays())
+}
+
+/// Deserialize items from multiple arrow2 chunks with the given schema
+/// (*requires* one of the `arrow2-*` features)
+///
+/// The items of all chunks are deserialized as a single sequence, e.g., a
+/// single vector of records. The deserialization is only compiled once and
+/// reused for all chunks with the same layout.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow2;
+/// use arrow2::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let (schema, first) = serde_arrow::to_arrow2_chunk(&fields, &[Record { a: Some(1.0), b: 2}])?;
+/// # let (_, second) = serde_arrow::to_arrow2_chunk(&fields, &[Record { a: None, b: 4}])?;
+/// # let chunks = vec![first, second];
+/// #
+/// let items: Vec<Record> = serde_arrow::from_arrow2_chunks(&schema, &chunks)?;
+/// # assert_eq!(items.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow2_chunks<'de, T, A>(schema: &Schema, chunks: &'de [Chunk<A>]) -> Result<T>
+where
+    T: Deserialize<'de>,
+    A: AsRef<dyn Array>,
+{
+    use crate::internal::deserialization::{compile_chunks, ChunkedInterpreter};
+
+    let fields = schema
+        .fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>>>()?;
+
+    let programs = compile_chunks(
+        &fields,
+        chunks.iter().map(|chunk| {
+            chunk
+                .arrays()
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&dyn Array>>()
+        }),
+    )?;
+    deserialize_from_source(ChunkedInterpreter::new(&programs)?)
 }
@@ -1,19 +1,20 @@
 use crate::{
     _impl::arrow2::{
         array::{
-            Array, BooleanArray, DictionaryArray, ListArray, MapArray, PrimitiveArray, StructArray,
-            UnionArray, Utf8Array,
+            Array, BinaryArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray,
+            FixedSizeListArray, ListArray, MapArray, PrimitiveArray, StructArray, UnionArray,
+            Utf8Array,
         },
         datatypes::DataType,
-        types::f16,
+        types::{days_ms, f16, i256, months_days_ns},
     },
-    internal::common::{DictionaryIndex, DictionaryValue},
+    internal::common::{DictionaryIndex, DictionaryValue, IntervalLayout},
 };
 use crate::{
     internal::{
         common::{check_supported_list_layout, ArrayMapping, BitBuffer, BufferExtract, Buffers},
         error::{error, fail},
-        schema::{GenericDataType, GenericField},
+        schema::{GenericDataType, GenericField, GenericIntervalUnit},
     },
     Result,
 };
@@ -60,6 +61,25 @@ impl BufferExtract for dyn Array {
             }};
         }
 
+        macro_rules! convert_interval {
+            ($array_type:ty, $layout:ident, $push_func:ident) => {{
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$array_type>>()
+                    .ok_or_else(|| error!("cannot interpret array as interval array"))?;
+
+                let buffer = buffers.$push_func(typed.values().as_slice())?;
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                Ok(M::Interval {
+                    field: field.clone(),
+                    buffer,
+                    validity,
+                    layout: IntervalLayout::$layout,
+                })
+            }};
+        }
+
         macro_rules! convert_utf8 {
             ($offset_type:ty, $variant:ident, $push_func:ident) => {{
                 let typed = self
@@ -80,6 +100,26 @@ impl BufferExtract for dyn Array {
             }};
         }
 
+        macro_rules! convert_binary {
+            ($offset_type:ty, $variant:ident, $push_func:ident) => {{
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<BinaryArray<$offset_type>>()
+                    .ok_or_else(|| error!("cannot interpret array as Binary array"))?;
+
+                let buffer = buffers.push_u8(typed.values().as_slice());
+                let offsets = buffers.$push_func(typed.offsets().as_slice())?;
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                Ok(M::$variant {
+                    field: field.clone(),
+                    validity,
+                    buffer,
+                    offsets,
+                })
+            }};
+        }
+
         macro_rules! convert_list {
             ($offset_type:ty, $variant:ident, $push_func:ident) => {{
                 let Some(typed) = self.as_any().downcast_ref::<ListArray<$offset_type>>() else {
@@ -113,7 +153,7 @@ impl BufferExtract for dyn Array {
 
         match &field.data_type {
             T::Null => {
-                if !matches!(self.data_type(), DataType::Null) {
+                if !matches!(self.data_type().to_logical_type(), DataType::Null) {
                     fail!("non-null array with null field");
                 }
 
@@ -154,13 +194,64 @@ impl BufferExtract for dyn Array {
             T::F16 => convert_primitive!(f16, F16, push_u16_cast),
             T::F32 => convert_primitive!(f32, F32, push_u32_cast),
             T::F64 => convert_primitive!(f64, F64, push_u64_cast),
+            T::Date32 => convert_primitive!(i32, Date32, push_u32_cast),
             T::Date64 => convert_primitive!(i64, Date64, push_u64_cast),
+            T::Time32(_) => convert_primitive!(i32, Time32, push_u32_cast),
+            T::Time64(_) => convert_primitive!(i64, Time64, push_u64_cast),
+            T::Duration(_) => convert_primitive!(i64, Duration, push_u64_cast),
+            T::Interval(GenericIntervalUnit::YearMonth) => {
+                convert_interval!(i32, YearMonth, push_u32_cast)
+            }
+            T::Interval(GenericIntervalUnit::DayTime) => {
+                convert_interval!(days_ms, DayTime, push_u32_cast)
+            }
+            T::Interval(GenericIntervalUnit::MonthDayNano) => {
+                convert_interval!(months_days_ns, MonthDayNano, push_u64_cast)
+            }
             T::Decimal128(_, _) => convert_primitive!(i128, Decimal128, push_u128_cast),
+            T::Decimal256(_, _) => convert_primitive!(i256, Decimal256, push_u256_cast),
             T::Timestamp(_, _) => convert_primitive!(i64, Date64, push_u64_cast),
             T::Utf8 => convert_utf8!(i32, Utf8, push_u32_cast),
             T::LargeUtf8 => convert_utf8!(i64, LargeUtf8, push_u64_cast),
+            T::Binary => convert_binary!(i32, Binary, push_u32_cast),
+            T::LargeBinary => convert_binary!(i64, LargeBinary, push_u64_cast),
+            T::FixedSizeBinary(n) => {
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .ok_or_else(|| error!("cannot interpret array as FixedSizeBinary array"))?;
+
+                let buffer = buffers.push_u8(typed.values().as_slice());
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                Ok(M::FixedSizeBinary {
+                    field: field.clone(),
+                    validity,
+                    buffer,
+                    n: usize::try_from(*n)?,
+                })
+            }
             T::List => convert_list!(i32, List, push_u32_cast),
             T::LargeList => convert_list!(i64, LargeList, push_u64_cast),
+            T::FixedSizeList(n) => {
+                let Some(typed) = self.as_any().downcast_ref::<FixedSizeListArray>() else {
+                    fail!("cannot interpret array as FixedSizeList array");
+                };
+
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                let Some(item_field) = field.children.first() else {
+                    fail!("cannot get first child of fixed size list array")
+                };
+                let item = typed.values().extract_buffers(item_field, buffers)?;
+
+                Ok(M::FixedSizeList {
+                    field: field.clone(),
+                    item: Box::new(item),
+                    validity,
+                    n: usize::try_from(*n)?,
+                })
+            }
             T::Struct => {
                 let typed = self
                     .as_any()
@@ -242,31 +333,29 @@ impl BufferExtract for dyn Array {
                             .downcast_ref::<DictionaryArray<$key_type>>()
                             .ok_or_else(|| error!("cannot convert array into u32 dictionary"))?;
 
-                        // NOTE: the array is validity is given by the key validity
-                        if typed.values().null_count() != 0 {
-                            fail!("dictionaries with nullable values are not supported");
-                        }
-
                         let validity = get_validity(typed).map(|b| buffers.push_u1(b));
                         let keys =
                             (typed.keys() as &dyn Array).extract_buffers(keys_field, buffers)?;
 
-                        let M::$variant { buffer: index_buffer, .. } = keys else {
+                        let M::$variant {
+                            buffer: index_buffer,
+                            ..
+                        } = keys
+                        else {
                             fail!("internal error unexpected array mapping for keys")
                         };
 
                         let values = typed.values().extract_buffers(values_field, buffers)?;
 
-                        let dictionary = match values {
-                            M::Utf8 { buffer, offsets, .. } => DictionaryValue::Utf8{ buffer, offsets },
-                            M::LargeUtf8 { buffer, offsets, .. } => DictionaryValue::LargeUtf8{ buffer, offsets },
-                            m => fail!("BufferExtract for dictionaries with values of type {m:?} is not implemented"),
-                        };
+                        // NOTE: null values are resolved via the validity of the values, see EmitOptionDictionary
+                        let values_validity = values.get_validity();
+                        let dictionary = DictionaryValue::try_from_mapping(values)?;
                         Ok(M::Dictionary {
                             field: field.clone(),
                             validity,
                             dictionary,
                             indices: DictionaryIndex::$variant(index_buffer),
+                            values_validity,
                         })
                     }};
                 }
@@ -283,7 +372,8 @@ impl BufferExtract for dyn Array {
                     dt => fail!("BufferExtract for dictionaries with key {dt} is not implemented"),
                 }
             }
-            T::Union => {
+            T::RunEndEncoded => fail!("RunEndEncoded arrays are not supported by arrow2"),
+            T::Union(_) => {
                 // TODO: test assumptions
                 let typed = self
                     .as_any()
@@ -291,6 +381,10 @@ impl BufferExtract for dyn Array {
                     .ok_or_else(|| error!("cannot convert array to union array"))?;
 
                 let types = buffers.push_u8_cast(typed.types().as_slice())?;
+                let offsets = match typed.offsets() {
+                    Some(offsets) => Some(buffers.push_u32_cast(offsets.as_slice())?),
+                    None => None,
+                };
                 let mut fields = Vec::new();
                 for (field, array) in field.children.iter().zip(typed.fields()) {
                     fields.push(array.extract_buffers(field, buffers)?);
@@ -301,6 +395,7 @@ impl BufferExtract for dyn Array {
                     validity: None,
                     fields,
                     types,
+                    offsets,
                 })
             }
         }
@@ -1,10 +1,11 @@
 use crate::{
-    _impl::arrow2::datatypes::{DataType, Field, IntegerType, TimeUnit, UnionMode},
+    _impl::arrow2::datatypes::{DataType, Field, IntegerType, IntervalUnit, TimeUnit, UnionMode},
     internal::{
         error::{error, fail, Error, Result},
         schema::{
-            GenericDataType, GenericField, GenericTimeUnit, SchemaLike, Sealed, SerdeArrowSchema,
-            Strategy, STRATEGY_KEY,
+            is_reserved_metadata_key, GenericDataType, GenericField, GenericIntervalUnit,
+            GenericTimeUnit, GenericUnionMode, SchemaLike, Sealed, SerdeArrowSchema, Strategy,
+            EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, STRATEGY_KEY,
         },
     },
 };
@@ -89,8 +90,27 @@ impl TryFrom<&Field> for GenericField {
         let name = field.name.to_owned();
         let nullable = field.is_nullable;
 
+        let (data_type, extension_name, extension_metadata) = match &field.data_type {
+            DataType::Extension(name, data_type, metadata) => {
+                (data_type.as_ref(), Some(name.clone()), metadata.clone())
+            }
+            data_type => (
+                data_type,
+                field.metadata.get(EXTENSION_NAME_KEY).cloned(),
+                field.metadata.get(EXTENSION_METADATA_KEY).cloned(),
+            ),
+        };
+        let metadata = field
+            .metadata
+            .iter()
+            .filter(|(key, _)| !is_reserved_metadata_key(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
         let mut children = Vec::<GenericField>::new();
-        let data_type = match &field.data_type {
+        let mut type_ids = None;
+        let mut sorted = false;
+        let data_type = match data_type {
             DataType::Boolean => GenericDataType::Bool,
             DataType::Null => GenericDataType::Null,
             DataType::Int8 => GenericDataType::I8,
@@ -106,13 +126,59 @@ impl TryFrom<&Field> for GenericField {
             DataType::Float64 => GenericDataType::F64,
             DataType::Utf8 => GenericDataType::Utf8,
             DataType::LargeUtf8 => GenericDataType::LargeUtf8,
+            DataType::Binary => GenericDataType::Binary,
+            DataType::LargeBinary => GenericDataType::LargeBinary,
+            DataType::FixedSizeBinary(n) => {
+                let Ok(n) = i32::try_from(*n) else {
+                    fail!("cannot represent the byte width of the FixedSizeBinary");
+                };
+                GenericDataType::FixedSizeBinary(n)
+            }
+            DataType::Date32 => GenericDataType::Date32,
             DataType::Date64 => GenericDataType::Date64,
+            DataType::Time32(TimeUnit::Second) => GenericDataType::Time32(GenericTimeUnit::Second),
+            DataType::Time32(TimeUnit::Millisecond) => {
+                GenericDataType::Time32(GenericTimeUnit::Millisecond)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                GenericDataType::Time64(GenericTimeUnit::Microsecond)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                GenericDataType::Time64(GenericTimeUnit::Nanosecond)
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                GenericDataType::Duration(GenericTimeUnit::Second)
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                GenericDataType::Duration(GenericTimeUnit::Millisecond)
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                GenericDataType::Duration(GenericTimeUnit::Microsecond)
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                GenericDataType::Duration(GenericTimeUnit::Nanosecond)
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                GenericDataType::Interval(GenericIntervalUnit::YearMonth)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                GenericDataType::Interval(GenericIntervalUnit::DayTime)
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                GenericDataType::Interval(GenericIntervalUnit::MonthDayNano)
+            }
             DataType::Decimal(precision, scale) => {
                 if *precision > u8::MAX as usize || *scale > i8::MAX as usize {
                     fail!("cannot represent precision / scale of the decimal");
                 }
                 GenericDataType::Decimal128(*precision as u8, *scale as i8)
             }
+            DataType::Decimal256(precision, scale) => {
+                if *precision > u8::MAX as usize || *scale > i8::MAX as usize {
+                    fail!("cannot represent precision / scale of the decimal");
+                }
+                GenericDataType::Decimal256(*precision as u8, *scale as i8)
+            }
             DataType::Timestamp(TimeUnit::Second, tz) => {
                 GenericDataType::Timestamp(GenericTimeUnit::Second, tz.clone())
             }
@@ -133,28 +199,41 @@ impl TryFrom<&Field> for GenericField {
                 children.push(field.as_ref().try_into()?);
                 GenericDataType::LargeList
             }
+            DataType::FixedSizeList(field, n) => {
+                children.push(field.as_ref().try_into()?);
+                let Ok(n) = i32::try_from(*n) else {
+                    fail!("cannot represent the number of elements of the FixedSizeList");
+                };
+                GenericDataType::FixedSizeList(n)
+            }
             DataType::Struct(fields) => {
                 for field in fields {
                     children.push(field.try_into()?);
                 }
                 GenericDataType::Struct
             }
-            DataType::Map(field, _) => {
+            DataType::Map(field, keys_sorted) => {
                 children.push(field.as_ref().try_into()?);
+                sorted = *keys_sorted;
                 GenericDataType::Map
             }
             DataType::Union(fields, field_indices, mode) => {
-                if field_indices.is_some() {
-                    fail!("Union types with explicit field indices are not supported");
-                }
-                if !mode.is_dense() {
-                    fail!("Only dense unions are supported at the moment");
+                if let Some(field_indices) = field_indices {
+                    let mut ids = Vec::new();
+                    for idx in field_indices {
+                        ids.push(i8::try_from(*idx)?);
+                    }
+                    if ids.iter().enumerate().any(|(pos, idx)| pos as i8 != *idx) {
+                        type_ids = Some(ids);
+                    }
                 }
-
                 for field in fields {
                     children.push(field.try_into()?);
                 }
-                GenericDataType::Union
+                GenericDataType::Union(match mode {
+                    UnionMode::Sparse => GenericUnionMode::Sparse,
+                    UnionMode::Dense => GenericUnionMode::Dense,
+                })
             }
             DataType::Dictionary(int_type, data_type, sorted) => {
                 if *sorted {
@@ -183,7 +262,13 @@ impl TryFrom<&Field> for GenericField {
             strategy,
             children,
             nullable,
-        };
+            type_ids,
+            sorted,
+            extension_name,
+            extension_metadata,
+            metadata,
+        }
+        .with_extension_strategy();
         field.validate()?;
 
         Ok(field)
@@ -208,7 +293,42 @@ impl TryFrom<&GenericField> for Field {
             GenericDataType::F16 => DataType::Float16,
             GenericDataType::F32 => DataType::Float32,
             GenericDataType::F64 => DataType::Float64,
+            GenericDataType::Date32 => DataType::Date32,
             GenericDataType::Date64 => DataType::Date64,
+            GenericDataType::Time32(GenericTimeUnit::Second) => DataType::Time32(TimeUnit::Second),
+            GenericDataType::Time32(GenericTimeUnit::Millisecond) => {
+                DataType::Time32(TimeUnit::Millisecond)
+            }
+            GenericDataType::Time64(GenericTimeUnit::Microsecond) => {
+                DataType::Time64(TimeUnit::Microsecond)
+            }
+            GenericDataType::Time64(GenericTimeUnit::Nanosecond) => {
+                DataType::Time64(TimeUnit::Nanosecond)
+            }
+            dt @ (GenericDataType::Time32(_) | GenericDataType::Time64(_)) => {
+                fail!("Invalid time unit for {dt}")
+            }
+            GenericDataType::Duration(GenericTimeUnit::Second) => {
+                DataType::Duration(TimeUnit::Second)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Millisecond) => {
+                DataType::Duration(TimeUnit::Millisecond)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Microsecond) => {
+                DataType::Duration(TimeUnit::Microsecond)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Nanosecond) => {
+                DataType::Duration(TimeUnit::Nanosecond)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::YearMonth) => {
+                DataType::Interval(IntervalUnit::YearMonth)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::DayTime) => {
+                DataType::Interval(IntervalUnit::DayTime)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::MonthDayNano) => {
+                DataType::Interval(IntervalUnit::MonthDayNano)
+            }
             GenericDataType::Timestamp(GenericTimeUnit::Second, tz) => {
                 DataType::Timestamp(TimeUnit::Second, tz.clone())
             }
@@ -227,8 +347,22 @@ impl TryFrom<&GenericField> for Field {
                 }
                 DataType::Decimal(*precision as usize, *scale as usize)
             }
+            GenericDataType::Decimal256(precision, scale) => {
+                if *scale < 0 {
+                    fail!("arrow2 does not support decimals with negative scale");
+                }
+                DataType::Decimal256(*precision as usize, *scale as usize)
+            }
             GenericDataType::Utf8 => DataType::Utf8,
             GenericDataType::LargeUtf8 => DataType::LargeUtf8,
+            GenericDataType::Binary => DataType::Binary,
+            GenericDataType::LargeBinary => DataType::LargeBinary,
+            GenericDataType::FixedSizeBinary(n) => {
+                let Ok(n) = usize::try_from(*n) else {
+                    fail!("invalid byte width for FixedSizeBinary: {n}");
+                };
+                DataType::FixedSizeBinary(n)
+            }
             GenericDataType::List => DataType::List(Box::new(
                 value
                     .children
@@ -243,6 +377,21 @@ impl TryFrom<&GenericField> for Field {
                     .ok_or_else(|| error!("List must a single child"))?
                     .try_into()?,
             )),
+            GenericDataType::FixedSizeList(n) => {
+                let Ok(n) = usize::try_from(*n) else {
+                    fail!("invalid number of elements for FixedSizeList: {n}");
+                };
+                DataType::FixedSizeList(
+                    Box::new(
+                        value
+                            .children
+                            .first()
+                            .ok_or_else(|| error!("FixedSizeList must a single child"))?
+                            .try_into()?,
+                    ),
+                    n,
+                )
+            }
             GenericDataType::Struct => DataType::Struct(
                 value
                     .children
@@ -256,16 +405,22 @@ impl TryFrom<&GenericField> for Field {
                     .first()
                     .ok_or_else(|| error!("Map must a two children"))?
                     .try_into()?;
-                DataType::Map(Box::new(element_field), false)
+                DataType::Map(Box::new(element_field), value.sorted)
             }
-            GenericDataType::Union => DataType::Union(
+            GenericDataType::Union(mode) => DataType::Union(
                 value
                     .children
                     .iter()
                     .map(Field::try_from)
                     .collect::<Result<Vec<_>>>()?,
-                None,
-                UnionMode::Dense,
+                value
+                    .type_ids
+                    .as_ref()
+                    .map(|type_ids| type_ids.iter().map(|id| i32::from(*id)).collect()),
+                match mode {
+                    GenericUnionMode::Sparse => UnionMode::Sparse,
+                    GenericUnionMode::Dense => UnionMode::Dense,
+                },
             ),
             GenericDataType::Dictionary => {
                 let Some(key_field) = value.children.first() else {
@@ -291,11 +446,24 @@ impl TryFrom<&GenericField> for Field {
 
                 DataType::Dictionary(key_type, Box::new(val_field.data_type), false)
             }
+            GenericDataType::RunEndEncoded => fail!("RunEndEncoded is not supported by arrow2"),
+        };
+
+        let data_type = match value.extension_name.as_ref() {
+            Some(name) => DataType::Extension(
+                name.clone(),
+                Box::new(data_type),
+                value.extension_metadata.clone(),
+            ),
+            None => data_type,
         };
 
         let mut field = Field::new(&value.name, data_type, value.nullable);
+        field.metadata = value.metadata.clone();
         if let Some(strategy) = value.strategy.as_ref() {
-            field.metadata = strategy.clone().into();
+            field
+                .metadata
+                .insert(STRATEGY_KEY.to_string(), strategy.to_string());
         }
 
         Ok(field)
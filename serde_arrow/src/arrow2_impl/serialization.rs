@@ -3,20 +3,21 @@
 use crate::{
     _impl::arrow2::{
         array::{
-            Array, BooleanArray, DictionaryArray, DictionaryKey, ListArray, MapArray, NullArray,
-            PrimitiveArray, StructArray, UnionArray, Utf8Array,
+            Array, BinaryArray, BooleanArray, DictionaryArray, DictionaryKey, FixedSizeBinaryArray,
+            FixedSizeListArray, ListArray, MapArray, NullArray, PrimitiveArray, StructArray,
+            UnionArray, Utf8Array,
         },
         bitmap::Bitmap,
         buffer::Buffer,
-        datatypes::{DataType, Field},
+        datatypes::{DataType, Field, IntervalUnit},
         offset::OffsetsBuffer,
-        types::{f16, NativeType, Offset},
+        types::{days_ms, f16, i256, months_days_ns, NativeType, Offset},
     },
     internal::{
         common::MutableBitBuffer,
         error::{fail, Result},
-        schema::GenericField,
-        serialization_ng::ArrayBuilder,
+        schema::{GenericField, GenericIntervalUnit, GenericUnionMode},
+        serialization_ng::{interval_builder::IntervalBuilder, ArrayBuilder},
     },
 };
 
@@ -36,7 +37,7 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
     use {ArrayBuilder as A, DataType as T};
     match builder {
         A::Null(builder) => Ok(Box::new(NullArray::new(T::Null, builder.count))),
-        A::UnknownVariant(_) => Ok(Box::new(NullArray::new(T::Null, 0))),
+        A::UnknownVariant(builder) => Ok(Box::new(NullArray::new(T::Null, builder.count))),
         A::Bool(builder) => {
             let buffer = Bitmap::from_u8_vec(builder.buffer.buffer, builder.buffer.len);
             let validity = build_validity(builder.validity);
@@ -65,16 +66,48 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
         ),
         A::F32(builder) => build_primitive_array(T::Float32, builder.buffer, builder.validity),
         A::F64(builder) => build_primitive_array(T::Float64, builder.buffer, builder.validity),
+        A::Date32(builder) => build_primitive_array(T::Date32, builder.buffer, builder.validity),
         A::Date64(builder) => build_primitive_array(
             Field::try_from(&builder.field)?.data_type,
             builder.buffer,
             builder.validity,
         ),
+        A::Time32(builder) => build_primitive_array(
+            Field::try_from(&builder.field)?.data_type,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Time64(builder) => build_primitive_array(
+            Field::try_from(&builder.field)?.data_type,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Duration(builder) => build_primitive_array(
+            Field::try_from(&builder.field)?.data_type,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Interval(builder) => build_interval_array(builder),
         A::Decimal128(builder) => build_primitive_array(
             T::Decimal(builder.precision as usize, usize::try_from(builder.scale)?),
             builder.buffer,
             builder.validity,
         ),
+        A::Decimal256(builder) => build_primitive_array(
+            T::Decimal256(builder.precision as usize, usize::try_from(builder.scale)?),
+            builder
+                .buffer
+                .into_iter()
+                .map(|val| {
+                    let (low, high) = val.split_at(16);
+                    i256::from_words(
+                        i128::from_le_bytes(high.try_into().unwrap()),
+                        i128::from_le_bytes(low.try_into().unwrap()),
+                    )
+                })
+                .collect(),
+            builder.validity,
+        ),
         A::Utf8(builder) => build_array_utf8_array(
             T::Utf8,
             builder.offsets.offsets,
@@ -87,6 +120,23 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
             builder.buffer,
             builder.validity,
         ),
+        A::Binary(builder) => build_array_binary_array(
+            T::Binary,
+            builder.offsets.offsets,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::LargeBinary(builder) => build_array_binary_array(
+            T::LargeBinary,
+            builder.offsets.offsets,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::FixedSizeBinary(builder) => Ok(Box::new(FixedSizeBinaryArray::try_new(
+            T::FixedSizeBinary(builder.n),
+            Buffer::from(builder.buffer),
+            build_validity(builder.validity),
+        )?)),
         A::LargeList(builder) => Ok(Box::new(ListArray::try_new(
             T::LargeList(Box::new(Field::try_from(&builder.field)?)),
             OffsetsBuffer::try_from(builder.offsets.offsets)?,
@@ -99,6 +149,11 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
             build_array(*builder.element)?,
             build_validity(builder.validity),
         )?)),
+        A::FixedSizeList(builder) => Ok(Box::new(FixedSizeListArray::try_new(
+            T::FixedSizeList(Box::new(Field::try_from(&builder.field)?), builder.n),
+            build_array(*builder.element)?,
+            build_validity(builder.validity),
+        )?)),
         A::Struct(builder) => {
             let mut values = Vec::new();
             for (_, field) in builder.named_fields {
@@ -117,12 +172,16 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
             )?))
         }
         A::Map(builder) => Ok(Box::new(MapArray::try_new(
-            T::Map(Box::new(Field::try_from(&builder.entry_field)?), false),
+            T::Map(
+                Box::new(Field::try_from(&builder.entry_field)?),
+                builder.sorted,
+            ),
             OffsetsBuffer::try_from(builder.offsets.offsets)?,
             build_array(*builder.entry)?,
             build_validity(builder.validity),
         )?)),
-        A::DictionaryUtf8(builder) => {
+        A::RunEndEncoded(_) => fail!("RunEndEncoded arrays are not supported by arrow2"),
+        A::Dictionary(builder) => {
             let values = build_array(*builder.values)?;
             match *builder.indices {
                 A::U8(ib) => {
@@ -163,7 +222,8 @@ fn build_array(builder: ArrayBuilder) -> Result<Box<dyn Array>> {
                 data_type,
                 Buffer::from(builder.types),
                 children,
-                Some(Buffer::from(builder.offsets)),
+                matches!(builder.mode, GenericUnionMode::Dense)
+                    .then(|| Buffer::from(builder.offsets)),
             )?))
         }
     }
@@ -186,6 +246,37 @@ fn build_primitive_array<T: NativeType>(
     )?))
 }
 
+fn build_interval_array(builder: IntervalBuilder) -> Result<Box<dyn Array>> {
+    match builder.unit {
+        GenericIntervalUnit::YearMonth => build_primitive_array(
+            DataType::Interval(IntervalUnit::YearMonth),
+            builder.months,
+            builder.validity,
+        ),
+        GenericIntervalUnit::DayTime => {
+            let buffer = std::iter::zip(builder.days, builder.time)
+                .map(|(days, millis)| Ok(days_ms::new(days, i32::try_from(millis)?)))
+                .collect::<Result<Vec<_>>>()?;
+            build_primitive_array(
+                DataType::Interval(IntervalUnit::DayTime),
+                buffer,
+                builder.validity,
+            )
+        }
+        GenericIntervalUnit::MonthDayNano => {
+            let buffer = std::iter::zip(builder.months, builder.days)
+                .zip(builder.time)
+                .map(|((months, days), nanos)| months_days_ns::new(months, days, nanos))
+                .collect::<Vec<_>>();
+            build_primitive_array(
+                DataType::Interval(IntervalUnit::MonthDayNano),
+                buffer,
+                builder.validity,
+            )
+        }
+    }
+}
+
 fn build_dictionary_array<K: DictionaryKey>(
     field: GenericField,
     data_type: DataType,
@@ -200,6 +291,20 @@ fn build_dictionary_array<K: DictionaryKey>(
     )?))
 }
 
+fn build_array_binary_array<O: Offset>(
+    data_type: DataType,
+    offsets: Vec<O>,
+    data: Vec<u8>,
+    validity: Option<MutableBitBuffer>,
+) -> Result<Box<dyn Array>> {
+    Ok(Box::new(BinaryArray::new(
+        data_type,
+        OffsetsBuffer::try_from(offsets)?,
+        Buffer::from(data),
+        build_validity(validity),
+    )))
+}
+
 fn build_array_utf8_array<O: Offset>(
     data_type: DataType,
     offsets: Vec<O>,
@@ -1,19 +1,25 @@
 #![deny(missing_docs)]
+use std::sync::Arc;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     _impl::arrow::{
-        array::{Array, ArrayRef},
-        datatypes::Field,
+        array::{Array, ArrayRef, RecordBatch},
+        datatypes::{Field, Schema, SchemaRef},
     },
     internal::{
-        error::Result,
+        batching::{BatchBackend, BatchBuilder},
+        deserialization::{compile_chunks, ChunkedInterpreter, ItemIterator, Program},
+        error::{fail, Result},
         schema::{GenericField, SerdeArrowSchema},
         serialization_ng::ArrayBuilder,
         source::deserialize_from_source,
     },
 };
 
+use super::type_support::FieldRef;
+
 /// Build arrow arrays record by record (*requires one of the `arrow-*`
 /// features*)
 ///
@@ -58,7 +64,10 @@ use crate::{
 /// # Ok(())
 /// # }
 /// ```
-pub struct ArrowBuilder(ArrayBuilder);
+pub struct ArrowBuilder {
+    schema: SchemaRef,
+    builder: ArrayBuilder,
+}
 
 impl std::fmt::Debug for ArrowBuilder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -73,20 +82,32 @@ impl ArrowBuilder {
     /// given fields.
     ///
     pub fn new(fields: &[Field]) -> Result<Self> {
-        let schema = SerdeArrowSchema::from_arrow_fields(fields)?;
-        Ok(Self(ArrayBuilder::new(&schema)?))
+        Self::from_schema(Schema::new(fields.to_vec()))
+    }
+
+    /// Build a new ArrowBuilder for the fields of the given schema
+    ///
+    /// In contrast to [`ArrowBuilder::new`], the schema of the built record
+    /// batches is the given schema, including its metadata.
+    ///
+    pub fn from_schema(schema: impl Into<SchemaRef>) -> Result<Self> {
+        let schema = schema.into();
+        let builder = ArrayBuilder::new(&SerdeArrowSchema {
+            fields: generic_fields(schema.fields())?,
+        })?;
+        Ok(Self { schema, builder })
     }
 
     /// Add a single record to the arrays
     ///
     pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<()> {
-        self.0.extend(&[item])
+        self.builder.extend(&[item])
     }
 
     /// Add multiple records to the arrays
     ///
     pub fn extend<T: Serialize + ?Sized>(&mut self, items: &T) -> Result<()> {
-        self.0.extend(items)
+        self.builder.extend(items)
     }
 
     /// Build the arrays from the rows pushed to far.
@@ -94,7 +115,160 @@ impl ArrowBuilder {
     /// This operation will reset the underlying buffers and start a new batch.
     ///
     pub fn build_arrays(&mut self) -> Result<Vec<ArrayRef>> {
-        self.0.build_arrow_arrays()
+        self.builder.build_arrow_arrays()
+    }
+
+    /// Build a record batch from the rows pushed to far.
+    ///
+    /// The schema of the record batch is the schema passed to
+    /// [`ArrowBuilder::from_schema`] or built from the fields passed to
+    /// [`ArrowBuilder::new`], including their metadata. This operation will
+    /// reset the underlying buffers and start a new batch.
+    ///
+    pub fn build_record_batch(&mut self) -> Result<RecordBatch> {
+        let arrays = self.build_arrays()?;
+        Ok(RecordBatch::try_new(self.schema.clone(), arrays)?)
+    }
+}
+
+/// Build record batches with a limited number of rows or bytes (*requires one
+/// of the `arrow-*` features*)
+///
+/// The batching mode of [`ArrowBuilder`]: records are pushed one by one and a
+/// record batch is completed, whenever the number of rows or the approximate
+/// number of bytes reach the configured limits. Completed batches are either
+/// returned from [`push`][ArrowBatchBuilder::push] and
+/// [`extend`][ArrowBatchBuilder::extend] or passed to the callback set via
+/// [`with_callback`][ArrowBatchBuilder::with_callback]. The remaining rows are
+/// built into a final batch by [`flush`][ArrowBatchBuilder::flush].
+///
+/// The number of bytes is estimated from the buffers filled so far. It does
+/// not include any allocation overhead.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow as arrow;
+/// use arrow::datatypes::{DataType, Field};
+/// use serde::Serialize;
+/// use serde_arrow::ArrowBatchBuilder;
+///
+/// ##[derive(Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let mut builder = ArrowBatchBuilder::new(&[
+///     Field::new("a", DataType::Float32, true),
+///     Field::new("b", DataType::UInt64, false),
+/// ])?
+/// .with_max_rows(2);
+///
+/// let mut batches = Vec::new();
+/// for b in 0..5 {
+///     batches.extend(builder.push(&Record { a: None, b })?);
+/// }
+/// batches.extend(builder.flush()?);
+///
+/// # assert_eq!(batches.len(), 3);
+/// # assert_eq!(batches[0].num_rows(), 2);
+/// # assert_eq!(batches[2].num_rows(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ArrowBatchBuilder<'a> {
+    inner: BatchBuilder<'a, ArrowBuilder>,
+}
+
+impl<'a> std::fmt::Debug for ArrowBatchBuilder<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArrowBatchBuilder<...>")
+    }
+}
+
+impl<'a> ArrowBatchBuilder<'a> {
+    /// Build a new ArrowBatchBuilder for the given fields
+    ///
+    /// Without any limits, all records are collected until
+    /// [`flush`][ArrowBatchBuilder::flush] is called.
+    ///
+    pub fn new(fields: &[Field]) -> Result<Self> {
+        Ok(Self {
+            inner: BatchBuilder::new(ArrowBuilder::new(fields)?),
+        })
+    }
+
+    /// Complete a batch once it contains the given number of rows
+    ///
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.inner.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Complete a batch once its buffers contain approximately the given
+    /// number of bytes
+    ///
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.inner.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Pass completed batches to the callback instead of returning them
+    ///
+    /// The callback may borrow from its environment for the lifetime of the
+    /// builder.
+    ///
+    pub fn with_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(RecordBatch) -> Result<()> + Send + 'a,
+    {
+        self.inner.callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Add a single record and return the completed batches, if any
+    ///
+    pub fn push<T: Serialize + ?Sized>(&mut self, item: &T) -> Result<Vec<RecordBatch>> {
+        self.inner.extend([item])
+    }
+
+    /// Add multiple records and return the completed batches, if any
+    ///
+    /// In contrast to [`ArrowBuilder::extend`], the records are given as an
+    /// iterator to allow completing batches in between records. If a record
+    /// fails to serialize, the rows not yet completed into a batch are
+    /// discarded. Batches completed before the error are returned by the next
+    /// call to [`push`][ArrowBatchBuilder::push],
+    /// [`extend`][ArrowBatchBuilder::extend] or
+    /// [`flush`][ArrowBatchBuilder::flush].
+    ///
+    pub fn extend<I>(&mut self, items: I) -> Result<Vec<RecordBatch>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        self.inner.extend(items)
+    }
+
+    /// Build a batch from the remaining rows and return all completed batches
+    ///
+    /// Returns an empty vector if no rows were pushed since the last batch or
+    /// if the batches were passed to the callback.
+    ///
+    pub fn flush(&mut self) -> Result<Vec<RecordBatch>> {
+        self.inner.flush()
+    }
+}
+
+impl BatchBackend for ArrowBuilder {
+    type Batch = RecordBatch;
+
+    fn array_builder(&mut self) -> &mut ArrayBuilder {
+        &mut self.builder
+    }
+
+    fn build_batch(&mut self) -> Result<RecordBatch> {
+        self.build_record_batch()
     }
 }
 
@@ -139,13 +313,10 @@ impl ArrowBuilder {
 ///
 /// ```rust
 /// # fn main() -> serde_arrow::Result<()> {
-/// # use serde_arrow::_impl::arrow::datatypes::Field;
+/// # use serde_arrow::_impl::arrow;
+/// # use arrow::datatypes::Field;
 /// # use serde::{Serialize, Deserialize};
 /// # use serde_arrow::schema::{SchemaLike, TracingOptions};
-/// # mod arrow {
-/// #   pub mod array { pub use serde_arrow::_impl::arrow::_raw::array::RecordBatch;  }
-/// #   pub mod datatypes { pub use serde_arrow::_impl::arrow::_raw::schema::Schema; }
-/// # }
 /// # #[derive(Serialize, Deserialize)]
 /// # struct Record { a: Option<f32>, b: u64 }
 /// # let items = vec![ Record { a: Some(1.0), b: 2}, ];
@@ -163,12 +334,55 @@ impl ArrowBuilder {
 /// # }
 /// ```
 ///
+/// Or more directly with [`to_record_batch`].
+///
 pub fn to_arrow<T: Serialize + ?Sized>(fields: &[Field], items: &T) -> Result<Vec<ArrayRef>> {
     let mut builder = ArrowBuilder::new(fields)?;
     builder.extend(items)?;
     builder.build_arrays()
 }
 
+/// Build a record batch from the given items (*requires one of the `arrow-*`
+/// features*)
+///
+/// The same as [`to_arrow`], but the arrays are wrapped in a record batch
+/// whose schema is built from the given fields.
+///
+/// Example:
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Serialize, Deserialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Serialize, Deserialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let items = vec![
+///     Record { a: Some(1.0), b: 2},
+///     // ...
+/// ];
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// let record_batch = serde_arrow::to_record_batch(&fields, &items)?;
+///
+/// assert_eq!(record_batch.num_columns(), 2);
+/// assert_eq!(record_batch.num_rows(), 1);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn to_record_batch<T: Serialize + ?Sized>(fields: &[Field], items: &T) -> Result<RecordBatch> {
+    let mut builder = ArrowBuilder::new(fields)?;
+    builder.extend(items)?;
+    builder.build_record_batch()
+}
+
 /// Deserialize items from arrow arrays (*requires one of the `arrow-*`
 /// features*)
 ///
@@ -202,16 +416,214 @@ pub fn from_arrow<'de, T, A>(fields: &'de [Field], arrays: &'de [A]) -> Result<T
 where
     T: Deserialize<'de>,
     A: AsRef<dyn Array>,
+{
+    deserialize_arrays(fields, arrays)
+}
+
+/// Lazily deserialize items from arrow arrays (*requires one of the `arrow-*`
+/// features*)
+///
+/// In contrast to [`from_arrow`], the type should be a single record (e.g., a
+/// struct). The returned iterator deserializes one record at a time. Therefore,
+/// the deserialized records do not have to be kept in memory at the same time
+/// and the iteration can be stopped early.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let items = &[Record { a: Some(1.0), b: 2}, Record { a: None, b: 4}];
+/// # let arrays = serde_arrow::to_arrow(&fields, &items)?;
+/// #
+/// let mut total = 0;
+/// for item in serde_arrow::from_arrow_iter::<Record, _>(&fields, &arrays)? {
+///     total += item?.b;
+/// }
+/// # assert_eq!(total, 6);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_iter<'de, T, A>(
+    fields: &[Field],
+    arrays: &'de [A],
+) -> Result<impl Iterator<Item = Result<T>> + 'de>
+where
+    T: Deserialize<'de> + 'de,
+    A: AsRef<dyn Array>,
+{
+    Ok(ItemIterator::new(compile_program(fields, arrays)?))
+}
+
+/// Deserialize items from a record batch (*requires one of the `arrow-*`
+/// features*)
+///
+/// The same as [`from_arrow`], but the fields are taken from the schema of the
+/// record batch.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let items = &[Record { a: Some(1.0), b: 2}];
+/// # let record_batch = serde_arrow::to_record_batch(&fields, &items)?;
+/// #
+/// let items: Vec<Record> = serde_arrow::from_record_batch(&record_batch)?;
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_record_batch<'de, T>(record_batch: &'de RecordBatch) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    let schema = record_batch.schema();
+    deserialize_arrays(schema.fields(), record_batch.columns())
+}
+
+/// Deserialize items from multiple record batches (*requires one of the
+/// `arrow-*` features*)
+///
+/// The record batches must share the same schema. Their rows are deserialized
+/// as a single sequence, e.g., a single vector of records. The deserialization
+/// is only compiled once and reused for all record batches with the same
+/// layout.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let batches = vec![
+/// #     serde_arrow::to_record_batch(&fields, &[Record { a: Some(1.0), b: 2}])?,
+/// #     serde_arrow::to_record_batch(&fields, &[Record { a: None, b: 4}])?,
+/// # ];
+/// #
+/// let items: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+/// # assert_eq!(items.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_record_batches<'de, T>(record_batches: &'de [RecordBatch]) -> Result<T>
+where
+    T: Deserialize<'de>,
+{
+    let schema = match record_batches.first() {
+        Some(record_batch) => record_batch.schema(),
+        None => Arc::new(Schema::empty()),
+    };
+    for record_batch in record_batches {
+        if record_batch.schema().fields() != schema.fields() {
+            fail!("All record batches must share the same schema");
+        }
+    }
+
+    let programs = compile_chunks(
+        &generic_fields(schema.fields())?,
+        record_batches
+            .iter()
+            .map(|record_batch| chunk_arrays(record_batch.columns())),
+    )?;
+    deserialize_from_source(ChunkedInterpreter::new(&programs)?)
+}
+
+/// Deserialize items from multiple chunks of arrow arrays (*requires one of
+/// the `arrow-*` features*)
+///
+/// Each chunk contains one array per field, all of equal length. The items of
+/// all chunks are deserialized as a single sequence. The deserialization is
+/// only compiled once and reused for all chunks with the same layout.
+///
+/// ```rust
+/// # fn main() -> serde_arrow::Result<()> {
+/// # use serde_arrow::_impl::arrow;
+/// use arrow::datatypes::Field;
+/// use serde::{Deserialize, Serialize};
+/// use serde_arrow::schema::{SchemaLike, TracingOptions};
+///
+/// ##[derive(Deserialize, Serialize)]
+/// struct Record {
+///     a: Option<f32>,
+///     b: u64,
+/// }
+///
+/// let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+/// # let chunks = vec![
+/// #     serde_arrow::to_arrow(&fields, &[Record { a: Some(1.0), b: 2}])?,
+/// #     serde_arrow::to_arrow(&fields, &[Record { a: None, b: 4}])?,
+/// # ];
+/// #
+/// let items: Vec<Record> = serde_arrow::from_arrow_chunks(&fields, &chunks)?;
+/// # assert_eq!(items.len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+///
+pub fn from_arrow_chunks<'de, T, C, A>(fields: &[Field], chunks: &'de [C]) -> Result<T>
+where
+    T: Deserialize<'de>,
+    C: AsRef<[A]>,
+    A: AsRef<dyn Array> + 'de,
+{
+    let programs = compile_chunks(
+        &generic_fields(fields)?,
+        chunks.iter().map(|chunk| chunk_arrays(chunk.as_ref())),
+    )?;
+    deserialize_from_source(ChunkedInterpreter::new(&programs)?)
+}
+
+fn deserialize_arrays<'de, T, F, A>(fields: &[F], arrays: &'de [A]) -> Result<T>
+where
+    T: Deserialize<'de>,
+    F: FieldRef,
+    A: AsRef<dyn Array>,
+{
+    let program = compile_program(fields, arrays)?;
+    let mut state = program.new_state();
+    deserialize_from_source(program.interpreter(&mut state))
+}
+
+fn compile_program<'de, F, A>(fields: &[F], arrays: &'de [A]) -> Result<Program<'de>>
+where
+    F: FieldRef,
+    A: AsRef<dyn Array>,
 {
     use crate::internal::{
         common::{BufferExtract, Buffers},
         deserialization,
     };
 
-    let fields = fields
-        .iter()
-        .map(GenericField::try_from)
-        .collect::<Result<Vec<_>>>()?;
+    let fields = generic_fields(fields)?;
 
     let num_items = arrays
         .iter()
@@ -225,6 +637,16 @@ where
         mappings.push(array.as_ref().extract_buffers(field, &mut buffers)?);
     }
 
-    let interpreter = deserialization::compile_deserialization(num_items, &mappings, buffers)?;
-    deserialize_from_source(interpreter)
+    deserialization::compile_deserialization(num_items, &mappings, buffers)
+}
+
+fn chunk_arrays<A: AsRef<dyn Array>>(arrays: &[A]) -> Vec<&(dyn Array + 'static)> {
+    arrays.iter().map(AsRef::as_ref).collect()
+}
+
+fn generic_fields<F: FieldRef>(fields: &[F]) -> Result<Vec<GenericField>> {
+    fields
+        .iter()
+        .map(|field| GenericField::try_from(field.as_field_ref()))
+        .collect()
 }
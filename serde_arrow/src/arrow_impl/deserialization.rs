@@ -1,21 +1,29 @@
-use crate::_impl::arrow::array::Array;
-use crate::internal::common::{BitBuffer, DictionaryIndex, DictionaryValue};
+use std::borrow::Cow;
+
+use crate::_impl::arrow::{array::Array, buffer::Buffer};
+use crate::internal::common::{
+    BitBuffer, DictionaryIndex, DictionaryValue, IntervalLayout, RunEnds,
+};
 use crate::internal::{
     common::{check_supported_list_layout, ArrayMapping, BufferExtract, Buffers},
     error::{error, fail, Result},
-    schema::{GenericDataType, GenericField, GenericTimeUnit},
+    schema::{GenericDataType, GenericField, GenericIntervalUnit, GenericTimeUnit},
 };
 
 use crate::_impl::arrow::{
     array::{
-        BooleanArray, DictionaryArray, GenericListArray, LargeStringArray, MapArray,
-        PrimitiveArray, StringArray, StructArray,
+        BinaryArray, BooleanArray, DictionaryArray, FixedSizeBinaryArray, FixedSizeListArray,
+        GenericListArray, LargeBinaryArray, LargeStringArray, MapArray, PrimitiveArray, RunArray,
+        StringArray, StructArray,
     },
     datatypes::{
-        DataType, Date64Type, Decimal128Type, Float16Type, Float32Type, Float64Type, Int16Type,
-        Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType,
-        TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type,
-        UInt8Type,
+        DataType, Date32Type, Date64Type, Decimal128Type, Decimal256Type, DurationMicrosecondType,
+        DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type,
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, IntervalDayTimeType,
+        IntervalMonthDayNanoType, IntervalYearMonthType, Time32MillisecondType, Time32SecondType,
+        Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType,
+        TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
     },
 };
 
@@ -47,6 +55,25 @@ impl BufferExtract for dyn Array {
             }};
         }
 
+        macro_rules! convert_interval {
+            ($array_type:ty, $layout:ident, $push_func:ident) => {{
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<$array_type>>()
+                    .ok_or_else(|| error!("cannot interpret array as interval array"))?;
+
+                let buffer = buffers.$push_func(typed.values())?;
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                Ok(M::Interval {
+                    field: field.clone(),
+                    buffer,
+                    validity,
+                    layout: IntervalLayout::$layout,
+                })
+            }};
+        }
+
         macro_rules! convert_utf8 {
             ($array_type:ty, $variant:ident, $push_func:ident) => {{
                 let typed = self
@@ -98,7 +125,9 @@ impl BufferExtract for dyn Array {
             }};
         }
 
-        use {ArrayMapping as M, GenericDataType as T, GenericTimeUnit as U};
+        use {
+            ArrayMapping as M, GenericDataType as T, GenericIntervalUnit as I, GenericTimeUnit as U,
+        };
 
         match &field.data_type {
             T::Null => {
@@ -142,8 +171,58 @@ impl BufferExtract for dyn Array {
             T::F16 => convert_primitive!(Float16Type, F16, push_u16_cast),
             T::F32 => convert_primitive!(Float32Type, F32, push_u32_cast),
             T::F64 => convert_primitive!(Float64Type, F64, push_u64_cast),
+            T::Date32 => convert_primitive!(Date32Type, Date32, push_u32_cast),
             T::Date64 => convert_primitive!(Date64Type, Date64, push_u64_cast),
+            T::Time32(U::Second) => convert_primitive!(Time32SecondType, Time32, push_u32_cast),
+            T::Time32(U::Millisecond) => {
+                convert_primitive!(Time32MillisecondType, Time32, push_u32_cast)
+            }
+            T::Time64(U::Microsecond) => {
+                convert_primitive!(Time64MicrosecondType, Time64, push_u64_cast)
+            }
+            T::Time64(U::Nanosecond) => {
+                convert_primitive!(Time64NanosecondType, Time64, push_u64_cast)
+            }
+            T::Time32(unit) => fail!("Time32 with unit {unit} is not supported"),
+            T::Time64(unit) => fail!("Time64 with unit {unit} is not supported"),
+            T::Duration(U::Second) => {
+                convert_primitive!(DurationSecondType, Duration, push_u64_cast)
+            }
+            T::Duration(U::Millisecond) => {
+                convert_primitive!(DurationMillisecondType, Duration, push_u64_cast)
+            }
+            T::Duration(U::Microsecond) => {
+                convert_primitive!(DurationMicrosecondType, Duration, push_u64_cast)
+            }
+            T::Duration(U::Nanosecond) => {
+                convert_primitive!(DurationNanosecondType, Duration, push_u64_cast)
+            }
+            T::Interval(I::YearMonth) => {
+                convert_interval!(IntervalYearMonthType, YearMonth, push_u32_cast)
+            }
+            T::Interval(I::DayTime) => {
+                convert_interval!(IntervalDayTimeType, PackedDayTime, push_u64_cast)
+            }
+            T::Interval(I::MonthDayNano) => {
+                convert_interval!(IntervalMonthDayNanoType, PackedMonthDayNano, push_u128_cast)
+            }
             T::Decimal128(_, _) => convert_primitive!(Decimal128Type, Decimal128, push_u128_cast),
+            T::Decimal256(_, _) => {
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<Decimal256Type>>()
+                    .ok_or_else(|| error!("Cannot interpret array as typed array"))?;
+
+                // use the raw bytes, as the memory layout of i256 is not exposed
+                let buffer = buffers.push_u256_cast(typed.values().inner().as_slice())?;
+                let validity = get_validity(typed).map(|v| buffers.push_u1(v));
+
+                Ok(M::Decimal256 {
+                    field: field.clone(),
+                    buffer,
+                    validity,
+                })
+            }
             T::Timestamp(U::Second, _) => {
                 convert_primitive!(TimestampSecondType, Date64, push_u64_cast)
             }
@@ -158,8 +237,50 @@ impl BufferExtract for dyn Array {
             }
             T::Utf8 => convert_utf8!(StringArray, Utf8, push_u32_cast),
             T::LargeUtf8 => convert_utf8!(LargeStringArray, LargeUtf8, push_u64_cast),
+            T::Binary => convert_utf8!(BinaryArray, Binary, push_u32_cast),
+            T::LargeBinary => convert_utf8!(LargeBinaryArray, LargeBinary, push_u64_cast),
+            T::FixedSizeBinary(n) => {
+                let typed = self
+                    .as_any()
+                    .downcast_ref::<FixedSizeBinaryArray>()
+                    .ok_or_else(|| error!("cannot convert array into fixed size binary"))?;
+
+                let n = usize::try_from(*n)?;
+                let start = usize::try_from(typed.value_offset(0))?;
+                let buffer = buffers.push_u8_cow(typed.value_data().into_bytes(start));
+                let validity = get_validity(self).map(|v| buffers.push_u1(v));
+
+                Ok(M::FixedSizeBinary {
+                    field: field.clone(),
+                    validity,
+                    buffer,
+                    n,
+                })
+            }
             T::List => convert_list!(i32, List, push_u32_cast),
             T::LargeList => convert_list!(i64, LargeList, push_u64_cast),
+            T::FixedSizeList(n) => {
+                let Some(typed) = self.as_any().downcast_ref::<FixedSizeListArray>() else {
+                    fail!("cannot convert array into FixedSizeListArray");
+                };
+                if typed.value_offset(0) != 0 {
+                    fail!("FixedSizeListArrays with non-zero offsets are not supported");
+                }
+
+                let validity = get_validity(self).map(|v| buffers.push_u1(v));
+
+                let Some(item_field) = field.children.first() else {
+                    fail!("cannot get first child of fixed size list array");
+                };
+                let item = typed.values().extract_buffers(item_field, buffers)?;
+
+                Ok(M::FixedSizeList {
+                    field: field.clone(),
+                    item: Box::new(item),
+                    validity,
+                    n: usize::try_from(*n)?,
+                })
+            }
             T::Struct => {
                 let typed = self
                     .as_any()
@@ -231,33 +352,30 @@ impl BufferExtract for dyn Array {
                             .downcast_ref::<DictionaryArray<$key_type>>()
                             .ok_or_else(|| error!("cannot convert array into u32 dictionary"))?;
 
-                        // NOTE: the array is validity is given by the key validity
-                        if typed.values().null_count() != 0 {
-                            fail!("dictionaries with nullable values are not supported");
-                        }
-
                         let validity = get_validity(typed).map(|b| buffers.push_u1(b));
                         let keys =
                             (typed.keys() as &dyn Array).extract_buffers(keys_field, buffers)?;
 
-                        let M::$variant { buffer: index_buffer, .. } = keys else {
+                        let M::$variant {
+                            buffer: index_buffer,
+                            ..
+                        } = keys
+                        else {
                             fail!("internal error unexpected array mapping for keys")
                         };
 
                         let values = typed.values().extract_buffers(values_field, buffers)?;
 
-                        let dictionary = match values {
-                            M::Utf8 { buffer, offsets, .. } => DictionaryValue::Utf8{ buffer, offsets },
-                            M::LargeUtf8 { buffer, offsets, .. } => DictionaryValue::LargeUtf8{ buffer, offsets },
-                            m => fail!("BufferExtract for dictionaries with values of type {m:?} is not implemented"),
-                        };
+                        // NOTE: null values are resolved via the validity of the values, see EmitOptionDictionary
+                        let values_validity = values.get_validity();
+                        let dictionary = DictionaryValue::try_from_mapping(values)?;
                         Ok(M::Dictionary {
                             field: field.clone(),
                             validity,
                             dictionary,
                             indices: DictionaryIndex::$variant(index_buffer),
+                            values_validity,
                         })
-
                     }};
                 }
 
@@ -273,7 +391,46 @@ impl BufferExtract for dyn Array {
                     dt => fail!("BufferExtract for dictionaries with key {dt} is not implemented"),
                 }
             }
-            T::Union => {
+            T::RunEndEncoded => {
+                let Some(run_ends_field) = field.children.first() else {
+                    fail!("cannot get run ends field of run-end encoded array");
+                };
+                let Some(values_field) = field.children.get(1) else {
+                    fail!("cannot get values field of run-end encoded array");
+                };
+
+                macro_rules! convert_run_end_encoded {
+                    ($run_end_type:ty, $variant:ident, $push_func:ident) => {{
+                        let typed = self
+                            .as_any()
+                            .downcast_ref::<RunArray<$run_end_type>>()
+                            .ok_or_else(|| {
+                                error!("cannot convert array into run-end encoded array")
+                            })?;
+                        if typed.run_ends().offset() != 0 {
+                            fail!("RunEndEncoded arrays with non-zero offsets are not supported");
+                        }
+
+                        let run_ends = buffers.$push_func(typed.run_ends().values())?;
+                        let values = typed.values().extract_buffers(values_field, buffers)?;
+
+                        Ok(M::RunEndEncoded {
+                            field: field.clone(),
+                            validity: None,
+                            run_ends: RunEnds::$variant(run_ends),
+                            values: Box::new(values),
+                        })
+                    }};
+                }
+
+                match &run_ends_field.data_type {
+                    T::I16 => convert_run_end_encoded!(Int16Type, I16, push_u16_cast),
+                    T::I32 => convert_run_end_encoded!(Int32Type, I32, push_u32_cast),
+                    T::I64 => convert_run_end_encoded!(Int64Type, I64, push_u64_cast),
+                    dt => fail!("BufferExtract for run ends of type {dt} is not implemented"),
+                }
+            }
+            T::Union(_) => {
                 use crate::_impl::arrow::array::UnionArray;
 
                 // TODO: test assumptions
@@ -283,10 +440,15 @@ impl BufferExtract for dyn Array {
                     .ok_or_else(|| error!("cannot convert array to union array"))?;
 
                 let types = buffers.push_u8_cast(typed.type_ids())?;
+                let offsets = match typed.offsets() {
+                    Some(offsets) => Some(buffers.push_u32_cast(offsets)?),
+                    None => None,
+                };
 
                 let mut fields = Vec::new();
-                for (idx, field) in field.children.iter().enumerate() {
-                    let array = typed.child(idx.try_into()?);
+                for (type_id, field) in field.get_union_type_ids()?.into_iter().zip(&field.children)
+                {
+                    let array = typed.child(type_id);
                     fields.push(array.extract_buffers(field, buffers)?);
                 }
 
@@ -295,12 +457,34 @@ impl BufferExtract for dyn Array {
                     validity: None,
                     fields,
                     types,
+                    offsets,
                 })
             }
         }
     }
 }
 
+/// The value data of fixed size binary arrays
+///
+/// Before arrow 47 `FixedSizeBinaryArray::value_data` returns an owned buffer,
+/// which cannot be borrowed for the lifetime of the array. In this case the
+/// data is copied.
+trait ValueData<'a> {
+    fn into_bytes(self, start: usize) -> Cow<'a, [u8]>;
+}
+
+impl<'a> ValueData<'a> for &'a [u8] {
+    fn into_bytes(self, start: usize) -> Cow<'a, [u8]> {
+        Cow::Borrowed(&self[start..])
+    }
+}
+
+impl<'a> ValueData<'a> for Buffer {
+    fn into_bytes(self, start: usize) -> Cow<'a, [u8]> {
+        Cow::Owned(self.as_slice()[start..].to_vec())
+    }
+}
+
 fn get_validity(arr: &dyn Array) -> Option<BitBuffer<'_>> {
     let validity = arr.nulls()?;
     let data = validity.validity();
@@ -1,11 +1,14 @@
+use std::collections::HashMap;
+
 use super::type_support::FieldRef;
 use crate::{
-    _impl::arrow::datatypes::{DataType, Field, TimeUnit, UnionMode},
+    _impl::arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit, UnionMode},
     internal::{
         error::{error, fail, Error, Result},
         schema::{
-            GenericDataType, GenericField, GenericTimeUnit, SchemaLike, Sealed, SerdeArrowSchema,
-            Strategy, STRATEGY_KEY,
+            is_reserved_metadata_key, GenericDataType, GenericField, GenericIntervalUnit,
+            GenericTimeUnit, GenericUnionMode, SchemaLike, Sealed, SerdeArrowSchema, Strategy,
+            EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, STRATEGY_KEY,
         },
     },
 };
@@ -99,10 +102,50 @@ impl TryFrom<&DataType> for GenericDataType {
             DataType::Float64 => Ok(GenericDataType::F64),
             DataType::Utf8 => Ok(GenericDataType::Utf8),
             DataType::LargeUtf8 => Ok(GenericDataType::LargeUtf8),
+            DataType::Binary => Ok(GenericDataType::Binary),
+            DataType::LargeBinary => Ok(GenericDataType::LargeBinary),
+            DataType::FixedSizeBinary(n) => Ok(GenericDataType::FixedSizeBinary(*n)),
+            DataType::Date32 => Ok(GenericDataType::Date32),
             DataType::Date64 => Ok(GenericDataType::Date64),
+            DataType::Time32(TimeUnit::Second) => {
+                Ok(GenericDataType::Time32(GenericTimeUnit::Second))
+            }
+            DataType::Time32(TimeUnit::Millisecond) => {
+                Ok(GenericDataType::Time32(GenericTimeUnit::Millisecond))
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                Ok(GenericDataType::Time64(GenericTimeUnit::Microsecond))
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                Ok(GenericDataType::Time64(GenericTimeUnit::Nanosecond))
+            }
+            DataType::Duration(TimeUnit::Second) => {
+                Ok(GenericDataType::Duration(GenericTimeUnit::Second))
+            }
+            DataType::Duration(TimeUnit::Millisecond) => {
+                Ok(GenericDataType::Duration(GenericTimeUnit::Millisecond))
+            }
+            DataType::Duration(TimeUnit::Microsecond) => {
+                Ok(GenericDataType::Duration(GenericTimeUnit::Microsecond))
+            }
+            DataType::Duration(TimeUnit::Nanosecond) => {
+                Ok(GenericDataType::Duration(GenericTimeUnit::Nanosecond))
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                Ok(GenericDataType::Interval(GenericIntervalUnit::YearMonth))
+            }
+            DataType::Interval(IntervalUnit::DayTime) => {
+                Ok(GenericDataType::Interval(GenericIntervalUnit::DayTime))
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => {
+                Ok(GenericDataType::Interval(GenericIntervalUnit::MonthDayNano))
+            }
             DataType::Decimal128(precision, scale) => {
                 Ok(GenericDataType::Decimal128(*precision, *scale))
             }
+            DataType::Decimal256(precision, scale) => {
+                Ok(GenericDataType::Decimal256(*precision, *scale))
+            }
             DataType::Timestamp(TimeUnit::Second, tz) => Ok(GenericDataType::Timestamp(
                 GenericTimeUnit::Second,
                 tz.as_ref().map(|s| s.to_string()),
@@ -132,10 +175,20 @@ impl TryFrom<&Field> for GenericField {
             Some(strategy_str) => Some(strategy_str.parse::<Strategy>()?),
             None => None,
         };
+        let extension_name = field.metadata().get(EXTENSION_NAME_KEY).cloned();
+        let extension_metadata = field.metadata().get(EXTENSION_METADATA_KEY).cloned();
+        let metadata = field
+            .metadata()
+            .iter()
+            .filter(|(key, _)| !is_reserved_metadata_key(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
         let name = field.name().to_owned();
         let nullable = field.is_nullable();
 
         let mut children = Vec::<GenericField>::new();
+        let mut type_ids = None;
+        let mut sorted = false;
         let data_type = match field.data_type() {
             DataType::List(field) => {
                 children.push(GenericField::try_from(field.as_ref())?);
@@ -145,28 +198,34 @@ impl TryFrom<&Field> for GenericField {
                 children.push(field.as_ref().try_into()?);
                 GenericDataType::LargeList
             }
+            DataType::FixedSizeList(field, n) => {
+                children.push(field.as_ref().try_into()?);
+                GenericDataType::FixedSizeList(*n)
+            }
             DataType::Struct(fields) => {
                 for field in fields {
                     children.push(field.as_field_ref().try_into()?);
                 }
                 GenericDataType::Struct
             }
-            DataType::Map(field, _) => {
+            DataType::Map(field, keys_sorted) => {
                 children.push(field.as_ref().try_into()?);
+                sorted = *keys_sorted;
                 GenericDataType::Map
             }
             DataType::Union(fields, mode) => {
-                if !matches!(mode, UnionMode::Dense) {
-                    fail!("Only dense unions are supported at the moment");
-                }
-
-                for (pos, (idx, field)) in fields.iter().enumerate() {
-                    if pos as i8 != idx {
-                        fail!("Union types with explicit field indices are not supported");
-                    }
+                let mut ids = Vec::new();
+                for (idx, field) in fields.iter() {
+                    ids.push(idx);
                     children.push(field.as_ref().try_into()?);
                 }
-                GenericDataType::Union
+                if ids.iter().enumerate().any(|(pos, idx)| pos as i8 != *idx) {
+                    type_ids = Some(ids);
+                }
+                GenericDataType::Union(match mode {
+                    UnionMode::Sparse => GenericUnionMode::Sparse,
+                    UnionMode::Dense => GenericUnionMode::Dense,
+                })
             }
             DataType::Dictionary(key_type, value_type) => {
                 children.push(GenericField::new("", key_type.as_ref().try_into()?, false));
@@ -177,6 +236,11 @@ impl TryFrom<&Field> for GenericField {
                 ));
                 GenericDataType::Dictionary
             }
+            DataType::RunEndEncoded(run_ends, values) => {
+                children.push(run_ends.as_ref().try_into()?);
+                children.push(values.as_ref().try_into()?);
+                GenericDataType::RunEndEncoded
+            }
             dt => dt.try_into()?,
         };
 
@@ -186,7 +250,13 @@ impl TryFrom<&Field> for GenericField {
             strategy,
             children,
             nullable,
-        };
+            type_ids,
+            sorted,
+            extension_name,
+            extension_metadata,
+            metadata,
+        }
+        .with_extension_strategy();
         field.validate()?;
 
         Ok(field)
@@ -211,12 +281,53 @@ impl TryFrom<&GenericField> for Field {
             GenericDataType::F16 => DataType::Float16,
             GenericDataType::F32 => DataType::Float32,
             GenericDataType::F64 => DataType::Float64,
+            GenericDataType::Date32 => DataType::Date32,
             GenericDataType::Date64 => DataType::Date64,
+            GenericDataType::Time32(GenericTimeUnit::Second) => DataType::Time32(TimeUnit::Second),
+            GenericDataType::Time32(GenericTimeUnit::Millisecond) => {
+                DataType::Time32(TimeUnit::Millisecond)
+            }
+            GenericDataType::Time64(GenericTimeUnit::Microsecond) => {
+                DataType::Time64(TimeUnit::Microsecond)
+            }
+            GenericDataType::Time64(GenericTimeUnit::Nanosecond) => {
+                DataType::Time64(TimeUnit::Nanosecond)
+            }
+            dt @ (GenericDataType::Time32(_) | GenericDataType::Time64(_)) => {
+                fail!("Invalid time unit for {dt}")
+            }
+            GenericDataType::Duration(GenericTimeUnit::Second) => {
+                DataType::Duration(TimeUnit::Second)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Millisecond) => {
+                DataType::Duration(TimeUnit::Millisecond)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Microsecond) => {
+                DataType::Duration(TimeUnit::Microsecond)
+            }
+            GenericDataType::Duration(GenericTimeUnit::Nanosecond) => {
+                DataType::Duration(TimeUnit::Nanosecond)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::YearMonth) => {
+                DataType::Interval(IntervalUnit::YearMonth)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::DayTime) => {
+                DataType::Interval(IntervalUnit::DayTime)
+            }
+            GenericDataType::Interval(GenericIntervalUnit::MonthDayNano) => {
+                DataType::Interval(IntervalUnit::MonthDayNano)
+            }
             GenericDataType::Decimal128(precision, scale) => {
                 DataType::Decimal128(*precision, *scale)
             }
+            GenericDataType::Decimal256(precision, scale) => {
+                DataType::Decimal256(*precision, *scale)
+            }
             GenericDataType::Utf8 => DataType::Utf8,
             GenericDataType::LargeUtf8 => DataType::LargeUtf8,
+            GenericDataType::Binary => DataType::Binary,
+            GenericDataType::LargeBinary => DataType::LargeBinary,
+            GenericDataType::FixedSizeBinary(n) => DataType::FixedSizeBinary(*n),
             GenericDataType::List => DataType::List(
                 Box::<Field>::new(
                     value
@@ -237,6 +348,17 @@ impl TryFrom<&GenericField> for Field {
                 )
                 .into(),
             ),
+            GenericDataType::FixedSizeList(n) => DataType::FixedSizeList(
+                Box::<Field>::new(
+                    value
+                        .children
+                        .first()
+                        .ok_or_else(|| error!("FixedSizeList must a single child"))?
+                        .try_into()?,
+                )
+                .into(),
+                *n,
+            ),
             GenericDataType::Struct => DataType::Struct(
                 value
                     .children
@@ -250,28 +372,33 @@ impl TryFrom<&GenericField> for Field {
                     .first()
                     .ok_or_else(|| error!("Map must a single child"))?
                     .try_into()?;
-                DataType::Map(Box::new(element_field).into(), false)
+                DataType::Map(Box::new(element_field).into(), value.sorted)
             }
             #[cfg(not(feature = "arrow-36"))]
-            GenericDataType::Union => {
+            GenericDataType::Union(mode) => {
                 let mut fields = Vec::new();
-                for (idx, field) in value.children.iter().enumerate() {
-                    fields.push((idx as i8, std::sync::Arc::new(Field::try_from(field)?)));
+                for (type_id, field) in value.get_union_type_ids()?.into_iter().zip(&value.children)
+                {
+                    fields.push((type_id, std::sync::Arc::new(Field::try_from(field)?)));
                 }
-                DataType::Union(fields.into_iter().collect(), UnionMode::Dense)
+                let mode = match mode {
+                    GenericUnionMode::Sparse => UnionMode::Sparse,
+                    GenericUnionMode::Dense => UnionMode::Dense,
+                };
+                DataType::Union(fields.into_iter().collect(), mode)
             }
             #[cfg(feature = "arrow-36")]
-            GenericDataType::Union => DataType::Union(
+            GenericDataType::Union(mode) => DataType::Union(
                 value
                     .children
                     .iter()
                     .map(Field::try_from)
                     .collect::<Result<Vec<_>>>()?,
-                (0..value.children.len())
-                    .into_iter()
-                    .map(|v| v as i8)
-                    .collect(),
-                UnionMode::Dense,
+                value.get_union_type_ids()?,
+                match mode {
+                    GenericUnionMode::Sparse => UnionMode::Sparse,
+                    GenericUnionMode::Dense => UnionMode::Dense,
+                },
             ),
             GenericDataType::Dictionary => {
                 let Some(key_field) = value.children.first() else {
@@ -297,6 +424,18 @@ impl TryFrom<&GenericField> for Field {
 
                 DataType::Dictionary(Box::new(key_type), Box::new(val_field.data_type().clone()))
             }
+            GenericDataType::RunEndEncoded => {
+                let Some(run_ends_field) = value.children.first() else {
+                    fail!("RunEndEncoded must have two children");
+                };
+                let Some(values_field) = value.children.get(1) else {
+                    fail!("RunEndEncoded must have two children");
+                };
+                DataType::RunEndEncoded(
+                    std::sync::Arc::new(run_ends_field.try_into()?),
+                    std::sync::Arc::new(values_field.try_into()?),
+                )
+            }
             GenericDataType::Timestamp(GenericTimeUnit::Second, tz) => {
                 DataType::Timestamp(TimeUnit::Second, tz.clone().map(|s| s.into()))
             }
@@ -311,9 +450,27 @@ impl TryFrom<&GenericField> for Field {
             }
         };
 
-        let mut field = Field::new(&value.name, data_type, value.nullable);
+        let mut metadata = value
+            .metadata
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect::<HashMap<_, _>>();
         if let Some(strategy) = value.strategy.as_ref() {
-            field.set_metadata(strategy.clone().into());
+            metadata.insert(STRATEGY_KEY.to_string(), strategy.to_string());
+        }
+        if let Some(extension_name) = value.extension_name.as_ref() {
+            metadata.insert(EXTENSION_NAME_KEY.to_string(), extension_name.clone());
+        }
+        if let Some(extension_metadata) = value.extension_metadata.as_ref() {
+            metadata.insert(
+                EXTENSION_METADATA_KEY.to_string(),
+                extension_metadata.clone(),
+            );
+        }
+
+        let mut field = Field::new(&value.name, data_type, value.nullable);
+        if !metadata.is_empty() {
+            field.set_metadata(metadata);
         }
 
         Ok(field)
@@ -5,12 +5,16 @@ use crate::{
     _impl::arrow::{
         array::{make_array, Array, ArrayData, ArrayRef, NullArray},
         buffer::{Buffer, ScalarBuffer},
-        datatypes::{ArrowNativeType, ArrowPrimitiveType, DataType, Field, Float16Type},
+        datatypes::{
+            i256, ArrowNativeType, ArrowPrimitiveType, DataType, Field, Float16Type,
+            IntervalDayTimeType, IntervalMonthDayNanoType, IntervalUnit,
+        },
     },
     internal::{
         common::MutableBitBuffer,
-        error::{fail, Result},
-        serialization_ng::ArrayBuilder,
+        error::{error, fail, Result},
+        schema::{GenericDataType, GenericIntervalUnit, GenericUnionMode},
+        serialization_ng::{interval_builder::IntervalBuilder, ArrayBuilder},
     },
 };
 
@@ -34,7 +38,7 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
     use {ArrayBuilder as A, DataType as T};
     match builder {
         A::Null(builder) => Ok(NullArray::new(builder.count).into_data()),
-        A::UnknownVariant(_) => Ok(NullArray::new(0).into_data()),
+        A::UnknownVariant(builder) => Ok(NullArray::new(builder.count).into_data()),
         A::Bool(builder) => build_array_data_primitive_with_len(
             T::Boolean,
             builder.buffer.len(),
@@ -60,16 +64,44 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
         ),
         A::F32(builder) => build_array_data_primitive(T::Float32, builder.buffer, builder.validity),
         A::F64(builder) => build_array_data_primitive(T::Float64, builder.buffer, builder.validity),
+        A::Date32(builder) => {
+            build_array_data_primitive(T::Date32, builder.buffer, builder.validity)
+        }
         A::Date64(builder) => build_array_data_primitive(
             Field::try_from(&builder.field)?.data_type().clone(),
             builder.buffer,
             builder.validity,
         ),
+        A::Time32(builder) => build_array_data_primitive(
+            Field::try_from(&builder.field)?.data_type().clone(),
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Time64(builder) => build_array_data_primitive(
+            Field::try_from(&builder.field)?.data_type().clone(),
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Duration(builder) => build_array_data_primitive(
+            Field::try_from(&builder.field)?.data_type().clone(),
+            builder.buffer,
+            builder.validity,
+        ),
+        A::Interval(builder) => build_array_data_interval(builder),
         A::Decimal128(builder) => build_array_data_primitive(
             T::Decimal128(builder.precision, builder.scale),
             builder.buffer,
             builder.validity,
         ),
+        A::Decimal256(builder) => build_array_data_primitive(
+            T::Decimal256(builder.precision, builder.scale),
+            builder
+                .buffer
+                .into_iter()
+                .map(i256::from_le_bytes)
+                .collect::<Vec<_>>(),
+            builder.validity,
+        ),
         A::Utf8(builder) => build_array_data_utf8(
             T::Utf8,
             builder.offsets.offsets,
@@ -82,6 +114,24 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
             builder.buffer,
             builder.validity,
         ),
+        A::Binary(builder) => build_array_data_utf8(
+            T::Binary,
+            builder.offsets.offsets,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::LargeBinary(builder) => build_array_data_utf8(
+            T::LargeBinary,
+            builder.offsets.offsets,
+            builder.buffer,
+            builder.validity,
+        ),
+        A::FixedSizeBinary(builder) => build_array_data_primitive_with_len(
+            T::FixedSizeBinary(i32::try_from(builder.n)?),
+            builder.len,
+            builder.buffer,
+            builder.validity,
+        ),
         A::LargeList(builder) => build_array_data_list(
             T::LargeList(Arc::new(Field::try_from(&builder.field)?)),
             builder.offsets.offsets.len() - 1,
@@ -96,6 +146,14 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
             build_array_data(*builder.element)?,
             builder.validity,
         ),
+        A::FixedSizeList(builder) => Ok(ArrayData::builder(T::FixedSizeList(
+            Arc::new(Field::try_from(&builder.field)?),
+            i32::try_from(builder.n)?,
+        ))
+        .len(builder.len)
+        .add_child_data(build_array_data(*builder.element)?)
+        .null_bit_buffer(builder.validity.map(|b| Buffer::from(b.buffer)))
+        .build()?),
         A::Struct(builder) => {
             let mut data = Vec::new();
             for (_, field) in builder.named_fields {
@@ -126,14 +184,14 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
         }
         A::Map(builder) => Ok(ArrayData::builder(T::Map(
             Arc::new(Field::try_from(&builder.entry_field)?),
-            false,
+            builder.sorted,
         ))
         .len(builder.offsets.offsets.len() - 1)
         .add_buffer(ScalarBuffer::from(builder.offsets.offsets).into_inner())
         .add_child_data(build_array_data(*builder.entry)?)
         .null_bit_buffer(builder.validity.map(|b| Buffer::from(b.buffer)))
         .build()?),
-        A::DictionaryUtf8(builder) => {
+        A::Dictionary(builder) => {
             let indices = build_array_data(*builder.indices)?;
             let values = build_array_data(*builder.values)?;
             let data_type = Field::try_from(&builder.field)?.data_type().clone();
@@ -144,6 +202,23 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
                 .child_data(vec![values])
                 .build()?)
         }
+        A::RunEndEncoded(builder) => {
+            let data_type = Field::try_from(&builder.field)?.data_type().clone();
+            let len = usize::try_from(builder.run_ends.last().copied().unwrap_or_default())?;
+            let run_ends = match builder.field.children.first().map(|f| &f.data_type) {
+                Some(GenericDataType::I16) => build_run_ends::<i16>(T::Int16, builder.run_ends)?,
+                Some(GenericDataType::I32) => build_run_ends::<i32>(T::Int32, builder.run_ends)?,
+                Some(GenericDataType::I64) => build_run_ends::<i64>(T::Int64, builder.run_ends)?,
+                Some(dt) => fail!("Invalid run ends type {dt} for RunEndEncoded"),
+                None => fail!("RunEndEncoded must have two children"),
+            };
+            let values = build_array_data(*builder.values)?;
+
+            Ok(ArrayData::builder(data_type)
+                .len(len)
+                .child_data(vec![run_ends, values])
+                .build()?)
+        }
         A::Union(builder) => {
             let data_type = Field::try_from(&builder.field)?.data_type().clone();
             let children = builder
@@ -153,12 +228,13 @@ fn build_array_data(builder: ArrayBuilder) -> Result<ArrayData> {
                 .collect::<Result<Vec<_>>>()?;
             let len = builder.types.len();
 
-            Ok(ArrayData::builder(data_type)
+            let mut data = ArrayData::builder(data_type)
                 .len(len)
-                .add_buffer(Buffer::from_vec(builder.types))
-                .add_buffer(Buffer::from_vec(builder.offsets))
-                .child_data(children)
-                .build()?)
+                .add_buffer(Buffer::from_vec(builder.types));
+            if matches!(builder.mode, GenericUnionMode::Dense) {
+                data = data.add_buffer(Buffer::from_vec(builder.offsets));
+            }
+            Ok(data.child_data(children).build()?)
         }
     }
 }
@@ -188,6 +264,58 @@ fn build_array_data_primitive_with_len<T: ArrowNativeType>(
     )?)
 }
 
+fn build_run_ends<T: ArrowNativeType + TryFrom<i64>>(
+    data_type: DataType,
+    run_ends: Vec<i64>,
+) -> Result<ArrayData> {
+    let run_ends = run_ends
+        .into_iter()
+        .map(|run_end| {
+            T::try_from(run_end)
+                .map_err(|_| error!("Run end {run_end} exceeds the range of {data_type}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    build_array_data_primitive(data_type, run_ends, None)
+}
+
+fn build_array_data_interval(builder: IntervalBuilder) -> Result<ArrayData> {
+    match builder.unit {
+        GenericIntervalUnit::YearMonth => build_array_data_primitive(
+            DataType::Interval(IntervalUnit::YearMonth),
+            builder.months,
+            builder.validity,
+        ),
+        GenericIntervalUnit::DayTime => {
+            let buffer = std::iter::zip(builder.days, builder.time)
+                .map(|(days, millis)| {
+                    Ok(IntervalDayTimeType::make_value(
+                        days,
+                        i32::try_from(millis)?,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            build_array_data_primitive(
+                DataType::Interval(IntervalUnit::DayTime),
+                buffer,
+                builder.validity,
+            )
+        }
+        GenericIntervalUnit::MonthDayNano => {
+            let buffer = std::iter::zip(builder.months, builder.days)
+                .zip(builder.time)
+                .map(|((months, days), nanos)| {
+                    IntervalMonthDayNanoType::make_value(months, days, nanos)
+                })
+                .collect::<Vec<_>>();
+            build_array_data_primitive(
+                DataType::Interval(IntervalUnit::MonthDayNano),
+                buffer,
+                builder.validity,
+            )
+        }
+    }
+}
+
 fn build_array_data_utf8<O: ArrowNativeType>(
     data_type: DataType,
     offsets: Vec<O>,
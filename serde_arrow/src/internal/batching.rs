@@ -0,0 +1,97 @@
+//! The batching logic shared by the batch builders of both backends
+//!
+use serde::Serialize;
+
+use crate::internal::{error::Result, serialization_ng::ArrayBuilder};
+
+/// The backend specific part of a batch builder
+pub trait BatchBackend {
+    type Batch;
+
+    fn array_builder(&mut self) -> &mut ArrayBuilder;
+
+    /// Build a batch from the rows pushed so far and reset the buffers
+    fn build_batch(&mut self) -> Result<Self::Batch>;
+}
+
+/// A callback receiving the completed batches
+pub type BatchCallback<'a, T> = Box<dyn FnMut(T) -> Result<()> + Send + 'a>;
+
+/// Complete batches, whenever the number of rows or bytes reach the limits
+///
+/// Completed batches are either passed to the callback or queued until they
+/// are returned from [`extend`][BatchBuilder::extend] or
+/// [`flush`][BatchBuilder::flush]. If a record fails to serialize, the queued
+/// batches are kept, but the rows of the incomplete batch are discarded, as the
+/// failing record may have been written partially.
+pub struct BatchBuilder<'a, B: BatchBackend> {
+    pub backend: B,
+    pub max_rows: Option<usize>,
+    pub max_bytes: Option<usize>,
+    pub callback: Option<BatchCallback<'a, B::Batch>>,
+    num_rows: usize,
+    completed: Vec<B::Batch>,
+}
+
+impl<'a, B: BatchBackend> BatchBuilder<'a, B> {
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            max_rows: None,
+            max_bytes: None,
+            callback: None,
+            num_rows: 0,
+            completed: Vec::new(),
+        }
+    }
+
+    pub fn extend<I>(&mut self, items: I) -> Result<Vec<B::Batch>>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        for item in items {
+            if let Err(err) = self.backend.array_builder().extend(&[item]) {
+                self.backend.array_builder().take();
+                self.num_rows = 0;
+                return Err(err);
+            }
+            self.num_rows += 1;
+
+            if self.is_full()? {
+                self.complete_batch()?;
+            }
+        }
+        Ok(std::mem::take(&mut self.completed))
+    }
+
+    pub fn flush(&mut self) -> Result<Vec<B::Batch>> {
+        if self.num_rows != 0 {
+            self.complete_batch()?;
+        }
+        Ok(std::mem::take(&mut self.completed))
+    }
+
+    fn complete_batch(&mut self) -> Result<()> {
+        let batch = self.backend.build_batch()?;
+        self.num_rows = 0;
+
+        match self.callback.as_mut() {
+            Some(callback) => callback(batch),
+            None => {
+                self.completed.push(batch);
+                Ok(())
+            }
+        }
+    }
+
+    fn is_full(&mut self) -> Result<bool> {
+        if matches!(self.max_rows, Some(max_rows) if self.num_rows >= max_rows) {
+            return Ok(true);
+        }
+        match self.max_bytes {
+            Some(max_bytes) => Ok(self.backend.array_builder().num_record_bytes()? >= max_bytes),
+            None => Ok(false),
+        }
+    }
+}
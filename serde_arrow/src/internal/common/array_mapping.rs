@@ -1,4 +1,7 @@
-use crate::internal::schema::GenericField;
+use crate::internal::{
+    error::{fail, Result},
+    schema::GenericField,
+};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DictionaryIndex {
@@ -14,8 +17,82 @@ pub enum DictionaryIndex {
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DictionaryValue {
+    U8 { buffer: usize },
+    U16 { buffer: usize },
+    U32 { buffer: usize },
+    U64 { buffer: usize },
+    I8 { buffer: usize },
+    I16 { buffer: usize },
+    I32 { buffer: usize },
+    I64 { buffer: usize },
+    F16 { buffer: usize },
+    F32 { buffer: usize },
+    F64 { buffer: usize },
     Utf8 { buffer: usize, offsets: usize },
     LargeUtf8 { buffer: usize, offsets: usize },
+    Binary { buffer: usize, offsets: usize },
+    LargeBinary { buffer: usize, offsets: usize },
+}
+
+impl DictionaryValue {
+    /// Get the buffers of the values of a dictionary from its array mapping
+    pub fn try_from_mapping(mapping: ArrayMapping) -> Result<Self> {
+        use ArrayMapping as M;
+        Ok(match mapping {
+            M::U8 { buffer, .. } => Self::U8 { buffer },
+            M::U16 { buffer, .. } => Self::U16 { buffer },
+            M::U32 { buffer, .. } => Self::U32 { buffer },
+            M::U64 { buffer, .. } => Self::U64 { buffer },
+            M::I8 { buffer, .. } => Self::I8 { buffer },
+            M::I16 { buffer, .. } => Self::I16 { buffer },
+            M::I32 { buffer, .. } => Self::I32 { buffer },
+            M::I64 { buffer, .. } => Self::I64 { buffer },
+            M::F16 { buffer, .. } => Self::F16 { buffer },
+            M::F32 { buffer, .. } => Self::F32 { buffer },
+            M::F64 { buffer, .. } => Self::F64 { buffer },
+            M::Utf8 {
+                buffer, offsets, ..
+            } => Self::Utf8 { buffer, offsets },
+            M::LargeUtf8 {
+                buffer, offsets, ..
+            } => Self::LargeUtf8 { buffer, offsets },
+            M::Binary {
+                buffer, offsets, ..
+            } => Self::Binary { buffer, offsets },
+            M::LargeBinary {
+                buffer, offsets, ..
+            } => Self::LargeBinary { buffer, offsets },
+            m => fail!("dictionaries with values of type {m:?} are not supported"),
+        })
+    }
+}
+
+/// The buffer of the run ends of a run-end encoded array
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RunEnds {
+    I16(usize),
+    I32(usize),
+    I64(usize),
+}
+
+/// The in-memory layout of interval values
+///
+/// arrow packs the components of an interval into a single integer, whereas
+/// arrow2 stores them as a C struct.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IntervalLayout {
+    /// Months as a `u32` buffer
+    YearMonth,
+    /// Days and milliseconds as pairs of consecutive entries in a `u32` buffer
+    DayTime,
+    /// Days and milliseconds packed into a `u64` buffer, days in the upper bits
+    PackedDayTime,
+    /// Months and days packed into the first, nanoseconds stored in the second
+    /// of pairs of consecutive entries in a `u64` buffer
+    MonthDayNano,
+    /// Months, days and nanoseconds packed into a `u128` buffer, months in the
+    /// upper bits
+    PackedMonthDayNano,
 }
 
 macro_rules! define_array_mapping {
@@ -27,7 +104,7 @@ macro_rules! define_array_mapping {
         )*
     ) => {
         /// Map an array to its corresponding buffers
-        #[derive(Debug, Clone)]
+        #[derive(Debug, Clone, PartialEq)]
         pub enum ArrayMapping {
             $(
                 $variant {
@@ -98,6 +175,9 @@ define_array_mapping!(
     Decimal128 {
         buffer: usize,
     },
+    Decimal256 {
+        buffer: usize,
+    },
     Utf8 {
         buffer: usize,
         offsets: usize,
@@ -106,9 +186,37 @@ define_array_mapping!(
         buffer: usize,
         offsets: usize,
     },
+    Binary {
+        buffer: usize,
+        offsets: usize,
+    },
+    LargeBinary {
+        buffer: usize,
+        offsets: usize,
+    },
+    FixedSizeBinary {
+        buffer: usize,
+        n: usize,
+    },
+    Date32 {
+        buffer: usize,
+    },
     Date64 {
         buffer: usize,
     },
+    Time32 {
+        buffer: usize,
+    },
+    Time64 {
+        buffer: usize,
+    },
+    Duration {
+        buffer: usize,
+    },
+    Interval {
+        buffer: usize,
+        layout: IntervalLayout,
+    },
     List {
         item: Box<ArrayMapping>,
         offsets: usize,
@@ -116,17 +224,27 @@ define_array_mapping!(
     Dictionary {
         dictionary: DictionaryValue,
         indices: DictionaryIndex,
+        values_validity: Option<usize>,
+    },
+    RunEndEncoded {
+        run_ends: RunEnds,
+        values: Box<ArrayMapping>,
     },
     LargeList {
         item: Box<ArrayMapping>,
         offsets: usize,
     },
+    FixedSizeList {
+        item: Box<ArrayMapping>,
+        n: usize,
+    },
     Struct {
         fields: Vec<ArrayMapping>,
     },
     Union {
         fields: Vec<ArrayMapping>,
         types: usize,
+        offsets: Option<usize>,
     },
     Map {
         offsets: usize,
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{borrow::Cow, sync::Arc};
 
 use bytemuck::NoUninit;
 
@@ -47,11 +47,16 @@ impl<T: BufferExtract + ?Sized> BufferExtract for Arc<T> {
 pub struct Buffers<'a> {
     pub u0: Vec<usize>,
     pub u1: Vec<BitBuffer<'a>>,
-    pub u8: Vec<&'a [u8]>,
+    /// Bytes, owned if the array does not allow to borrow its data
+    pub u8: Vec<Cow<'a, [u8]>>,
     pub u16: Vec<&'a [u16]>,
     pub u32: Vec<&'a [u32]>,
     pub u64: Vec<&'a [u64]>,
     pub u128: Vec<&'a [u128]>,
+    /// 256 bit integers in little endian byte order
+    pub u256: Vec<&'a [[u8; 32]]>,
+    /// Strings owned by the buffers, e.g., field names
+    pub str: Vec<String>,
 }
 
 impl<'a> Buffers<'a> {
@@ -70,6 +75,10 @@ impl<'a> Buffers<'a> {
     }
 
     pub fn push_u8(&mut self, val: &'a [u8]) -> usize {
+        self.push_u8_cow(Cow::Borrowed(val))
+    }
+
+    pub fn push_u8_cow(&mut self, val: Cow<'a, [u8]>) -> usize {
         self.u8.push(val);
         self.u8.len() - 1
     }
@@ -93,6 +102,16 @@ impl<'a> Buffers<'a> {
         self.u128.push(val);
         self.u128.len() - 1
     }
+
+    pub fn push_u256(&mut self, val: &'a [[u8; 32]]) -> usize {
+        self.u256.push(val);
+        self.u256.len() - 1
+    }
+
+    pub fn push_str(&mut self, val: &str) -> usize {
+        self.str.push(val.to_owned());
+        self.str.len() - 1
+    }
 }
 
 impl<'a> Buffers<'a> {
@@ -115,11 +134,15 @@ impl<'a> Buffers<'a> {
     pub fn push_u128_cast<T: NoUninit>(&mut self, val: &'a [T]) -> Result<usize> {
         Ok(self.push_u128(bytemuck::try_cast_slice::<T, u128>(val)?))
     }
+
+    pub fn push_u256_cast<T: NoUninit>(&mut self, val: &'a [T]) -> Result<usize> {
+        Ok(self.push_u256(bytemuck::try_cast_slice::<T, [u8; 32]>(val)?))
+    }
 }
 
 impl<'a> Buffers<'a> {
-    pub fn get_u8(&self, idx: usize) -> &'a [u8] {
-        self.u8[idx]
+    pub fn get_u8(&self, idx: usize) -> &[u8] {
+        &self.u8[idx]
     }
 
     pub fn get_u16(&self, idx: usize) -> &'a [u16] {
@@ -134,8 +157,8 @@ impl<'a> Buffers<'a> {
         self.u64[idx]
     }
 
-    pub fn get_i8(&self, idx: usize) -> &'a [i8] {
-        bytemuck::cast_slice(self.u8[idx])
+    pub fn get_i8(&self, idx: usize) -> &[i8] {
+        bytemuck::cast_slice(&self.u8[idx])
     }
 
     pub fn get_i16(&self, idx: usize) -> &'a [i16] {
@@ -149,6 +172,10 @@ impl<'a> Buffers<'a> {
     pub fn get_i64(&self, idx: usize) -> &'a [i64] {
         bytemuck::cast_slice(self.u64[idx])
     }
+
+    pub fn get_str(&self, idx: usize) -> &str {
+        &self.str[idx]
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -206,6 +233,11 @@ impl MutableBitBuffer {
         self.len += 1;
     }
 
+    /// The number of bytes required to store the pushed bits
+    pub fn num_bytes(&self) -> usize {
+        self.len.div_ceil(8)
+    }
+
     pub fn clear(&mut self) {
         *self = Self::default();
     }
@@ -266,6 +298,11 @@ impl<O: Offset> MutableOffsetBuffer<O> {
         Ok(())
     }
 
+    /// The number of bytes required to store the offsets
+    pub fn num_bytes(&self) -> usize {
+        std::mem::size_of_val(self.offsets.as_slice())
+    }
+
     pub fn clear(&mut self) {
         *self = Self::default();
     }
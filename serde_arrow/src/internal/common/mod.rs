@@ -3,7 +3,7 @@ mod array_mapping;
 mod buffers;
 mod checks;
 
-pub use array_mapping::{ArrayMapping, DictionaryIndex, DictionaryValue};
+pub use array_mapping::{ArrayMapping, DictionaryIndex, DictionaryValue, IntervalLayout, RunEnds};
 #[allow(unused)]
 pub use buffers::{
     BitBuffer, BufferExtract, Buffers, MutableBitBuffer, MutableOffsetBuffer, Offset,
@@ -1,7 +1,10 @@
-use std::sync::RwLock;
+use std::{collections::BTreeMap, sync::RwLock};
+
+use crate::internal::schema::Strategy;
 
 pub static CONFIGURATION: RwLock<Configuration> = RwLock::new(Configuration {
     debug_print_program: false,
+    extension_strategies: BTreeMap::new(),
     _prevent_construction: (),
 });
 
@@ -9,6 +12,9 @@ pub static CONFIGURATION: RwLock<Configuration> = RwLock::new(Configuration {
 #[derive(Default, Clone)]
 pub struct Configuration {
     pub(crate) debug_print_program: bool,
+    /// The strategies registered for extension types, see
+    /// [register_extension_strategy][crate::schema::register_extension_strategy]
+    pub(crate) extension_strategies: BTreeMap<String, Strategy>,
     /// A non public member to allow extending the member list as non-breaking
     /// changes
     _prevent_construction: (),
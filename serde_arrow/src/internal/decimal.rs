@@ -7,6 +7,7 @@
 use crate::internal::error::{fail, Result};
 
 pub const BUFFER_SIZE_I128: usize = 64;
+pub const BUFFER_SIZE_I256: usize = 256;
 
 /// Helper to parse decimals
 ///
@@ -49,6 +50,20 @@ impl DecimalParser {
         Ok(val)
     }
 
+    /// Parse a decimal into a 256 bit integer in little endian byte order
+    pub fn parse_decimal256(self, buffer: &mut [u8], s: &[u8]) -> Result<[u8; 32]> {
+        let (s, sign) = parse_sign(s);
+        let digits = self.copy_digits(buffer, s)?;
+        let mut limbs = parse_u256_digits(digits)?;
+        if limbs[3] >> 63 != 0 {
+            fail!("invalid decimal: the value does not fit into 256 bits");
+        }
+        if matches!(sign, Sign::Minus) {
+            negate_u256(&mut limbs);
+        }
+        Ok(u256_to_bytes(limbs))
+    }
+
     pub fn copy_digits<'b>(self, buffer: &'b mut [u8], s: &[u8]) -> Result<&'b str> {
         use DecimalParser::*;
         match self {
@@ -96,6 +111,55 @@ impl Sign {
     }
 }
 
+/// Parse ASCII digits into the little endian `u64` limbs of an unsigned 256
+/// bit integer
+fn parse_u256_digits(s: &str) -> Result<[u64; 4]> {
+    if s.is_empty() {
+        fail!("invalid decimal: missing digits");
+    }
+
+    let mut limbs = [0_u64; 4];
+    for digit in s.bytes() {
+        if !digit.is_ascii_digit() {
+            fail!("invalid decimal");
+        }
+        let mut carry = u128::from(digit - b'0');
+        for limb in &mut limbs {
+            let val = u128::from(*limb) * 10 + carry;
+            *limb = val as u64;
+            carry = val >> 64;
+        }
+        if carry != 0 {
+            fail!("invalid decimal: the value does not fit into 256 bits");
+        }
+    }
+    Ok(limbs)
+}
+
+/// Compute the two's complement of the little endian `u64` limbs
+fn negate_u256(limbs: &mut [u64; 4]) {
+    let mut carry = true;
+    for limb in limbs {
+        (*limb, carry) = (!*limb).overflowing_add(u64::from(carry));
+    }
+}
+
+fn u256_to_bytes(limbs: [u64; 4]) -> [u8; 32] {
+    let mut res = [0; 32];
+    for (chunk, limb) in res.chunks_exact_mut(8).zip(limbs) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    res
+}
+
+fn u256_from_bytes(bytes: [u8; 32]) -> [u64; 4] {
+    let mut res = [0; 4];
+    for (limb, chunk) in res.iter_mut().zip(bytes.chunks_exact(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    res
+}
+
 fn copy_digits_integer_only<'b>(
     buffer: &'b mut [u8],
     s: &[u8],
@@ -366,21 +430,78 @@ pub fn format_decimal(buffer: &mut [u8], val: i128, scale: i8) -> &str {
         initial_length - buffer.len()
     }
 
+    let num_bytes_written = write_val(buffer, val);
+    format_scaled(buffer, num_bytes_written, val < 0, val == 0, scale)
+}
+
+/// Format a 256 bit integer in little endian byte order as a decimal
+pub fn format_decimal256(buffer: &mut [u8], val: [u8; 32], scale: i8) -> &str {
+    let mut limbs = u256_from_bytes(val);
+    let is_negative = limbs[3] >> 63 != 0;
+    if is_negative {
+        negate_u256(&mut limbs);
+    }
+    let is_zero = limbs == [0; 4];
+
+    // collect the digits in reverse order by repeated division by 10
+    let mut digits = [0_u8; 80];
+    let mut num_digits = 0;
+    loop {
+        let mut rem = 0_u128;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 64) | u128::from(*limb);
+            *limb = (cur / 10) as u64;
+            rem = cur % 10;
+        }
+        digits[num_digits] = b'0' + rem as u8;
+        num_digits += 1;
+
+        if limbs == [0; 4] {
+            break;
+        }
+    }
+
+    let num_sign_bytes = usize::from(is_negative);
+    if is_negative {
+        buffer[0] = b'-';
+    }
+    for (dst, src) in buffer[num_sign_bytes..][..num_digits]
+        .iter_mut()
+        .zip(digits[..num_digits].iter().rev())
+    {
+        *dst = *src;
+    }
+
+    format_scaled(
+        buffer,
+        num_sign_bytes + num_digits,
+        is_negative,
+        is_zero,
+        scale,
+    )
+}
+
+/// Insert the decimal point into a formatted integer of `num_bytes_written`
+/// bytes at the start of the buffer
+fn format_scaled(
+    buffer: &mut [u8],
+    num_bytes_written: usize,
+    is_negative: bool,
+    is_zero: bool,
+    scale: i8,
+) -> &str {
     let res = if scale == 0 {
-        let num_bytes_written = write_val(buffer, val);
         &buffer[..num_bytes_written]
-    } else if scale < 0 && val == 0 {
+    } else if scale < 0 && is_zero {
         b"0"
     } else if scale < 0 {
         let scale = -scale as usize;
-        let num_bytes_written = write_val(buffer, val);
 
         buffer[num_bytes_written..][..scale].fill(b'0');
         &buffer[..num_bytes_written + scale]
     } else {
         let scale = scale as usize;
-        let num_bytes_written = write_val(buffer, val);
-        let num_sign_bytes = if val >= 0 { 0 } else { 1 };
+        let num_sign_bytes = if is_negative { 1 } else { 0 };
         let num_digits_written = num_bytes_written - num_sign_bytes;
 
         if num_digits_written <= scale {
@@ -449,3 +570,49 @@ fn test_format_decimal() {
 
     assert_eq!(format_decimal_str(12345, 3), "12.345");
 }
+
+#[test]
+fn test_decimal256_roundtrip() {
+    fn roundtrip(s: &str, precision: u8, scale: i8) -> String {
+        let mut buffer = [0; BUFFER_SIZE_I256];
+        let val = DecimalParser::new(precision, scale, false)
+            .parse_decimal256(&mut buffer, s.as_bytes())
+            .unwrap();
+
+        let mut buffer = [0; BUFFER_SIZE_I256];
+        format_decimal256(&mut buffer, val, scale).to_owned()
+    }
+
+    assert_eq!(roundtrip("0", 5, 0), "0");
+    assert_eq!(roundtrip("-123", 5, 0), "-123");
+    assert_eq!(roundtrip("-1.23", 5, 2), "-1.23");
+    assert_eq!(roundtrip("0.05", 5, 3), "0.050");
+    assert_eq!(roundtrip("12300", 5, -2), "12300");
+
+    let max = "9".repeat(76);
+    assert_eq!(roundtrip(&max, 76, 0), max);
+    assert_eq!(roundtrip(&format!("-{max}"), 76, 0), format!("-{max}"));
+
+    let large = "123456789012345678901234567890123456789012345.678901234567890";
+    assert_eq!(roundtrip(large, 60, 15), large);
+}
+
+#[test]
+fn test_decimal256_byte_order() {
+    let mut buffer = [0; BUFFER_SIZE_I256];
+    let parser = DecimalParser::new(76, 0, false);
+
+    let val = parser.parse_decimal256(&mut buffer, b"1").unwrap();
+    assert_eq!(val[0], 1);
+    assert!(val[1..].iter().all(|b| *b == 0));
+
+    let val = parser.parse_decimal256(&mut buffer, b"-1").unwrap();
+    assert!(val.iter().all(|b| *b == 0xff));
+
+    // 2^128
+    let val = parser
+        .parse_decimal256(&mut buffer, b"340282366920938463463374607431768211456")
+        .unwrap();
+    assert_eq!(val[16], 1);
+    assert!(val[..16].iter().chain(&val[17..]).all(|b| *b == 0));
+}
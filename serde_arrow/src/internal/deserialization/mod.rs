@@ -1,17 +1,23 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+use serde::Deserialize;
 
 use crate::{
     internal::{
         error::{error, fail, Result},
         event::Event,
-        schema::GenericDataType,
-        source::EventSource,
+        schema::{GenericDataType, GenericField, GenericTimeUnit, GenericUnionMode},
+        source::{deserialize_item_from_source, EventSource},
+        timezone::Timezone,
     },
     schema::Strategy,
 };
 
 use super::{
-    common::{define_bytecode, ArrayMapping, Buffers, DictionaryIndex, DictionaryValue},
+    common::{
+        define_bytecode, ArrayMapping, BufferExtract, Buffers, DictionaryIndex, DictionaryValue,
+        IntervalLayout, RunEnds,
+    },
     config::CONFIGURATION,
     decimal,
 };
@@ -26,45 +32,90 @@ pub struct CompilationOptions {}
 
 pub fn compile_deserialization<'a>(
     num_items: usize,
-    arrays: &'a [ArrayMapping],
+    arrays: &[ArrayMapping],
     buffers: Buffers<'a>,
-) -> Result<Interpreter<'a>> {
+) -> Result<Program<'a>> {
     let mut compiler = Compiler {
         num_items,
-        arrays,
         buffers,
         num_positions: 0,
+        outer_sequence_count: 0,
         program: Vec::new(),
     };
-    compiler.compile()?;
+    compiler.compile(arrays)?;
 
     let current_config = CONFIGURATION.read().unwrap().clone();
     if current_config.debug_print_program {
         println!("Program: {program:?}", program = compiler.program);
     }
 
-    Ok(compiler.into_program())
+    Ok(compiler.into_program(arrays))
+}
+
+/// Compile one program per chunk of arrays
+///
+/// The bytecode of the previous chunk is reused, if the arrays of a chunk have
+/// the same layout. Without any chunks, a single program without items is
+/// returned.
+pub fn compile_chunks<'a, A: BufferExtract + ?Sized + 'a>(
+    fields: &[GenericField],
+    chunks: impl IntoIterator<Item = Vec<&'a A>>,
+) -> Result<Vec<Program<'a>>> {
+    let mut programs: Vec<Program<'a>> = Vec::new();
+    for arrays in chunks {
+        if arrays.len() != fields.len() {
+            fail!(
+                "Expected {} arrays per chunk, found {}",
+                fields.len(),
+                arrays.len()
+            );
+        }
+
+        let num_items = arrays.first().map(|a| a.len()).unwrap_or_default();
+        if arrays.iter().any(|a| a.len() != num_items) {
+            fail!("All arrays of a chunk must have the same length");
+        }
+
+        let mut buffers = Buffers::new();
+        let mut mappings = Vec::new();
+        for (field, array) in fields.iter().zip(arrays) {
+            mappings.push(array.extract_buffers(field, &mut buffers)?);
+        }
+
+        let program = match programs.last() {
+            Some(prev) => prev.for_chunk(num_items, &mappings, buffers)?,
+            None => compile_deserialization(num_items, &mappings, buffers)?,
+        };
+        programs.push(program);
+    }
+
+    if programs.is_empty() {
+        programs.push(compile_deserialization(0, &[], Buffers::new())?);
+    }
+
+    Ok(programs)
 }
 
 struct Compiler<'a> {
     num_items: usize,
-    arrays: &'a [ArrayMapping],
     buffers: Buffers<'a>,
     num_positions: usize,
+    outer_sequence_count: usize,
     program: Vec<Bytecode>,
 }
 
 impl<'a> Compiler<'a> {
-    fn compile(&mut self) -> Result<()> {
-        self.compile_fields()?;
+    fn compile(&mut self, arrays: &[ArrayMapping]) -> Result<()> {
+        self.compile_fields(arrays)?;
         self.fix_redirects()?;
         Ok(())
     }
 
-    fn compile_fields(&mut self) -> Result<()> {
+    fn compile_fields(&mut self, arrays: &[ArrayMapping]) -> Result<()> {
         self.push_instr(EmitOuterStartSequence { next: 1 });
 
         let outer_sequence_count = self.buffers.push_u0(self.num_items);
+        self.outer_sequence_count = outer_sequence_count;
         let outer_sequence_item_pos = self.program.len();
 
         let outer_sequence_position = self.new_position();
@@ -79,9 +130,9 @@ impl<'a> Compiler<'a> {
         self.push_instr(EmitStartOuterStruct { next: NEXT_INSTR });
 
         let mut child_positions = Vec::new();
-        for array in self.arrays {
+        for array in arrays {
             let field = array.get_field();
-            let name_buffer = self.buffers.push_u8(field.name.as_bytes());
+            let name_buffer = self.buffers.push_str(&field.name);
             self.push_instr(EmitConstantString {
                 next: NEXT_INSTR,
                 buffer: name_buffer,
@@ -119,13 +170,30 @@ impl<'a> Compiler<'a> {
     ///
     fn compile_field(
         &mut self,
-        array: &'a ArrayMapping,
+        array: &ArrayMapping,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
         let position = self.new_position();
 
         let option_instr;
-        if let Some(validity) = array.get_validity() {
+        if let &ArrayMapping::Dictionary {
+            indices,
+            values_validity: Some(values_validity),
+            ..
+        } = array
+        {
+            option_instr = Some(self.program.len());
+
+            self.push_instr(EmitOptionDictionary {
+                next: NEXT_INSTR,
+                position,
+                validity: array.get_validity(),
+                index: indices,
+                values_validity,
+                positions_to_increment: Vec::new(),
+                if_none: usize::MAX,
+            });
+        } else if let Some(validity) = array.get_validity() {
             option_instr = Some(self.program.len());
 
             self.push_instr(EmitOptionPrimitive {
@@ -146,19 +214,24 @@ impl<'a> Compiler<'a> {
 
         if let Some(option_instr) = option_instr {
             let if_none = self.program.len();
-            let Some(Bytecode::EmitOptionPrimitive(instr)) = self.program.get_mut(option_instr)
-            else {
-                unreachable!()
-            };
-            instr.if_none = if_none;
-            instr.positions_to_increment = inner_child_positions;
+            match self.program.get_mut(option_instr) {
+                Some(Bytecode::EmitOptionPrimitive(instr)) => {
+                    instr.if_none = if_none;
+                    instr.positions_to_increment = inner_child_positions;
+                }
+                Some(Bytecode::EmitOptionDictionary(instr)) => {
+                    instr.if_none = if_none;
+                    instr.positions_to_increment = inner_child_positions;
+                }
+                _ => unreachable!(),
+            }
         }
         Ok(())
     }
 
     fn compile_field_inner(
         &mut self,
-        array: &'a ArrayMapping,
+        array: &ArrayMapping,
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -238,6 +311,18 @@ impl<'a> Compiler<'a> {
                     scale,
                 })
             }
+            M::Decimal256 { field, buffer, .. } => {
+                let scale = match &field.data_type {
+                    GenericDataType::Decimal256(_, scale) => *scale,
+                    _ => fail!("inconsistent state for Decimal256 in compile_deserialzation"),
+                };
+                self.push_instr(EmitDecimal256 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                    scale,
+                })
+            }
             &M::Utf8 {
                 buffer, offsets, ..
             } => self.push_instr(EmitStr32 {
@@ -254,26 +339,78 @@ impl<'a> Compiler<'a> {
                 offsets,
                 position,
             }),
+            &M::Binary {
+                buffer, offsets, ..
+            } => self.push_instr(EmitBytes32 {
+                next: NEXT_INSTR,
+                buffer,
+                offsets,
+                position,
+            }),
+            &M::LargeBinary {
+                buffer, offsets, ..
+            } => self.push_instr(EmitBytes64 {
+                next: NEXT_INSTR,
+                buffer,
+                offsets,
+                position,
+            }),
+            M::FixedSizeBinary {
+                field, buffer, n, ..
+            } => match field.strategy.as_ref() {
+                Some(Strategy::UuidStrAsFixedSizeBinary) => self.push_instr(EmitUuidStr {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                None => self.push_instr(EmitFixedSizeBytes {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    n: *n,
+                    position,
+                }),
+                Some(strategy) => fail!(
+                    "compilation of FixedSizeBinary with strategy {strategy} is not yet supported"
+                ),
+            },
             &M::Dictionary {
                 dictionary,
                 indices,
                 ..
-            } => self.push_instr(EmitDictionaryStr {
+            } => self.push_instr(EmitDictionary {
                 next: NEXT_INSTR,
                 position,
                 value: dictionary,
                 index: indices,
             }),
+            M::Date32 { field, buffer, .. } => match field.strategy.as_ref() {
+                Some(Strategy::NaiveStrAsDate32) => self.push_instr(EmitDate32NaiveStr {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                None => self.push_instr(EmitI32 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                Some(strategy) => {
+                    fail!("compilation of date32 with strategy {strategy} is not yet supported")
+                }
+            },
             M::Date64 { field, buffer, .. } => match field.strategy.as_ref() {
                 Some(Strategy::NaiveStrAsDate64) => self.push_instr(EmitDate64NaiveStr {
                     next: NEXT_INSTR,
                     buffer: *buffer,
                     position,
+                    unit: get_timestamp_unit(field)?,
                 }),
                 Some(Strategy::UtcStrAsDate64) => self.push_instr(EmitDate64UtcStr {
                     next: NEXT_INSTR,
                     buffer: *buffer,
                     position,
+                    unit: get_timestamp_unit(field)?,
+                    timezone: get_timezone(field)?,
                 }),
                 None => self.push_instr(EmitI64 {
                     next: NEXT_INSTR,
@@ -284,12 +421,73 @@ impl<'a> Compiler<'a> {
                     fail!("compilation of date64 with strategy {strategy} is not yet supported")
                 }
             },
+            M::Time32 { field, buffer, .. } => match field.strategy.as_ref() {
+                Some(Strategy::NaiveStrAsTime) => self.push_instr(EmitTime32NaiveStr {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                    unit: get_time_unit(field)?,
+                }),
+                None => self.push_instr(EmitI32 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                Some(strategy) => {
+                    fail!("compilation of time32 with strategy {strategy} is not yet supported")
+                }
+            },
+            M::Time64 { field, buffer, .. } => match field.strategy.as_ref() {
+                Some(Strategy::NaiveStrAsTime) => self.push_instr(EmitTime64NaiveStr {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                    unit: get_time_unit(field)?,
+                }),
+                None => self.push_instr(EmitI64 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                Some(strategy) => {
+                    fail!("compilation of time64 with strategy {strategy} is not yet supported")
+                }
+            },
+            M::Duration { field, buffer, .. } => match field.strategy.as_ref() {
+                Some(Strategy::SecsNanosAsDuration) => {
+                    self.compile_secs_nanos_duration(field, *buffer, position)?
+                }
+                None => self.push_instr(EmitI64 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                Some(strategy) => {
+                    fail!("compilation of duration with strategy {strategy} is not yet supported")
+                }
+            },
+            M::Interval {
+                field,
+                buffer,
+                layout,
+                ..
+            } => match layout {
+                IntervalLayout::YearMonth => self.push_instr(EmitI32 {
+                    next: NEXT_INSTR,
+                    buffer: *buffer,
+                    position,
+                }),
+                _ => self.compile_interval(field, *buffer, *layout, position)?,
+            },
             M::List { item, offsets, .. } => self
                 .compile_list(item, position, *offsets, false)
                 .map(|_| 0)?,
             M::LargeList { item, offsets, .. } => self
                 .compile_list(item, position, *offsets, true)
                 .map(|_| 0)?,
+            M::FixedSizeList { item, n, .. } => self
+                .compile_fixed_size_list(item, position, *n)
+                .map(|_| 0)?,
             M::Struct { field, fields, .. } => match field.strategy.as_ref() {
                 None => self
                     .compile_struct(fields, position, child_positions)
@@ -324,9 +522,20 @@ impl<'a> Compiler<'a> {
                 self.compile_map(key_field, values_field, position, *offsets)
                     .map(|_| 0)?
             }
-            M::Union { fields, types, .. } => {
-                self.compile_union(fields, position, *types).map(|_| 0)?
-            }
+            M::Union {
+                field,
+                fields,
+                types,
+                offsets,
+                ..
+            } => self
+                .compile_union(field, fields, position, *types, *offsets, child_positions)
+                .map(|_| 0)?,
+            M::RunEndEncoded {
+                run_ends, values, ..
+            } => self
+                .compile_run_end_encoded(values, *run_ends, position)
+                .map(|_| 0)?,
         };
         Ok(())
     }
@@ -336,7 +545,7 @@ impl<'a> Compiler<'a> {
 impl<'a> Compiler<'a> {
     fn compile_list(
         &mut self,
-        item: &'a ArrayMapping,
+        item: &ArrayMapping,
         position: usize,
         offsets: usize,
         is_large: bool,
@@ -390,11 +599,94 @@ impl<'a> Compiler<'a> {
     }
 }
 
+/// Fixed size list support
+impl<'a> Compiler<'a> {
+    fn compile_fixed_size_list(
+        &mut self,
+        item: &ArrayMapping,
+        position: usize,
+        n: usize,
+    ) -> Result<()> {
+        let inner_position = self.new_position();
+        let emit_start_instr = self.push_instr(EmitStartFixedSizeList {
+            next: NEXT_INSTR,
+            position,
+            inner_position,
+            n,
+            child_positions: Vec::new(),
+        });
+
+        let if_item_instr = self.program.len() + 1;
+        let emit_item_instr = self.push_instr(EmitItemFixedSizeList {
+            next: NEXT_INSTR,
+            if_end: UNSET_INSTR,
+            position,
+            inner_position,
+            n,
+        });
+
+        let mut child_positions = Vec::new();
+        self.compile_field(item, &mut child_positions)?;
+
+        let if_end_instr = self.program.len() + 1;
+        self.push_instr(EmitEndFixedSizeList {
+            next: NEXT_INSTR,
+            if_item: if_item_instr,
+            position,
+            inner_position,
+            n,
+        });
+
+        if let Some(Bytecode::EmitItemFixedSizeList(instr)) = self.program.get_mut(emit_item_instr)
+        {
+            instr.if_end = if_end_instr;
+        } else {
+            fail!("invalid state during compilation");
+        }
+        if let Some(Bytecode::EmitStartFixedSizeList(instr)) =
+            self.program.get_mut(emit_start_instr)
+        {
+            instr.child_positions = child_positions;
+        } else {
+            fail!("invalid state during compilation");
+        }
+
+        Ok(())
+    }
+}
+
+/// Run-end encoded support
+impl<'a> Compiler<'a> {
+    fn compile_run_end_encoded(
+        &mut self,
+        values: &ArrayMapping,
+        run_ends: RunEnds,
+        position: usize,
+    ) -> Result<()> {
+        let seek_instr = self.push_instr(SeekRunEndEncoded {
+            next: NEXT_INSTR,
+            position,
+            run_ends,
+            value_positions: Vec::new(),
+        });
+
+        let mut value_positions = Vec::new();
+        self.compile_field(values, &mut value_positions)?;
+
+        let Some(Bytecode::SeekRunEndEncoded(instr)) = self.program.get_mut(seek_instr) else {
+            fail!("internal error: did not find run-end encoded seek");
+        };
+        instr.value_positions = value_positions;
+
+        Ok(())
+    }
+}
+
 /// Struct support
 impl<'a> Compiler<'a> {
     fn compile_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &[ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -402,7 +694,7 @@ impl<'a> Compiler<'a> {
 
         for array in arrays {
             let field = array.get_field();
-            let name_buffer = self.buffers.push_u8(field.name.as_bytes());
+            let name_buffer = self.buffers.push_str(&field.name);
             self.push_instr(EmitConstantString {
                 next: NEXT_INSTR,
                 buffer: name_buffer,
@@ -418,9 +710,122 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    fn compile_secs_nanos_duration(
+        &mut self,
+        field: &GenericField,
+        buffer: usize,
+        position: usize,
+    ) -> Result<usize> {
+        let unit = match &field.data_type {
+            GenericDataType::Duration(unit) => unit.clone(),
+            dt => fail!("inconsistent state: expected a duration data type, found {dt}"),
+        };
+
+        self.push_instr(EmitStartStruct { next: NEXT_INSTR });
+
+        let secs_buffer = self.buffers.push_str("secs");
+        self.push_instr(EmitConstantString {
+            next: NEXT_INSTR,
+            buffer: secs_buffer,
+        });
+        self.push_instr(EmitDurationSecs {
+            next: NEXT_INSTR,
+            position,
+            buffer,
+            unit: unit.clone(),
+        });
+
+        let nanos_buffer = self.buffers.push_str("nanos");
+        self.push_instr(EmitConstantString {
+            next: NEXT_INSTR,
+            buffer: nanos_buffer,
+        });
+        self.push_instr(EmitDurationNanos {
+            next: NEXT_INSTR,
+            position,
+            buffer,
+            unit,
+        });
+
+        Ok(self.push_instr(EmitEndStruct {
+            next: NEXT_INSTR,
+            position,
+        }))
+    }
+
+    fn compile_interval(
+        &mut self,
+        field: &GenericField,
+        buffer: usize,
+        layout: IntervalLayout,
+        position: usize,
+    ) -> Result<usize> {
+        let components: &[&str] = match layout {
+            IntervalLayout::DayTime | IntervalLayout::PackedDayTime => &["days", "millis"],
+            IntervalLayout::MonthDayNano | IntervalLayout::PackedMonthDayNano => {
+                &["months", "days", "nanos"]
+            }
+            IntervalLayout::YearMonth => {
+                fail!("inconsistent state: year-month intervals are not composite")
+            }
+        };
+        let as_tuple = matches!(field.strategy, Some(Strategy::TupleAsStruct));
+
+        if as_tuple {
+            self.push_instr(EmitStartTuple { next: NEXT_INSTR });
+        } else {
+            self.push_instr(EmitStartStruct { next: NEXT_INSTR });
+        }
+
+        for &component in components {
+            if as_tuple {
+                self.push_instr(EmitItemTuple { next: NEXT_INSTR });
+            } else {
+                let name_buffer = self.buffers.push_str(component);
+                self.push_instr(EmitConstantString {
+                    next: NEXT_INSTR,
+                    buffer: name_buffer,
+                });
+            }
+
+            match component {
+                "months" => self.push_instr(EmitIntervalMonths {
+                    next: NEXT_INSTR,
+                    position,
+                    buffer,
+                    layout,
+                }),
+                "days" => self.push_instr(EmitIntervalDays {
+                    next: NEXT_INSTR,
+                    position,
+                    buffer,
+                    layout,
+                }),
+                _ => self.push_instr(EmitIntervalTime {
+                    next: NEXT_INSTR,
+                    position,
+                    buffer,
+                    layout,
+                }),
+            };
+        }
+
+        if as_tuple {
+            Ok(self.push_instr(EmitEndTuple {
+                next: NEXT_INSTR,
+                position,
+            }))
+        } else {
+            Ok(self.push_instr(EmitEndStruct {
+                next: NEXT_INSTR,
+                position,
+            }))
+        }
+    }
+
     fn compile_tuple_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &[ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -440,7 +845,7 @@ impl<'a> Compiler<'a> {
 
     fn compile_map_struct(
         &mut self,
-        arrays: &'a [ArrayMapping],
+        arrays: &[ArrayMapping],
         position: usize,
         child_positions: &mut Vec<usize>,
     ) -> Result<()> {
@@ -448,7 +853,7 @@ impl<'a> Compiler<'a> {
 
         for array in arrays {
             let field = array.get_field();
-            let name_buffer = self.buffers.push_u8(field.name.as_bytes());
+            let name_buffer = self.buffers.push_str(&field.name);
             self.push_instr(EmitConstantString {
                 next: NEXT_INSTR,
                 buffer: name_buffer,
@@ -468,8 +873,8 @@ impl<'a> Compiler<'a> {
 impl<'a> Compiler<'a> {
     fn compile_map(
         &mut self,
-        key_field: &'a ArrayMapping,
-        value_field: &'a ArrayMapping,
+        key_field: &ArrayMapping,
+        value_field: &ArrayMapping,
         position: usize,
         offsets: usize,
     ) -> Result<()> {
@@ -531,13 +936,50 @@ impl<'a> Compiler<'a> {
 impl<'a> Compiler<'a> {
     fn compile_union(
         &mut self,
-        fields: &'a [ArrayMapping],
+        field: &GenericField,
+        fields: &[ArrayMapping],
         position: usize,
         types: usize,
+        offsets: Option<usize>,
+        child_positions: &mut Vec<usize>,
     ) -> Result<()> {
+        let is_sparse = matches!(
+            field.data_type,
+            GenericDataType::Union(GenericUnionMode::Sparse)
+        );
+        match (is_sparse, offsets) {
+            (true, Some(_)) => fail!("Sparse unions must not have an offsets buffer"),
+            (false, None) => fail!("Dense unions require an offsets buffer"),
+            _ => {}
+        }
+        let null_variant = field.get_union_null_variant();
+        let type_ids = field.get_union_type_ids()?;
+
+        // map the type ids to the index of the corresponding child
+        let mut child_indices = Vec::new();
+        for (idx, &type_id) in type_ids.iter().enumerate() {
+            let type_id = usize::try_from(type_id)?;
+            if child_indices.len() <= type_id {
+                child_indices.resize(type_id + 1, None);
+            }
+            child_indices[type_id] = Some(idx);
+        }
+
+        let option_instr = match null_variant {
+            Some(null_variant) => Some(self.push_instr(EmitOptionUnion {
+                next: NEXT_INSTR,
+                position,
+                types,
+                null_type_id: usize::try_from(type_ids[null_variant])?,
+                if_none: UNSET_INSTR,
+                positions_to_increment: Vec::new(),
+            })),
+            None => None,
+        };
+
         let mut field_names = Vec::new();
         for field in fields {
-            let idx = self.buffers.push_u8(field.get_field().name.as_bytes());
+            let idx = self.buffers.push_str(&field.get_field().name);
             field_names.push(idx);
         }
         let dispatch_instr = self.push_instr(UnionDispatch {
@@ -545,19 +987,37 @@ impl<'a> Compiler<'a> {
             field_instr: Vec::new(),
             position,
             types,
+            child_indices,
             field_names,
+            sparse_positions: Vec::new(),
+            offsets,
+            dense_positions: Vec::new(),
         });
 
         let mut field_instr = Vec::new();
         let mut redirect_instrs = Vec::new();
+        let mut sparse_positions = Vec::new();
+        let mut dense_positions = Vec::new();
+        let mut null_positions = vec![position];
 
-        for field in fields {
+        for (idx, field) in fields.iter().enumerate() {
             field_instr.push(self.program.len());
 
-            let mut dummy_positions = Vec::new();
-            self.compile_field(field, &mut dummy_positions)?;
-            // unions in nullable structs are currently not supported
-            drop(dummy_positions);
+            let mut field_positions = Vec::new();
+            self.compile_field(field, &mut field_positions)?;
+            if is_sparse {
+                // in sparse unions all children advance with the union
+                child_positions.extend(field_positions.iter().copied());
+                null_positions.extend(field_positions.iter().copied());
+                sparse_positions.push(field_positions);
+            } else {
+                if Some(idx) == null_variant {
+                    null_positions.extend(field_positions.iter().copied());
+                }
+                // in dense unions the children are positioned via the offsets,
+                // unions in nullable structs are currently not supported
+                dense_positions.push(field_positions);
+            }
 
             let redirect_instr = self.push_instr(Redirect { next: UNSET_INSTR });
             redirect_instrs.push(redirect_instr);
@@ -567,6 +1027,8 @@ impl<'a> Compiler<'a> {
             fail!("internal error: did not find union dispatch")
         };
         instr.field_instr = field_instr;
+        instr.sparse_positions = sparse_positions;
+        instr.dense_positions = dense_positions;
 
         let instr_after_union = self.program.len();
         for redirect_instr in redirect_instrs {
@@ -576,6 +1038,14 @@ impl<'a> Compiler<'a> {
             instr.next = instr_after_union;
         }
 
+        if let Some(option_instr) = option_instr {
+            let Some(Bytecode::EmitOptionUnion(instr)) = self.program.get_mut(option_instr) else {
+                fail!("internal error: did not find union option");
+            };
+            instr.if_none = instr_after_union;
+            instr.positions_to_increment = null_positions;
+        }
+
         Ok(())
     }
 
@@ -632,11 +1102,12 @@ impl<'a> Compiler<'a> {
         self.num_positions - 1
     }
 
-    fn into_program(self) -> Interpreter<'a> {
-        Interpreter {
-            current_instr: 0,
-            program: self.program,
-            positions: vec![0; self.num_positions],
+    fn into_program(self, arrays: &[ArrayMapping]) -> Program<'a> {
+        Program {
+            bytecode: self.program.into(),
+            num_positions: self.num_positions,
+            arrays: arrays.into(),
+            outer_sequence_count: self.outer_sequence_count,
             buffers: self.buffers,
         }
     }
@@ -700,25 +1171,57 @@ define_bytecode!{
         /// whether to use i64 offsets (`true`) or i32 offsets (`false )`
         is_large: bool,
     },
-    EmitStartMap {
-        /// the position inside the offsets array
+    EmitStartFixedSizeList {
+        /// the position of the list
         position: usize,
-        /// the instruction to jump to if the list is at its end
-        if_end: usize,
         /// the position inside the overall items
         inner_position: usize,
-        /// the buffer that contains the offsets
-        offsets: usize,
+        /// the number of items per list
+        n: usize,
+        /// the positions of the items, reset at the start of each list as
+        /// missing lists still contain `n` items
+        child_positions: Vec<usize>,
     },
-    /// Handle the end-of-sequence / item case
-    EmitEndMap {
-        /// the position inside the offsets array
+    EmitItemFixedSizeList {
+        /// the position of the list
         position: usize,
         /// the position inside the overall items
         inner_position: usize,
-        /// the instruction to jump to if the list is not yet at its end
-        if_item: usize,
-        /// the buffer that contains the offsets
+        /// the instruction to jump to if the list is at its end
+        if_end: usize,
+        /// the number of items per list
+        n: usize,
+    },
+    /// Handle the end-of-list / item case
+    EmitEndFixedSizeList {
+        /// the position of the list
+        position: usize,
+        /// the position inside the overall items
+        inner_position: usize,
+        /// the instruction to jump to if the list is not yet at its end
+        if_item: usize,
+        /// the number of items per list
+        n: usize,
+    },
+    EmitStartMap {
+        /// the position inside the offsets array
+        position: usize,
+        /// the instruction to jump to if the list is at its end
+        if_end: usize,
+        /// the position inside the overall items
+        inner_position: usize,
+        /// the buffer that contains the offsets
+        offsets: usize,
+    },
+    /// Handle the end-of-sequence / item case
+    EmitEndMap {
+        /// the position inside the offsets array
+        position: usize,
+        /// the position inside the overall items
+        inner_position: usize,
+        /// the instruction to jump to if the list is not yet at its end
+        if_item: usize,
+        /// the buffer that contains the offsets
         offsets: usize,
     },
     EmitItemMap {
@@ -763,6 +1266,39 @@ define_bytecode!{
         /// The indices of the position counters to increment if none
         positions_to_increment: Vec<usize>,
     },
+    /// Emit nullability information for a dictionary with nullable values
+    ///
+    /// The value is null, if either the key or the referenced value is null.
+    EmitOptionDictionary {
+        /// The index of the position counter for the keys
+        position: usize,
+        /// The index of the u1 buffer containing the validity of the keys
+        validity: Option<usize>,
+        /// The buffer of the keys
+        index: DictionaryIndex,
+        /// The index of the u1 buffer containing the validity of the values
+        values_validity: usize,
+        /// The instruction to jump to, if the validity is false
+        if_none: usize,
+        /// The indices of the position counters to increment if none
+        positions_to_increment: Vec<usize>,
+    },
+    /// Emit nullability information for a union with a null variant
+    ///
+    /// The value is null, if the type of the current element is the null
+    /// variant.
+    EmitOptionUnion {
+        /// The index of the position counter of the union
+        position: usize,
+        /// The buffer of the type ids
+        types: usize,
+        /// The type id of the null variant
+        null_type_id: usize,
+        /// The instruction to jump to, if the value is null
+        if_none: usize,
+        /// The indices of the position counters to increment if none
+        positions_to_increment: Vec<usize>,
+    },
     EmitNull {},
     EmitBool {
         position: usize,
@@ -822,20 +1358,86 @@ define_bytecode!{
         buffer: usize,
         offsets: usize,
     },
+    EmitBytes32 {
+        position: usize,
+        buffer: usize,
+        offsets: usize,
+    },
+    EmitBytes64 {
+        position: usize,
+        buffer: usize,
+        offsets: usize,
+    },
+    EmitFixedSizeBytes {
+        position: usize,
+        buffer: usize,
+        n: usize,
+    },
+    EmitUuidStr {
+        position: usize,
+        buffer: usize,
+    },
     EmitDecimal128 {
         position: usize,
         buffer: usize,
         scale: i8,
     },
+    EmitDecimal256 {
+        position: usize,
+        buffer: usize,
+        scale: i8,
+    },
+    EmitDate32NaiveStr {
+        position: usize,
+        buffer: usize,
+    },
     EmitDate64NaiveStr {
         position: usize,
         buffer: usize,
+        unit: GenericTimeUnit,
     },
     EmitDate64UtcStr {
         position: usize,
         buffer: usize,
+        unit: GenericTimeUnit,
+        timezone: Timezone,
+    },
+    EmitTime32NaiveStr {
+        position: usize,
+        buffer: usize,
+        unit: GenericTimeUnit,
+    },
+    EmitTime64NaiveStr {
+        position: usize,
+        buffer: usize,
+        unit: GenericTimeUnit,
+    },
+    EmitDurationSecs {
+        position: usize,
+        buffer: usize,
+        unit: GenericTimeUnit,
+    },
+    EmitDurationNanos {
+        position: usize,
+        buffer: usize,
+        unit: GenericTimeUnit,
+    },
+    EmitIntervalMonths {
+        position: usize,
+        buffer: usize,
+        layout: IntervalLayout,
+    },
+    EmitIntervalDays {
+        position: usize,
+        buffer: usize,
+        layout: IntervalLayout,
+    },
+    EmitIntervalTime {
+        position: usize,
+        buffer: usize,
+        layout: IntervalLayout,
     },
-    EmitDictionaryStr {
+    EmitDictionary {
         position: usize,
         value: DictionaryValue,
         index: DictionaryIndex,
@@ -843,8 +1445,32 @@ define_bytecode!{
     UnionDispatch {
         position: usize,
         types: usize,
+        /// The child index for each type id
+        child_indices: Vec<Option<usize>>,
         field_instr: Vec<usize>,
         field_names: Vec<usize>,
+        /// For sparse unions, the positions of each variant. The positions of
+        /// all variants but the selected one are skipped. Empty for dense
+        /// unions.
+        sparse_positions: Vec<Vec<usize>>,
+        /// For dense unions, the buffer of the offsets into the children
+        offsets: Option<usize>,
+        /// For dense unions, the positions of each variant. The positions of
+        /// the selected variant are moved to its offset. Empty for sparse
+        /// unions.
+        dense_positions: Vec<Vec<usize>>,
+    },
+    /// Move the positions of the values of a run-end encoded array to the run
+    /// of the current row
+    ///
+    /// This instruction does not emit an event.
+    SeekRunEndEncoded {
+        /// The index of the position counter for the rows
+        position: usize,
+        /// The buffer of the run ends
+        run_ends: RunEnds,
+        /// The indices of the position counters of the values
+        value_positions: Vec<usize>,
     },
 }
 
@@ -853,7 +1479,7 @@ trait Instruction: std::fmt::Debug {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         fail!("not implemented for {self:?} (positions: {positions:?})")
     }
@@ -865,7 +1491,7 @@ impl Instruction for Bytecode {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         dispatch_bytecode!(self, instr => instr.emit(positions, buffers))
     }
@@ -879,7 +1505,7 @@ impl Instruction for Redirect {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         fail!(concat!(
             "internal error: redirect reached in execution. ",
@@ -896,7 +1522,7 @@ impl Instruction for EmitOuterStartSequence {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::StartSequence)))
     }
@@ -911,7 +1537,7 @@ impl Instruction for EmitOuterItem {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         if positions[self.position] >= buffers.u0[self.count] {
             Ok((self.if_end, Some(Event::EndSequence)))
@@ -932,7 +1558,7 @@ impl Instruction for EmitOuterEndSequence {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         if positions[self.position] >= buffers.u0[self.count] {
             Ok((self.next, Some(Event::EndSequence)))
@@ -953,7 +1579,7 @@ impl Instruction for EmitStartOuterStruct {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::StartStruct)))
     }
@@ -968,7 +1594,7 @@ impl Instruction for EmitEndOuterStruct {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::EndStruct)))
     }
@@ -983,7 +1609,7 @@ impl Instruction for EmitStartStruct {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::StartStruct)))
     }
@@ -998,7 +1624,7 @@ impl Instruction for EmitEndStruct {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         positions[self.position] += 1;
         Ok((self.next, Some(Event::EndStruct)))
@@ -1014,7 +1640,7 @@ impl Instruction for EmitStartMapStruct {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::StartMap)))
     }
@@ -1029,7 +1655,7 @@ impl Instruction for EmitEndMapStruct {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         positions[self.position] += 1;
         Ok((self.next, Some(Event::EndMap)))
@@ -1045,7 +1671,7 @@ impl Instruction for EmitStartTuple {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::StartTuple)))
     }
@@ -1060,7 +1686,7 @@ impl Instruction for EmitItemTuple {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::Item)))
     }
@@ -1075,7 +1701,7 @@ impl Instruction for EmitEndTuple {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         positions[self.position] += 1;
         Ok((self.next, Some(Event::EndTuple)))
@@ -1091,7 +1717,7 @@ impl Instruction for EmitStartSequence {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1122,11 +1748,77 @@ impl Instruction for EmitStartSequence {
     }
 }
 
+impl Instruction for EmitStartFixedSizeList {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        _buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let start = positions[self.position] * self.n;
+        positions[self.inner_position] = start;
+        for &pos in &self.child_positions {
+            positions[pos] = start;
+        }
+        Ok((self.next, Some(Event::StartSequence)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitItemFixedSizeList {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        _buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let end = (positions[self.position] + 1) * self.n;
+        if positions[self.inner_position] >= end {
+            positions[self.position] += 1;
+            Ok((self.if_end, Some(Event::EndSequence)))
+        } else {
+            positions[self.inner_position] += 1;
+            Ok((self.next, Some(Event::Item)))
+        }
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_end = get_target_update(redirects, self.if_end);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitEndFixedSizeList {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        _buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let end = (positions[self.position] + 1) * self.n;
+        if positions[self.inner_position] >= end {
+            positions[self.position] += 1;
+            Ok((self.next, Some(Event::EndSequence)))
+        } else {
+            positions[self.inner_position] += 1;
+            Ok((self.if_item, Some(Event::Item)))
+        }
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_item = get_target_update(redirects, self.if_item);
+        Ok(())
+    }
+}
+
 impl Instruction for EmitItemSequence {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1167,7 +1859,7 @@ impl Instruction for EmitEndSequence {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1208,7 +1900,7 @@ impl Instruction for EmitStartMap {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1232,7 +1924,7 @@ impl Instruction for EmitItemMap {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1264,7 +1956,7 @@ impl Instruction for EmitEndMap {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let outer_pos = positions[self.position];
 
@@ -1296,10 +1988,9 @@ impl Instruction for EmitConstantString {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        let s = std::str::from_utf8(buffers.u8[self.buffer])?;
-        Ok((self.next, Some(Event::Str(s))))
+        Ok((self.next, Some(Event::Str(buffers.get_str(self.buffer)))))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1312,7 +2003,7 @@ impl Instruction for EmitOptionPrimitive {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let pos = positions[self.position];
         if buffers.u1[self.validity].is_set(pos) {
@@ -1332,21 +2023,122 @@ impl Instruction for EmitOptionPrimitive {
     }
 }
 
+impl Instruction for EmitOptionDictionary {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let pos = positions[self.position];
+        let is_valid = match self.validity {
+            Some(validity) => buffers.u1[validity].is_set(pos),
+            None => true,
+        } && buffers.u1[self.values_validity]
+            .is_set(get_dictionary_index(self.index, buffers, pos)?);
+
+        if is_valid {
+            Ok((self.next, Some(Event::Some)))
+        } else {
+            for idx in &self.positions_to_increment {
+                positions[*idx] += 1;
+            }
+            Ok((self.if_none, Some(Event::Null)))
+        }
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_none = get_target_update(redirects, self.if_none);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitOptionUnion {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let pos = positions[self.position];
+        let ty: usize = buffers.get_i8(self.types)[pos].try_into()?;
+
+        if ty != self.null_type_id {
+            Ok((self.next, Some(Event::Some)))
+        } else {
+            for idx in &self.positions_to_increment {
+                positions[*idx] += 1;
+            }
+            Ok((self.if_none, Some(Event::Null)))
+        }
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        self.if_none = get_target_update(redirects, self.if_none);
+        Ok(())
+    }
+}
+
+impl Instruction for SeekRunEndEncoded {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let row = positions[self.position];
+        positions[self.position] += 1;
+
+        let run = get_run_index(self.run_ends, buffers, row)?;
+        for &position in &self.value_positions {
+            positions[position] = run;
+        }
+
+        Ok((self.next, None))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
 impl Instruction for UnionDispatch {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let pos = positions[self.position];
         positions[self.position] += 1;
 
-        let ty: usize = buffers.get_i8(self.types)[pos].try_into()?;
-        let name = buffers.get_u8(self.field_names[ty]);
-        let name = std::str::from_utf8(name)?;
+        let type_id: usize = buffers.get_i8(self.types)[pos].try_into()?;
+        let Some(&Some(ty)) = self.child_indices.get(type_id) else {
+            fail!("Invalid type id {type_id} in union");
+        };
+        let name = buffers.get_str(self.field_names[ty]);
 
         let next = self.field_instr[ty];
 
+        for (idx, variant_positions) in self.sparse_positions.iter().enumerate() {
+            if idx != ty {
+                for &position in variant_positions {
+                    positions[position] += 1;
+                }
+            }
+        }
+
+        if let Some(offsets) = self.offsets {
+            let offset: usize = buffers
+                .get_i32(offsets)
+                .get(pos)
+                .copied()
+                .ok_or_else(|| error!("Missing offset for union element {pos}"))?
+                .try_into()?;
+            for &position in &self.dense_positions[ty] {
+                positions[position] = offset;
+            }
+        }
+
         Ok((next, Some(Event::Variant(name, ty))))
     }
 
@@ -1363,7 +2155,7 @@ impl Instruction for EndOfProgram {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, None))
     }
@@ -1378,7 +2170,7 @@ impl Instruction for EmitNull {
     fn emit<'a>(
         &self,
         _positions: &mut [usize],
-        _buffers: &Buffers<'a>,
+        _buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         Ok((self.next, Some(Event::Null)))
     }
@@ -1393,7 +2185,7 @@ impl Instruction for EmitBool {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = buffers.u1[self.buffer].is_set(positions[self.position]);
         positions[self.position] += 1;
@@ -1410,7 +2202,7 @@ impl Instruction for EmitU8 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = buffers.u8[self.buffer][positions[self.position]];
         positions[self.position] += 1;
@@ -1427,7 +2219,7 @@ impl Instruction for EmitU16 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = buffers.u16[self.buffer][positions[self.position]];
         positions[self.position] += 1;
@@ -1444,7 +2236,7 @@ impl Instruction for EmitU32 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = buffers.u32[self.buffer][positions[self.position]];
         positions[self.position] += 1;
@@ -1461,7 +2253,7 @@ impl Instruction for EmitU64 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = buffers.u64[self.buffer][positions[self.position]];
         positions[self.position] += 1;
@@ -1478,7 +2270,7 @@ impl Instruction for EmitI8 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             i8::from_ne_bytes(buffers.u8[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1496,7 +2288,7 @@ impl Instruction for EmitI16 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             i16::from_ne_bytes(buffers.u16[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1514,7 +2306,7 @@ impl Instruction for EmitI32 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             i32::from_ne_bytes(buffers.u32[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1532,7 +2324,7 @@ impl Instruction for EmitI64 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1550,7 +2342,7 @@ impl Instruction for EmitF16 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val = f16::from_bits(buffers.u16[self.buffer][positions[self.position]]);
         positions[self.position] += 1;
@@ -1567,7 +2359,7 @@ impl Instruction for EmitF32 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             f32::from_ne_bytes(buffers.u32[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1585,7 +2377,7 @@ impl Instruction for EmitF64 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let val =
             f64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
@@ -1603,7 +2395,7 @@ impl Instruction for EmitStr32 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let pos = positions[self.position];
         positions[self.position] += 1;
@@ -1624,7 +2416,7 @@ impl Instruction for EmitStr64 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
         let pos = positions[self.position];
         positions[self.position] += 1;
@@ -1641,20 +2433,19 @@ impl Instruction for EmitStr64 {
     }
 }
 
-impl Instruction for EmitDecimal128 {
+impl Instruction for EmitBytes32 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        let val =
-            i128::from_ne_bytes(buffers.u128[self.buffer][positions[self.position]].to_ne_bytes());
+        let pos = positions[self.position];
         positions[self.position] += 1;
 
-        let mut buffer = [0; decimal::BUFFER_SIZE_I128];
-        let ev = Event::OwnedStr(decimal::format_decimal(&mut buffer, val, self.scale).to_owned());
-
-        Ok((self.next, Some(ev)))
+        let start = usize::try_from(buffers.get_i32(self.offsets)[pos])?;
+        let end = usize::try_from(buffers.get_i32(self.offsets)[pos + 1])?;
+        let b = &buffers.u8[self.buffer][start..end];
+        Ok((self.next, Some(Event::Bytes(b))))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1663,26 +2454,19 @@ impl Instruction for EmitDecimal128 {
     }
 }
 
-impl Instruction for EmitDate64NaiveStr {
+impl Instruction for EmitBytes64 {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        use chrono::NaiveDateTime;
-
-        let val =
-            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        let pos = positions[self.position];
         positions[self.position] += 1;
 
-        let Some(val) =
-            NaiveDateTime::from_timestamp_opt(val / 1000, (val % 1000) as u32 * 100_000)
-        else {
-            fail!("Unsupported timestamp value: {val}");
-        };
-
-        // NOTE: chrono documents that Debug, not Display, can be parsed
-        Ok((self.next, Some(format!("{:?}", val).into())))
+        let start = usize::try_from(buffers.get_i64(self.offsets)[pos])?;
+        let end = usize::try_from(buffers.get_i64(self.offsets)[pos + 1])?;
+        let b = &buffers.u8[self.buffer][start..end];
+        Ok((self.next, Some(Event::Bytes(b))))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1691,27 +2475,18 @@ impl Instruction for EmitDate64NaiveStr {
     }
 }
 
-impl Instruction for EmitDate64UtcStr {
+impl Instruction for EmitFixedSizeBytes {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        use chrono::{TimeZone, Utc};
-
-        let val =
-            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        let pos = positions[self.position];
         positions[self.position] += 1;
 
-        let Some(val) = Utc
-            .timestamp_opt(val / 1000, (val % 1000) as u32 * 100_000)
-            .earliest()
-        else {
-            fail!("Unsupported timestamp value: {val}");
-        };
-
-        // NOTE: chrono documents that Debug, not Display, can be parsed
-        Ok((self.next, Some(format!("{:?}", val).into())))
+        let start = pos * self.n;
+        let b = &buffers.u8[self.buffer][start..start + self.n];
+        Ok((self.next, Some(Event::Bytes(b))))
     }
 
     fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
@@ -1720,41 +2495,503 @@ impl Instruction for EmitDate64UtcStr {
     }
 }
 
-impl Instruction for EmitDictionaryStr {
+impl Instruction for EmitUuidStr {
     fn emit<'a>(
         &self,
         positions: &mut [usize],
-        buffers: &Buffers<'a>,
+        buffers: &'a Buffers<'a>,
     ) -> Result<(usize, Option<Event<'a>>)> {
-        use {DictionaryIndex as I, DictionaryValue as V};
-
         let pos = positions[self.position];
         positions[self.position] += 1;
 
-        let index: usize = match self.index {
-            I::U8(buffer) => buffers.get_u8(buffer)[pos].into(),
-            I::U16(buffer) => buffers.get_u16(buffer)[pos].into(),
-            I::U32(buffer) => buffers.get_u32(buffer)[pos].try_into()?,
-            I::U64(buffer) => buffers.get_u64(buffer)[pos].try_into()?,
-            I::I8(buffer) => buffers.get_i8(buffer)[pos].try_into()?,
-            I::I16(buffer) => buffers.get_i16(buffer)[pos].try_into()?,
-            I::I32(buffer) => buffers.get_i32(buffer)[pos].try_into()?,
-            I::I64(buffer) => buffers.get_i64(buffer)[pos].try_into()?,
-        };
-
-        match self.value {
-            V::Utf8 { buffer, offsets } => {
-                let start = usize::try_from(buffers.get_i32(offsets)[index])?;
-                let end = usize::try_from(buffers.get_i32(offsets)[index + 1])?;
-                let s = std::str::from_utf8(&buffers.u8[buffer][start..end])?;
-                Ok((self.next, Some(Event::Str(s))))
+        let b = &buffers.u8[self.buffer][16 * pos..16 * (pos + 1)];
+        let mut res = String::with_capacity(36);
+        for (idx, byte) in b.iter().enumerate() {
+            if matches!(idx, 4 | 6 | 8 | 10) {
+                res.push('-');
             }
-            V::LargeUtf8 { buffer, offsets } => {
-                let start = usize::try_from(buffers.get_i64(offsets)[index])?;
+            res.push_str(&format!("{byte:02x}"));
+        }
+        Ok((self.next, Some(res.into())))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDecimal128 {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val =
+            i128::from_ne_bytes(buffers.u128[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        let mut buffer = [0; decimal::BUFFER_SIZE_I128];
+        let ev = Event::OwnedStr(decimal::format_decimal(&mut buffer, val, self.scale).to_owned());
+
+        Ok((self.next, Some(ev)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDecimal256 {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val = buffers.u256[self.buffer][positions[self.position]];
+        positions[self.position] += 1;
+
+        let mut buffer = [0; decimal::BUFFER_SIZE_I256];
+        let ev =
+            Event::OwnedStr(decimal::format_decimal256(&mut buffer, val, self.scale).to_owned());
+
+        Ok((self.next, Some(ev)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDate32NaiveStr {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        use chrono::{Duration, NaiveDate};
+
+        let val =
+            i32::from_ne_bytes(buffers.u32[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        let Some(val) = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .and_then(|epoch| epoch.checked_add_signed(Duration::days(val.into())))
+        else {
+            fail!("Unsupported date value: {val}");
+        };
+
+        // NOTE: chrono documents that Debug, not Display, can be parsed
+        Ok((self.next, Some(format!("{:?}", val).into())))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDate64NaiveStr {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val =
+            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        let val = to_utc_date_time(val, &self.unit)?.naive_utc();
+
+        // NOTE: chrono documents that Debug, not Display, can be parsed
+        Ok((self.next, Some(format!("{:?}", val).into())))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDate64UtcStr {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val =
+            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        let val = to_utc_date_time(val, &self.unit)?;
+        Ok((self.next, Some(self.timezone.format(val).into())))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+fn get_dictionary_index(
+    index: DictionaryIndex,
+    buffers: &Buffers<'_>,
+    pos: usize,
+) -> Result<usize> {
+    use DictionaryIndex as I;
+
+    Ok(match index {
+        I::U8(buffer) => buffers.get_u8(buffer)[pos].into(),
+        I::U16(buffer) => buffers.get_u16(buffer)[pos].into(),
+        I::U32(buffer) => buffers.get_u32(buffer)[pos].try_into()?,
+        I::U64(buffer) => buffers.get_u64(buffer)[pos].try_into()?,
+        I::I8(buffer) => buffers.get_i8(buffer)[pos].try_into()?,
+        I::I16(buffer) => buffers.get_i16(buffer)[pos].try_into()?,
+        I::I32(buffer) => buffers.get_i32(buffer)[pos].try_into()?,
+        I::I64(buffer) => buffers.get_i64(buffer)[pos].try_into()?,
+    })
+}
+
+/// Find the run containing the given row, i.e., the first run ending after it
+fn get_run_index(run_ends: RunEnds, buffers: &Buffers<'_>, row: usize) -> Result<usize> {
+    let row = i64::try_from(row)?;
+    let (run, num_runs) = match run_ends {
+        RunEnds::I16(buffer) => {
+            let run_ends = buffers.get_i16(buffer);
+            let run = run_ends.partition_point(|&end| i64::from(end) <= row);
+            (run, run_ends.len())
+        }
+        RunEnds::I32(buffer) => {
+            let run_ends = buffers.get_i32(buffer);
+            let run = run_ends.partition_point(|&end| i64::from(end) <= row);
+            (run, run_ends.len())
+        }
+        RunEnds::I64(buffer) => {
+            let run_ends = buffers.get_i64(buffer);
+            let run = run_ends.partition_point(|&end| end <= row);
+            (run, run_ends.len())
+        }
+    };
+    if run >= num_runs {
+        fail!("row {row} is not covered by the run ends of the run-end encoded array");
+    }
+    Ok(run)
+}
+
+fn get_timestamp_unit(field: &GenericField) -> Result<GenericTimeUnit> {
+    match &field.data_type {
+        GenericDataType::Date64 => Ok(GenericTimeUnit::Millisecond),
+        GenericDataType::Timestamp(unit, _) => Ok(unit.clone()),
+        dt => fail!("inconsistent state: expected a timestamp data type, found {dt}"),
+    }
+}
+
+fn get_timezone(field: &GenericField) -> Result<Timezone> {
+    match &field.data_type {
+        GenericDataType::Timestamp(_, Some(tz)) => Timezone::parse(tz),
+        _ => Ok(Timezone::Utc),
+    }
+}
+
+fn to_utc_date_time(val: i64, unit: &GenericTimeUnit) -> Result<chrono::DateTime<chrono::Utc>> {
+    use chrono::{TimeZone, Utc};
+
+    let (secs, nano) = match unit {
+        GenericTimeUnit::Second => (val, 0),
+        GenericTimeUnit::Millisecond => (val.div_euclid(1_000), val.rem_euclid(1_000) * 1_000_000),
+        GenericTimeUnit::Microsecond => {
+            (val.div_euclid(1_000_000), val.rem_euclid(1_000_000) * 1_000)
+        }
+        GenericTimeUnit::Nanosecond => {
+            (val.div_euclid(1_000_000_000), val.rem_euclid(1_000_000_000))
+        }
+    };
+
+    let Some(date_time) = Utc.timestamp_opt(secs, nano as u32).earliest() else {
+        fail!("Unsupported timestamp value: {val}");
+    };
+    Ok(date_time)
+}
+
+fn get_time_unit(field: &GenericField) -> Result<GenericTimeUnit> {
+    match &field.data_type {
+        GenericDataType::Time32(unit) | GenericDataType::Time64(unit) => Ok(unit.clone()),
+        dt => fail!("inconsistent state: expected a time data type, found {dt}"),
+    }
+}
+
+fn format_naive_time<'a>(val: i64, unit: &GenericTimeUnit) -> Result<Event<'a>> {
+    use chrono::NaiveTime;
+
+    let (secs, nano) = match unit {
+        GenericTimeUnit::Second => (val, 0),
+        GenericTimeUnit::Millisecond => (val / 1_000, (val % 1_000) * 1_000_000),
+        GenericTimeUnit::Microsecond => (val / 1_000_000, (val % 1_000_000) * 1_000),
+        GenericTimeUnit::Nanosecond => (val / 1_000_000_000, val % 1_000_000_000),
+    };
+
+    let Some(time) = u32::try_from(secs)
+        .ok()
+        .zip(u32::try_from(nano).ok())
+        .and_then(|(secs, nano)| NaiveTime::from_num_seconds_from_midnight_opt(secs, nano))
+    else {
+        fail!("Unsupported time value: {val}");
+    };
+
+    // NOTE: chrono documents that Debug, not Display, can be parsed
+    Ok(format!("{:?}", time).into())
+}
+
+impl Instruction for EmitTime32NaiveStr {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val =
+            i32::from_ne_bytes(buffers.u32[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        Ok((self.next, Some(format_naive_time(val.into(), &self.unit)?)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitTime64NaiveStr {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        let val =
+            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        positions[self.position] += 1;
+
+        Ok((self.next, Some(format_naive_time(val, &self.unit)?)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+/// Split a duration in the given unit into non-negative seconds and nanoseconds
+fn split_duration(val: i64, unit: &GenericTimeUnit) -> Result<(u64, u32)> {
+    if val < 0 {
+        fail!("Negative durations cannot be represented as secs and nanos: {val}");
+    }
+    let (secs, nanos) = match unit {
+        GenericTimeUnit::Second => (val, 0),
+        GenericTimeUnit::Millisecond => (val / 1_000, (val % 1_000) * 1_000_000),
+        GenericTimeUnit::Microsecond => (val / 1_000_000, (val % 1_000_000) * 1_000),
+        GenericTimeUnit::Nanosecond => (val / 1_000_000_000, val % 1_000_000_000),
+    };
+    Ok((u64::try_from(secs)?, u32::try_from(nanos)?))
+}
+
+impl Instruction for EmitDurationSecs {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        // NOTE: the position is incremented by the closing EmitEndStruct
+        let val =
+            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        let (secs, _) = split_duration(val, &self.unit)?;
+        Ok((self.next, Some(Event::U64(secs))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDurationNanos {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        // NOTE: the position is incremented by the closing EmitEndStruct
+        let val =
+            i64::from_ne_bytes(buffers.u64[self.buffer][positions[self.position]].to_ne_bytes());
+        let (_, nanos) = split_duration(val, &self.unit)?;
+        Ok((self.next, Some(Event::U32(nanos))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+/// Read the months, days and time components of an interval
+///
+/// The time component is given in milliseconds for day-time intervals and in
+/// nanoseconds for month-day-nano intervals.
+fn read_interval(
+    buffers: &Buffers<'_>,
+    buffer: usize,
+    layout: IntervalLayout,
+    idx: usize,
+) -> (i32, i32, i64) {
+    match layout {
+        IntervalLayout::YearMonth => {
+            let months = i32::from_ne_bytes(buffers.u32[buffer][idx].to_ne_bytes());
+            (months, 0, 0)
+        }
+        IntervalLayout::DayTime => {
+            let days = i32::from_ne_bytes(buffers.u32[buffer][2 * idx].to_ne_bytes());
+            let millis = i32::from_ne_bytes(buffers.u32[buffer][2 * idx + 1].to_ne_bytes());
+            (0, days, i64::from(millis))
+        }
+        IntervalLayout::PackedDayTime => {
+            let val = buffers.u64[buffer][idx];
+            (0, (val >> 32) as i32, i64::from(val as i32))
+        }
+        IntervalLayout::MonthDayNano => {
+            let [months, days]: [i32; 2] = bytemuck::cast(buffers.u64[buffer][2 * idx]);
+            let nanos = i64::from_ne_bytes(buffers.u64[buffer][2 * idx + 1].to_ne_bytes());
+            (months, days, nanos)
+        }
+        IntervalLayout::PackedMonthDayNano => {
+            let val = buffers.u128[buffer][idx];
+            ((val >> 96) as i32, (val >> 64) as i32, val as i64)
+        }
+    }
+}
+
+impl Instruction for EmitIntervalMonths {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        // NOTE: the position is incremented by the closing EmitEndStruct / EmitEndTuple
+        let (months, _, _) =
+            read_interval(buffers, self.buffer, self.layout, positions[self.position]);
+        Ok((self.next, Some(Event::I32(months))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitIntervalDays {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        // NOTE: the position is incremented by the closing EmitEndStruct / EmitEndTuple
+        let (_, days, _) =
+            read_interval(buffers, self.buffer, self.layout, positions[self.position]);
+        Ok((self.next, Some(Event::I32(days))))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitIntervalTime {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        // NOTE: the position is incremented by the closing EmitEndStruct / EmitEndTuple
+        let (_, _, time) =
+            read_interval(buffers, self.buffer, self.layout, positions[self.position]);
+        let event = match self.layout {
+            IntervalLayout::DayTime | IntervalLayout::PackedDayTime => {
+                Event::I32(i32::try_from(time)?)
+            }
+            _ => Event::I64(time),
+        };
+        Ok((self.next, Some(event)))
+    }
+
+    fn update_targets(&mut self, redirects: &HashMap<usize, usize>) -> Result<()> {
+        self.next = get_target_update(redirects, self.next);
+        Ok(())
+    }
+}
+
+impl Instruction for EmitDictionary {
+    fn emit<'a>(
+        &self,
+        positions: &mut [usize],
+        buffers: &'a Buffers<'a>,
+    ) -> Result<(usize, Option<Event<'a>>)> {
+        use DictionaryValue as V;
+
+        let pos = positions[self.position];
+        positions[self.position] += 1;
+
+        let index = get_dictionary_index(self.index, buffers, pos)?;
+
+        match self.value {
+            V::U8 { buffer } => Ok((self.next, Some(Event::U8(buffers.get_u8(buffer)[index])))),
+            V::U16 { buffer } => Ok((self.next, Some(Event::U16(buffers.get_u16(buffer)[index])))),
+            V::U32 { buffer } => Ok((self.next, Some(Event::U32(buffers.get_u32(buffer)[index])))),
+            V::U64 { buffer } => Ok((self.next, Some(Event::U64(buffers.get_u64(buffer)[index])))),
+            V::I8 { buffer } => Ok((self.next, Some(Event::I8(buffers.get_i8(buffer)[index])))),
+            V::I16 { buffer } => Ok((self.next, Some(Event::I16(buffers.get_i16(buffer)[index])))),
+            V::I32 { buffer } => Ok((self.next, Some(Event::I32(buffers.get_i32(buffer)[index])))),
+            V::I64 { buffer } => Ok((self.next, Some(Event::I64(buffers.get_i64(buffer)[index])))),
+            V::F16 { buffer } => {
+                let val = f16::from_bits(buffers.get_u16(buffer)[index]);
+                Ok((self.next, Some(Event::F32(val.to_f32()))))
+            }
+            V::F32 { buffer } => {
+                let val = f32::from_bits(buffers.get_u32(buffer)[index]);
+                Ok((self.next, Some(Event::F32(val))))
+            }
+            V::F64 { buffer } => {
+                let val = f64::from_bits(buffers.get_u64(buffer)[index]);
+                Ok((self.next, Some(Event::F64(val))))
+            }
+            V::Utf8 { buffer, offsets } => {
+                let start = usize::try_from(buffers.get_i32(offsets)[index])?;
+                let end = usize::try_from(buffers.get_i32(offsets)[index + 1])?;
+                let s = std::str::from_utf8(&buffers.u8[buffer][start..end])?;
+                Ok((self.next, Some(Event::Str(s))))
+            }
+            V::LargeUtf8 { buffer, offsets } => {
+                let start = usize::try_from(buffers.get_i64(offsets)[index])?;
                 let end = usize::try_from(buffers.get_i64(offsets)[index + 1])?;
                 let s = std::str::from_utf8(&buffers.u8[buffer][start..end])?;
                 Ok((self.next, Some(Event::Str(s))))
             }
+            V::Binary { buffer, offsets } => {
+                let start = usize::try_from(buffers.get_i32(offsets)[index])?;
+                let end = usize::try_from(buffers.get_i32(offsets)[index + 1])?;
+                Ok((
+                    self.next,
+                    Some(Event::Bytes(&buffers.u8[buffer][start..end])),
+                ))
+            }
+            V::LargeBinary { buffer, offsets } => {
+                let start = usize::try_from(buffers.get_i64(offsets)[index])?;
+                let end = usize::try_from(buffers.get_i64(offsets)[index + 1])?;
+                Ok((
+                    self.next,
+                    Some(Event::Bytes(&buffers.u8[buffer][start..end])),
+                ))
+            }
         }
     }
 
@@ -1764,19 +3001,212 @@ impl Instruction for EmitDictionaryStr {
     }
 }
 
-pub struct Interpreter<'a> {
+/// A compiled program together with the buffers it reads from
+pub struct Program<'a> {
+    /// The bytecode, shared between the programs of chunks with the same layout
+    pub bytecode: Arc<[Bytecode]>,
+    num_positions: usize,
+    /// The arrays the bytecode was compiled for
+    arrays: Arc<[ArrayMapping]>,
+    /// The index of the u0 buffer holding the number of items
+    outer_sequence_count: usize,
+    buffers: Buffers<'a>,
+}
+
+impl<'a> Program<'a> {
+    /// Build the state to execute this program from the start
+    pub fn new_state(&self) -> InterpreterState {
+        InterpreterState {
+            current_instr: 0,
+            positions: vec![0; self.num_positions],
+        }
+    }
+
+    /// Execute the program with the given state
+    ///
+    /// The state is kept outside of the interpreter to allow resuming the
+    /// execution, e.g., to deserialize the outer sequence item by item.
+    pub fn interpreter<'s>(&'s self, state: &'s mut InterpreterState) -> Interpreter<'s> {
+        Interpreter {
+            program: self,
+            state,
+        }
+    }
+
+    /// Build the program for another chunk of arrays
+    ///
+    /// If the arrays of the chunk have the same layout as the arrays of this
+    /// program (i.e., the same buffers including validities), the bytecode is
+    /// reused. Otherwise, the chunk is compiled anew.
+    pub fn for_chunk<'b>(
+        &self,
+        num_items: usize,
+        arrays: &[ArrayMapping],
+        mut buffers: Buffers<'b>,
+    ) -> Result<Program<'b>> {
+        if arrays != &*self.arrays {
+            return compile_deserialization(num_items, arrays, buffers);
+        }
+        if buffers.u0.len() != self.outer_sequence_count || !buffers.str.is_empty() {
+            fail!("Cannot reuse the program: the buffers of the chunk do not match");
+        }
+
+        let outer_sequence_count = buffers.push_u0(num_items);
+        buffers.str = self.buffers.str.clone();
+
+        Ok(Program {
+            bytecode: Arc::clone(&self.bytecode),
+            num_positions: self.num_positions,
+            arrays: Arc::clone(&self.arrays),
+            outer_sequence_count,
+            buffers,
+        })
+    }
+
+    fn next_event(&'a self, state: &mut InterpreterState) -> Result<Option<Event<'a>>> {
+        loop {
+            let instr = &self.bytecode[state.current_instr];
+            let (next_instr, ev) = instr.emit(&mut state.positions, &self.buffers)?;
+            state.current_instr = next_instr;
+
+            // skip instructions that only update positions, e.g., SeekRunEndEncoded
+            if ev.is_some() || matches!(instr, Bytecode::EndOfProgram(_)) {
+                return Ok(ev);
+            }
+        }
+    }
+}
+
+/// The current instruction and positions of an executed [Program]
+pub struct InterpreterState {
     current_instr: usize,
-    program: Vec<Bytecode>,
     positions: Vec<usize>,
-    buffers: Buffers<'a>,
+}
+
+pub struct Interpreter<'a> {
+    program: &'a Program<'a>,
+    state: &'a mut InterpreterState,
 }
 
 impl<'a> EventSource<'a> for Interpreter<'a> {
     fn next(&mut self) -> Result<Option<Event<'a>>> {
-        let (next_instr, ev) =
-            self.program[self.current_instr].emit(&mut self.positions, &self.buffers)?;
-        self.current_instr = next_instr;
-        Ok(ev)
+        self.program.next_event(self.state)
+    }
+}
+
+/// Execute the programs of multiple chunks as a single outer sequence
+///
+/// The start of the outer sequence is only emitted for the first chunk and its
+/// end only for the last chunk.
+pub struct ChunkedInterpreter<'a> {
+    programs: &'a [Program<'a>],
+    chunk: usize,
+    state: InterpreterState,
+}
+
+impl<'a> ChunkedInterpreter<'a> {
+    pub fn new(programs: &'a [Program<'a>]) -> Result<Self> {
+        let Some(first) = programs.first() else {
+            fail!("Cannot execute an empty list of programs");
+        };
+        Ok(Self {
+            programs,
+            chunk: 0,
+            state: first.new_state(),
+        })
+    }
+}
+
+impl<'a> EventSource<'a> for ChunkedInterpreter<'a> {
+    fn next(&mut self) -> Result<Option<Event<'a>>> {
+        loop {
+            let program = &self.programs[self.chunk];
+            let at_start = self.state.current_instr == 0;
+            let ev = program.next_event(&mut self.state)?;
+            let at_end = matches!(
+                program.bytecode[self.state.current_instr],
+                Bytecode::EndOfProgram(_)
+            );
+
+            match ev {
+                Some(Event::StartSequence) if at_start && self.chunk != 0 => {}
+                Some(Event::EndSequence) if at_end && self.chunk + 1 < self.programs.len() => {
+                    self.chunk += 1;
+                    self.state = self.programs[self.chunk].new_state();
+                }
+                ev => return Ok(ev),
+            }
+        }
+    }
+}
+
+/// Deserialize the items of the outer sequence of a [Program] one by one
+///
+/// The execution of the program is resumed for each item. Therefore, only a
+/// single item is deserialized at a time and the iteration can be stopped
+/// early.
+pub struct ItemIterator<'a, T> {
+    program: Program<'a>,
+    state: InterpreterState,
+    started: bool,
+    finished: bool,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<'a, T> ItemIterator<'a, T> {
+    pub fn new(program: Program<'a>) -> Self {
+        let state = program.new_state();
+        Self {
+            program,
+            state,
+            started: false,
+            finished: false,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Deserialize<'a>> ItemIterator<'a, T> {
+    fn next_item(&mut self) -> Result<Option<T>> {
+        let mut interpreter = self.program.interpreter(&mut self.state);
+
+        if !self.started {
+            self.started = true;
+            match interpreter.next()? {
+                Some(Event::StartSequence) => {}
+                Some(ev) => fail!("Invalid event {ev}, expected the start of the outer sequence"),
+                None => fail!("Invalid end of events, expected the start of the outer sequence"),
+            }
+        }
+
+        match interpreter.next()? {
+            Some(Event::Item) => Ok(Some(deserialize_item_from_source(interpreter)?)),
+            Some(Event::EndSequence) => {
+                self.finished = true;
+                Ok(None)
+            }
+            Some(ev) => fail!("Invalid event {ev} in the outer sequence"),
+            None => fail!("Invalid end of events in the outer sequence"),
+        }
+    }
+}
+
+impl<'a, T: Deserialize<'a>> Iterator for ItemIterator<'a, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match self.next_item() {
+            Ok(Some(item)) => Some(Ok(item)),
+            Ok(None) => None,
+            Err(err) => {
+                self.finished = true;
+                Some(Err(err))
+            }
+        }
     }
 }
 
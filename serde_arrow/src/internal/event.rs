@@ -9,10 +9,11 @@ use crate::internal::error::{fail, Error, Result};
 /// implementing `Serialize` and used to create objects that implement
 /// `Deserialize`.
 ///
-/// For the borrow strings events (`Str`), there  are corresponding owned events
-/// (`OwnedStr`). To normalize to the borrowed events or to the owned events use
-/// `event.to_self()` or `event.to_static` respectively. For equality borrowed
-/// and owned events are considered equal.
+/// For the borrow strings and bytes events (`Str`, `Bytes`), there are
+/// corresponding owned events (`OwnedStr`, `OwnedBytes`). To normalize to the
+/// borrowed events or to the owned events use `event.to_self()` or
+/// `event.to_static` respectively. For equality borrowed and owned events are
+/// considered equal.
 ///
 #[derive(Debug, Clone)]
 pub enum Event<'a> {
@@ -42,6 +43,10 @@ pub enum Event<'a> {
     Str(&'a str),
     /// The owned variant of `Str`
     OwnedStr(String),
+    /// Borrowed bytes
+    Bytes(&'a [u8]),
+    /// The owned variant of `Bytes`
+    OwnedBytes(Vec<u8>),
     /// Push the default of the current type
     Default,
     /// Denote the variant of a union
@@ -57,6 +62,8 @@ pub enum Event<'a> {
     U16(u16),
     U32(u32),
     U64(u64),
+    I128(i128),
+    U128(u128),
     F32(f32),
     F64(f64),
 }
@@ -85,12 +92,16 @@ impl<'a> std::fmt::Display for Event<'a> {
             Event::U16(v) => write!(f, "U16({v})"),
             Event::U32(v) => write!(f, "U32({v})"),
             Event::U64(v) => write!(f, "U64({v})"),
+            Event::I128(v) => write!(f, "I128({v})"),
+            Event::U128(v) => write!(f, "U128({v})"),
             Event::F32(v) => write!(f, "F32({v})"),
             Event::F64(v) => write!(f, "F64({v})"),
             Event::Variant(n, i) => write!(f, "Variant({n:?}, {i})"),
             Event::OwnedVariant(n, i) => write!(f, "OwnedVariant({n:?}, {i})"),
             Event::Str(v) => write!(f, "Str({v:?})"),
             Event::OwnedStr(v) => write!(f, "String({v:?})"),
+            Event::Bytes(v) => write!(f, "Bytes({v:?})"),
+            Event::OwnedBytes(v) => write!(f, "OwnedBytes({v:?})"),
         }
     }
 }
@@ -130,6 +141,16 @@ impl<'this, 'other> std::cmp::PartialEq<Event<'other>> for Event<'this> {
                 OwnedStr(o) => s == o,
                 _ => false,
             },
+            Bytes(s) => match other {
+                Bytes(o) => s == o,
+                OwnedBytes(o) => s == o,
+                _ => false,
+            },
+            OwnedBytes(s) => match other {
+                Bytes(o) => s == o,
+                OwnedBytes(o) => s == o,
+                _ => false,
+            },
             Some => matches!(other, Some),
             Bool(s) => matches!(other, Bool(o) if s == o),
             I8(s) => matches!(other, I8(o) if s == o),
@@ -140,6 +161,8 @@ impl<'this, 'other> std::cmp::PartialEq<Event<'other>> for Event<'this> {
             U16(s) => matches!(other, U16(o) if s == o),
             U32(s) => matches!(other, U32(o) if s == o),
             U64(s) => matches!(other, U64(o) if s == o),
+            I128(s) => matches!(other, I128(o) if s == o),
+            U128(s) => matches!(other, U128(o) if s == o),
             F32(s) => matches!(other, F32(o) if s == o),
             F64(s) => matches!(other, F64(o) if s == o),
         }
@@ -159,6 +182,8 @@ impl<'a> Event<'a> {
         match self {
             Event::OwnedStr(s) => Event::Str(s),
             Event::Str(s) => Event::Str(s),
+            Event::OwnedBytes(v) => Event::Bytes(v),
+            Event::Bytes(v) => Event::Bytes(v),
             Event::Variant(n, i) => Event::Variant(n, *i),
             Event::OwnedVariant(n, i) => Event::Variant(n, *i),
             Event::StartSequence => Event::StartSequence,
@@ -181,6 +206,8 @@ impl<'a> Event<'a> {
             &Event::U16(v) => Event::U16(v),
             &Event::U32(v) => Event::U32(v),
             &Event::U64(v) => Event::U64(v),
+            &Event::I128(v) => Event::I128(v),
+            &Event::U128(v) => Event::U128(v),
             &Event::F32(v) => Event::F32(v),
             &Event::F64(v) => Event::F64(v),
             Event::Null => Event::Null,
@@ -193,6 +220,8 @@ impl<'a> Event<'a> {
         match self {
             &Event::Str(s) => Event::OwnedStr(s.to_owned()),
             Event::OwnedStr(v) => Event::OwnedStr(v.clone()),
+            &Event::Bytes(v) => Event::OwnedBytes(v.to_owned()),
+            Event::OwnedBytes(v) => Event::OwnedBytes(v.clone()),
             &Event::Variant(n, i) => Event::OwnedVariant(n.to_owned(), i),
             Event::OwnedVariant(n, i) => Event::OwnedVariant(n.clone(), *i),
             Event::StartSequence => Event::StartSequence,
@@ -215,6 +244,8 @@ impl<'a> Event<'a> {
             &Event::U16(v) => Event::U16(v),
             &Event::U32(v) => Event::U32(v),
             &Event::U64(v) => Event::U64(v),
+            &Event::I128(v) => Event::I128(v),
+            &Event::U128(v) => Event::U128(v),
             &Event::F32(v) => Event::F32(v),
             &Event::F64(v) => Event::F64(v),
             Event::Null => Event::Null,
@@ -264,6 +295,8 @@ impl<'a> Event<'a> {
             Event::Bool(_)
                 | Event::Str(_)
                 | Event::OwnedStr(_)
+                | Event::Bytes(_)
+                | Event::OwnedBytes(_)
                 | Event::I8(_)
                 | Event::I16(_)
                 | Event::I32(_)
@@ -272,6 +305,8 @@ impl<'a> Event<'a> {
                 | Event::U16(_)
                 | Event::U32(_)
                 | Event::U64(_)
+                | Event::I128(_)
+                | Event::U128(_)
                 | Event::F32(_)
                 | Event::F64(_)
         )
@@ -311,9 +346,12 @@ event_implement_simple_from!(u8, U8);
 event_implement_simple_from!(u16, U16);
 event_implement_simple_from!(u32, U32);
 event_implement_simple_from!(u64, U64);
+event_implement_simple_from!(i128, I128);
+event_implement_simple_from!(u128, U128);
 event_implement_simple_from!(f32, F32);
 event_implement_simple_from!(f64, F64);
 event_implement_simple_from!(String, OwnedStr);
+event_implement_simple_from!(Vec<u8>, OwnedBytes);
 
 impl<'a> From<&'a str> for Event<'a> {
     fn from(val: &'a str) -> Event<'a> {
@@ -321,6 +359,12 @@ impl<'a> From<&'a str> for Event<'a> {
     }
 }
 
+impl<'a> From<&'a [u8]> for Event<'a> {
+    fn from(val: &'a [u8]) -> Event<'a> {
+        Self::Bytes(val)
+    }
+}
+
 macro_rules! event_implement_try_from_from_event {
     ($ty:ty, $($variant:ident),*) => {
         impl<'a> TryFrom<Event<'a>> for $ty {
@@ -348,16 +392,66 @@ impl<'a> TryFrom<Event<'a>> for f32 {
 
 event_implement_try_from_from_event!(bool, Bool);
 
-event_implement_try_from_from_event!(i8, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(i16, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(i32, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(i64, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
+event_implement_try_from_from_event!(i8, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(i16, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(i32, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(i64, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+
+event_implement_try_from_from_event!(u8, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(u16, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(u32, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+event_implement_try_from_from_event!(u64, U8, U16, U32, U64, U128, I8, I16, I32, I64, I128, Bool);
+
+macro_rules! int128_from_le_bytes {
+    ($ty:ty, $bytes:expr) => {{
+        let bytes: &[u8] = $bytes;
+        let Ok(bytes) = <[u8; 16]>::try_from(bytes) else {
+            fail!(
+                "invalid conversion from {} bytes to {}, expected 16 bytes",
+                bytes.len(),
+                stringify!($ty)
+            );
+        };
+        Ok(<$ty>::from_le_bytes(bytes))
+    }};
+}
+
+/// Implement the conversion of events into 128 bit integers
+///
+/// In addition to integer events, decimal strings without fractional digits
+/// (e.g., emitted for `Decimal128(_, 0)` arrays) and 16 bytes in little endian
+/// order (e.g., emitted for `FixedSizeBinary(16)` arrays) are accepted.
+macro_rules! event_implement_try_from_for_int128 {
+    ($ty:ty) => {
+        impl<'a> TryFrom<Event<'a>> for $ty {
+            type Error = Error;
+            fn try_from(val: Event<'_>) -> Result<$ty> {
+                match val {
+                    Event::U8(val) => Ok(val.try_into()?),
+                    Event::U16(val) => Ok(val.try_into()?),
+                    Event::U32(val) => Ok(val.try_into()?),
+                    Event::U64(val) => Ok(val.try_into()?),
+                    Event::U128(val) => Ok(val.try_into()?),
+                    Event::I8(val) => Ok(val.try_into()?),
+                    Event::I16(val) => Ok(val.try_into()?),
+                    Event::I32(val) => Ok(val.try_into()?),
+                    Event::I64(val) => Ok(val.try_into()?),
+                    Event::I128(val) => Ok(val.try_into()?),
+                    Event::Str(val) => Ok(val.parse()?),
+                    Event::OwnedStr(val) => Ok(val.parse()?),
+                    Event::Bytes(val) => int128_from_le_bytes!($ty, val),
+                    Event::OwnedBytes(val) => int128_from_le_bytes!($ty, val.as_slice()),
+                    event => fail!("invalid conversion from {} to {}", event, stringify!($ty)),
+                }
+            }
+        }
+    };
+}
 
-event_implement_try_from_from_event!(u8, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(u16, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(u32, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
-event_implement_try_from_from_event!(u64, U8, U16, U32, U64, I8, I16, I32, I64, Bool);
+event_implement_try_from_for_int128!(i128);
+event_implement_try_from_for_int128!(u128);
 
 event_implement_try_from_from_event!(f64, F32, F64);
 
 event_implement_try_from_from_event!(String, Str, OwnedStr);
+event_implement_try_from_from_event!(Vec<u8>, Bytes, OwnedBytes);
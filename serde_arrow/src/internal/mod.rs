@@ -1,3 +1,4 @@
+pub mod batching;
 pub mod common;
 pub mod config;
 pub mod conversions;
@@ -10,4 +11,5 @@ pub mod schema;
 pub mod serialization_ng;
 pub mod sink;
 pub mod source;
+pub mod timezone;
 pub mod tracing;
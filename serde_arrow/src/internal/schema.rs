@@ -4,7 +4,9 @@ use std::{
 };
 
 use crate::internal::{
+    config::CONFIGURATION,
     error::{fail, Error, Result},
+    timezone::Timezone,
     tracing::{Tracer, TracingMode, TracingOptions},
 };
 
@@ -16,6 +18,14 @@ use serde::{Deserialize, Serialize};
 ///
 pub const STRATEGY_KEY: &str = "SERDE_ARROW:strategy";
 
+/// The metadata key under which arrow stores the name of an extension type
+///
+pub const EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+
+/// The metadata key under which arrow stores the metadata of an extension type
+///
+pub const EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+
 pub trait Sealed {}
 
 /// A sealed trait to add support for constructing schema-like objects
@@ -94,6 +104,10 @@ pub trait SchemaLike: Sized + Sealed {
     ///   to use (e.g., "NaiveStrAsDate64").
     /// - `"children"` (**optional**): a list of child fields, the semantics
     ///   depend on the data type
+    /// - `"extension_name"`, `"extension_metadata"` (**optional**): the name
+    ///   and the metadata of the extension type of the field
+    /// - `"metadata"` (**optional**): an object with additional string
+    ///   metadata of the field, e.g., descriptions or units
     ///
     /// The following data types are supported:
     ///
@@ -102,16 +116,35 @@ pub trait SchemaLike: Sized + Sealed {
     /// - unsigned integers: `"U8"`, `"U16"`, `"U32"`, `"U64"`
     /// - floats: `"F16"`, `"F32"`, `"F64"`
     /// - strings: `"Utf8"`, `"LargeUtf8"`
-    /// - decimals: `"Decimal128(precision, scale)"`, as in `"Decimal128(5, 2)"`
-    /// - lists: `"List"`, `"LargeList"`. `"children"` must contain a single
-    ///   field named `"element"` that describes the element types
+    /// - bytes: `"Binary"`, `"LargeBinary"`, `"FixedSizeBinary(n)"` with the
+    ///   number of bytes per value, as in `"FixedSizeBinary(16)"`
+    /// - dates: `"Date32"`, `"Date64"`
+    /// - times: `"Time32(unit)"` with unit `"Second"` or `"Millisecond"`,
+    ///   `"Time64(unit)"` with unit `"Microsecond"` or `"Nanosecond"`
+    /// - durations: `"Duration(unit)"` with unit `"Second"`, `"Millisecond"`,
+    ///   `"Microsecond"` or `"Nanosecond"`
+    /// - intervals: `"Interval(unit)"` with unit `"YearMonth"`, `"DayTime"` or
+    ///   `"MonthDayNano"`
+    /// - decimals: `"Decimal128(precision, scale)"`, as in `"Decimal128(5, 2)"`,
+    ///   or `"Decimal256(precision, scale)"` for precisions of up to 76 digits
+    /// - lists: `"List"`, `"LargeList"`, `"FixedSizeList(n)"` with the number
+    ///   of elements per list, as in `"FixedSizeList(3)"`. `"children"` must
+    ///   contain a single field named `"element"` that describes the element
+    ///   types
     /// - structs: `"Struct"`. `"children"` must contain the child fields
     /// - maps: `"Map"`. `"children"` must contain two fields, named `"key"` and
-    ///   `"value"` that encode the key and value types
-    /// - unions: `"Union"`. `"children"` must contain the different variants
+    ///   `"value"` that encode the key and value types. With `"sorted": true`,
+    ///   the entries of each map are sorted by key
+    /// - unions: `"Union"` or `"Union(mode)"` with mode `"Dense"` (the default)
+    ///   or `"Sparse"`. `"children"` must contain the different variants. The
+    ///   optional `"type_ids"` list the type id of each variant, by default the
+    ///   index of the variant is used
     /// - dictionaries: `"Dictionary"`. `"children"` must contain two different
-    ///   fields, named `"key"` of integer type and named `"value"` of string
-    ///   type
+    ///   fields, named `"key"` of integer type and named `"value"` of integer,
+    ///   float, string or binary type
+    /// - run-end encoded arrays: `"RunEndEncoded"`. `"children"` must contain
+    ///   two fields, named `"run_ends"` of type `"I16"`, `"I32"` or `"I64"` and
+    ///   named `"values"` of a non-nested type
     ///
     fn from_value<T: Serialize + ?Sized>(value: &T) -> Result<Self>;
 
@@ -358,25 +391,52 @@ pub enum Strategy {
     /// Marker that the type of the field could not be determined during tracing
     ///
     InconsistentTypes,
-    /// Serialize Rust strings containing UTC datetimes with timezone as Arrows
-    /// Date64
+    /// Serialize Rust strings containing datetimes with timezone as Arrows
+    /// Date64 or as timestamps with timezone of any unit
+    ///
+    /// Datetimes with any offset are normalized to UTC. In deserialization,
+    /// timestamps are formatted in the timezone of the field.
     ///
     /// This strategy makes sense for chrono's `DateTime<Utc>` types without
     /// additional configuration. As they are serialized as strings.
     UtcStrAsDate64,
     /// Serialize Rust strings containing datetimes without timezone as Arrow
-    /// Date64
+    /// Date64 or as timestamps without timezone of any unit
     ///
     /// This strategy makes sense for chrono's `NaiveDateTime` types without
     /// additional configuration. As they are serialized as strings.
     ///
     NaiveStrAsDate64,
+    /// Serialize Rust strings containing dates without time as Arrow Date32
+    ///
+    /// This strategy makes sense for chrono's `NaiveDate` types without
+    /// additional configuration. As they are serialized as strings.
+    ///
+    NaiveStrAsDate32,
+    /// Serialize Rust strings containing times without date as Arrow Time32
+    /// or Time64
+    ///
+    /// This strategy makes sense for chrono's `NaiveTime` types without
+    /// additional configuration. As they are serialized as strings.
+    ///
+    NaiveStrAsTime,
+    /// Serialize Rust structs with `secs` and `nanos` fields as Arrow Duration
+    ///
+    /// This strategy makes sense for `std::time::Duration` without additional
+    /// configuration. As it is serialized as a struct with the fields `secs`
+    /// and `nanos`. Without this strategy, durations are deserialized as
+    /// integers.
+    ///
+    SecsNanosAsDuration,
     /// Serialize Rust tuples as Arrow structs with numeric field names starting
     /// at `"0"`
     ///
     /// This strategy is most-likely the most optimal one, as Rust tuples can
     /// contain different types, whereas Arrow sequences must be of uniform type
     ///
+    /// For `Interval(DayTime)` and `Interval(MonthDayNano)` fields, this
+    /// strategy deserializes the interval as a tuple instead of a struct.
+    ///
     TupleAsStruct,
     /// Serialize Rust maps as Arrow structs
     ///
@@ -400,6 +460,23 @@ pub enum Strategy {
     /// serialization or deserialization of such a field is attempted, it will
     /// result in an error.
     UnknownVariant,
+    /// Mark the child of a nullable union that encodes missing values
+    ///
+    /// This strategy applies only to fields with DataType Null that are
+    /// children of nullable unions. The child does not correspond to a variant,
+    /// `None` is serialized as this child and deserialized from it.
+    NullVariant,
+    /// Serialize Rust strings containing UUIDs as Arrow FixedSizeBinary(16)
+    ///
+    /// Both the hyphenated and the simple hex format are accepted in
+    /// serialization. In deserialization, UUIDs are formatted as lowercase
+    /// hyphenated strings.
+    ///
+    /// This strategy makes sense for uuid's `Uuid` type without additional
+    /// configuration, as it is serialized as a string for human readable
+    /// formats.
+    ///
+    UuidStrAsFixedSizeBinary,
 }
 
 impl std::fmt::Display for Strategy {
@@ -408,9 +485,14 @@ impl std::fmt::Display for Strategy {
             Self::InconsistentTypes => write!(f, "InconsistentTypes"),
             Self::UtcStrAsDate64 => write!(f, "UtcStrAsDate64"),
             Self::NaiveStrAsDate64 => write!(f, "NaiveStrAsDate64"),
+            Self::NaiveStrAsDate32 => write!(f, "NaiveStrAsDate32"),
+            Self::NaiveStrAsTime => write!(f, "NaiveStrAsTime"),
+            Self::SecsNanosAsDuration => write!(f, "SecsNanosAsDuration"),
             Self::TupleAsStruct => write!(f, "TupleAsStruct"),
             Self::MapAsStruct => write!(f, "MapAsStruct"),
             Self::UnknownVariant => write!(f, "UnknownVariant"),
+            Self::NullVariant => write!(f, "NullVariant"),
+            Self::UuidStrAsFixedSizeBinary => write!(f, "UuidStrAsFixedSizeBinary"),
         }
     }
 }
@@ -437,14 +519,45 @@ impl FromStr for Strategy {
             "InconsistentTypes" => Ok(Self::InconsistentTypes),
             "UtcStrAsDate64" => Ok(Self::UtcStrAsDate64),
             "NaiveStrAsDate64" => Ok(Self::NaiveStrAsDate64),
+            "NaiveStrAsDate32" => Ok(Self::NaiveStrAsDate32),
+            "NaiveStrAsTime" => Ok(Self::NaiveStrAsTime),
+            "SecsNanosAsDuration" => Ok(Self::SecsNanosAsDuration),
             "TupleAsStruct" => Ok(Self::TupleAsStruct),
             "MapAsStruct" => Ok(Self::MapAsStruct),
             "UnknownVariant" => Ok(Self::UnknownVariant),
+            "NullVariant" => Ok(Self::NullVariant),
+            "UuidStrAsFixedSizeBinary" => Ok(Self::UuidStrAsFixedSizeBinary),
             _ => fail!("Unknown strategy {s}"),
         }
     }
 }
 
+/// Register the strategy to use for fields of the given extension type
+///
+/// When converting arrow fields into a schema, fields with the extension name
+/// `name` (stored under [`EXTENSION_NAME_KEY`]) and without an explicit
+/// strategy use the registered strategy. When converting the schema back into
+/// arrow fields, the strategy is always stored in the metadata.
+///
+/// The registry is shared by all threads in the current program.
+///
+/// ```rust
+/// # use serde_arrow::schema::{register_extension_strategy, Strategy};
+/// register_extension_strategy("arrow.uuid", Strategy::UuidStrAsFixedSizeBinary);
+/// ```
+pub fn register_extension_strategy(name: &str, strategy: Strategy) {
+    let mut guard = CONFIGURATION.write().unwrap();
+    guard
+        .extension_strategies
+        .insert(name.to_string(), strategy);
+}
+
+/// Get the strategy registered for the given extension type
+pub(crate) fn get_extension_strategy(name: &str) -> Option<Strategy> {
+    let guard = CONFIGURATION.read().unwrap();
+    guard.extension_strategies.get(name).cloned()
+}
+
 impl From<Strategy> for BTreeMap<String, String> {
     fn from(value: Strategy) -> Self {
         let mut res = BTreeMap::new();
@@ -480,6 +593,82 @@ impl std::fmt::Display for GenericTimeUnit {
     }
 }
 
+impl FromStr for GenericTimeUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "Second" => Ok(Self::Second),
+            "Millisecond" => Ok(Self::Millisecond),
+            "Microsecond" => Ok(Self::Microsecond),
+            "Nanosecond" => Ok(Self::Nanosecond),
+            s => fail!("expected valid time unit, found: {s:?}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum GenericIntervalUnit {
+    YearMonth,
+    DayTime,
+    MonthDayNano,
+}
+
+impl std::fmt::Display for GenericIntervalUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenericIntervalUnit::YearMonth => write!(f, "YearMonth"),
+            GenericIntervalUnit::DayTime => write!(f, "DayTime"),
+            GenericIntervalUnit::MonthDayNano => write!(f, "MonthDayNano"),
+        }
+    }
+}
+
+impl FromStr for GenericIntervalUnit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "YearMonth" => Ok(Self::YearMonth),
+            "DayTime" => Ok(Self::DayTime),
+            "MonthDayNano" => Ok(Self::MonthDayNano),
+            s => fail!("expected valid interval unit, found: {s:?}"),
+        }
+    }
+}
+
+/// The memory layout of union arrays
+///
+/// In dense unions, each child only contains the values of its variant and
+/// an additional offsets buffer maps rows to child positions. In sparse
+/// unions, all children have the same length as the union.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum GenericUnionMode {
+    Sparse,
+    Dense,
+}
+
+impl std::fmt::Display for GenericUnionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenericUnionMode::Sparse => write!(f, "Sparse"),
+            GenericUnionMode::Dense => write!(f, "Dense"),
+        }
+    }
+}
+
+impl FromStr for GenericUnionMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim() {
+            "Sparse" => Ok(Self::Sparse),
+            "Dense" => Ok(Self::Dense),
+            s => fail!("expected valid union mode, found: {s:?}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
 #[serde(try_from = "GenericDataTypeString", into = "GenericDataTypeString")]
 pub enum GenericDataType {
@@ -498,15 +687,26 @@ pub enum GenericDataType {
     F64,
     Utf8,
     LargeUtf8,
+    Binary,
+    LargeBinary,
+    FixedSizeBinary(i32),
+    Date32,
     Date64,
+    Time32(GenericTimeUnit),
+    Time64(GenericTimeUnit),
+    Duration(GenericTimeUnit),
+    Interval(GenericIntervalUnit),
     Struct,
     List,
     LargeList,
-    Union,
+    FixedSizeList(i32),
+    Union(GenericUnionMode),
     Map,
     Dictionary,
+    RunEndEncoded,
     Timestamp(GenericTimeUnit, Option<String>),
     Decimal128(u8, i8),
+    Decimal256(u8, i8),
 }
 
 impl std::fmt::Display for GenericDataType {
@@ -517,6 +717,9 @@ impl std::fmt::Display for GenericDataType {
             Bool => write!(f, "Bool"),
             Utf8 => write!(f, "Utf8"),
             LargeUtf8 => write!(f, "LargeUtf8"),
+            Binary => write!(f, "Binary"),
+            LargeBinary => write!(f, "LargeBinary"),
+            FixedSizeBinary(n) => write!(f, "FixedSizeBinary({n})"),
             I8 => write!(f, "I8"),
             I16 => write!(f, "I16"),
             I32 => write!(f, "I32"),
@@ -528,13 +731,22 @@ impl std::fmt::Display for GenericDataType {
             F16 => write!(f, "F16"),
             F32 => write!(f, "F32"),
             F64 => write!(f, "F64"),
+            Date32 => write!(f, "Date32"),
             Date64 => write!(f, "Date64"),
+            Time32(unit) => write!(f, "Time32({unit})"),
+            Time64(unit) => write!(f, "Time64({unit})"),
+            Duration(unit) => write!(f, "Duration({unit})"),
+            Interval(unit) => write!(f, "Interval({unit})"),
             Struct => write!(f, "Struct"),
             List => write!(f, "List"),
             LargeList => write!(f, "LargeList"),
-            Union => write!(f, "Union"),
+            FixedSizeList(n) => write!(f, "FixedSizeList({n})"),
+            // NOTE: dense unions are the default and written without mode
+            Union(GenericUnionMode::Dense) => write!(f, "Union"),
+            Union(mode) => write!(f, "Union({mode})"),
             Map => write!(f, "Map"),
             Dictionary => write!(f, "Dictionary"),
+            RunEndEncoded => write!(f, "RunEndEncoded"),
             Timestamp(unit, timezone) => {
                 if let Some(timezone) = timezone {
                     write!(f, "Timestamp({unit}, Some(\"{timezone}\"))")
@@ -543,10 +755,24 @@ impl std::fmt::Display for GenericDataType {
                 }
             }
             Decimal128(precision, scale) => write!(f, "Decimal128({precision}, {scale})"),
+            Decimal256(precision, scale) => write!(f, "Decimal256({precision}, {scale})"),
         }
     }
 }
 
+/// Parse the `precision, scale)` part of a decimal data type
+fn parse_decimal_params(name: &str, s: &str) -> Result<(u8, i8)> {
+    let Some(s) = s.strip_suffix(')') else {
+        fail!("invalid {name} data type");
+    };
+    let Some((precision, scale)) = s.split_once(',') else {
+        fail!("invalid {name} data type");
+    };
+    let precision = u8::from_str(precision.trim())?;
+    let scale = i8::from_str(scale.trim())?;
+    Ok((precision, scale))
+}
+
 impl std::str::FromStr for GenericDataType {
     type Err = Error;
 
@@ -561,6 +787,10 @@ impl std::str::FromStr for GenericDataType {
             Ok(GenericDataType::Utf8)
         } else if s == "LargeUtf8" {
             Ok(GenericDataType::LargeUtf8)
+        } else if s == "Binary" {
+            Ok(GenericDataType::Binary)
+        } else if s == "LargeBinary" {
+            Ok(GenericDataType::LargeBinary)
         } else if s == "U8" || s == "UInt8" {
             Ok(GenericDataType::U8)
         } else if s == "U16" || s == "UInt16" {
@@ -583,6 +813,8 @@ impl std::str::FromStr for GenericDataType {
             Ok(GenericDataType::F32)
         } else if s == "F64" || s == "Float64" {
             Ok(GenericDataType::F64)
+        } else if s == "Date32" {
+            Ok(GenericDataType::Date32)
         } else if s == "Date64" {
             Ok(GenericDataType::Date64)
         } else if s == "Struct" {
@@ -592,11 +824,13 @@ impl std::str::FromStr for GenericDataType {
         } else if s == "LargeList" {
             Ok(GenericDataType::LargeList)
         } else if s == "Union" {
-            Ok(GenericDataType::Union)
+            Ok(GenericDataType::Union(GenericUnionMode::Dense))
         } else if s == "Map" {
             Ok(GenericDataType::Map)
         } else if s == "Dictionary" {
             Ok(GenericDataType::Dictionary)
+        } else if s == "RunEndEncoded" {
+            Ok(GenericDataType::RunEndEncoded)
         } else if let Some(s) = s.strip_prefix("Timestamp(") {
             let (s, unit) = if let Some(s) = s.strip_prefix("Second, ") {
                 (s, GenericTimeUnit::Second)
@@ -625,17 +859,47 @@ impl std::str::FromStr for GenericDataType {
             };
 
             Ok(GenericDataType::Timestamp(unit, Some(s.to_string())))
-        } else if let Some(s) = s.strip_prefix("Decimal128(") {
+        } else if let Some(s) = s.strip_prefix("Time32(") {
             let Some(s) = s.strip_suffix(')') else {
-                fail!("invalid Decimal128 data type");
+                fail!("invalid Time32 data type");
             };
-            let Some((precision, scale)) = s.split_once(',') else {
-                fail!("invalid Decimal128 data type");
+            Ok(GenericDataType::Time32(s.parse()?))
+        } else if let Some(s) = s.strip_prefix("Time64(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid Time64 data type");
             };
-            let precision = u8::from_str(precision.trim())?;
-            let scale = i8::from_str(scale.trim())?;
-
+            Ok(GenericDataType::Time64(s.parse()?))
+        } else if let Some(s) = s.strip_prefix("Duration(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid Duration data type");
+            };
+            Ok(GenericDataType::Duration(s.parse()?))
+        } else if let Some(s) = s.strip_prefix("Interval(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid Interval data type");
+            };
+            Ok(GenericDataType::Interval(s.parse()?))
+        } else if let Some(s) = s.strip_prefix("Union(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid Union data type");
+            };
+            Ok(GenericDataType::Union(s.parse()?))
+        } else if let Some(s) = s.strip_prefix("FixedSizeList(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid FixedSizeList data type");
+            };
+            Ok(GenericDataType::FixedSizeList(i32::from_str(s.trim())?))
+        } else if let Some(s) = s.strip_prefix("FixedSizeBinary(") {
+            let Some(s) = s.strip_suffix(')') else {
+                fail!("invalid FixedSizeBinary data type");
+            };
+            Ok(GenericDataType::FixedSizeBinary(i32::from_str(s.trim())?))
+        } else if let Some(s) = s.strip_prefix("Decimal128(") {
+            let (precision, scale) = parse_decimal_params("Decimal128", s)?;
             Ok(GenericDataType::Decimal128(precision, scale))
+        } else if let Some(s) = s.strip_prefix("Decimal256(") {
+            let (precision, scale) = parse_decimal_params("Decimal256", s)?;
+            Ok(GenericDataType::Decimal256(precision, scale))
         } else {
             fail!("cannot parse data type")
         }
@@ -675,12 +939,49 @@ pub struct GenericField {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub children: Vec<GenericField>,
+
+    /// The type ids of the children of unions, defaults to their indices
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ids: Option<Vec<i8>>,
+
+    /// Whether the keys of each map are sorted
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub sorted: bool,
+
+    /// The name of the extension type, stored under `ARROW:extension:name`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_name: Option<String>,
+
+    /// The metadata of the extension type, stored under
+    /// `ARROW:extension:metadata`
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension_metadata: Option<String>,
+
+    /// Additional metadata of the field, e.g., descriptions or units
+    ///
+    /// The strategy and the extension type are stored in their own attributes
+    /// and must not be included.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
 }
 
 fn is_false(val: &bool) -> bool {
     !*val
 }
 
+/// Whether the key of the field metadata is stored in a dedicated attribute
+pub(crate) fn is_reserved_metadata_key(key: &str) -> bool {
+    matches!(
+        key,
+        STRATEGY_KEY | EXTENSION_NAME_KEY | EXTENSION_METADATA_KEY
+    )
+}
+
 impl GenericField {
     pub fn new(name: &str, data_type: GenericDataType, nullable: bool) -> Self {
         Self {
@@ -689,6 +990,11 @@ impl GenericField {
             nullable,
             children: Vec::new(),
             strategy: None,
+            type_ids: None,
+            sorted: false,
+            extension_name: None,
+            extension_metadata: None,
+            metadata: BTreeMap::new(),
         }
     }
 
@@ -697,6 +1003,29 @@ impl GenericField {
     }
 
     pub fn validate(&self) -> Result<()> {
+        if self.type_ids.is_some() && !matches!(self.data_type, GenericDataType::Union(_)) {
+            fail!(
+                "type ids are only supported for Union fields, found: {}",
+                self.data_type
+            );
+        }
+        if self.sorted && !matches!(self.data_type, GenericDataType::Map) {
+            fail!(
+                "sorted is only supported for Map fields, found: {}",
+                self.data_type
+            );
+        }
+        if self.extension_metadata.is_some() && self.extension_name.is_none() {
+            fail!("extension metadata requires an extension name");
+        }
+        if let Some(key) = self
+            .metadata
+            .keys()
+            .find(|key| is_reserved_metadata_key(key))
+        {
+            fail!("metadata must not contain the reserved key {key:?}");
+        }
+
         match self.data_type {
             GenericDataType::Null => self.validate_null(),
             GenericDataType::Bool => self.validate_primitive(),
@@ -713,15 +1042,26 @@ impl GenericField {
             GenericDataType::F64 => self.validate_primitive(),
             GenericDataType::Utf8 => self.validate_primitive(),
             GenericDataType::LargeUtf8 => self.validate_primitive(),
+            GenericDataType::Binary => self.validate_primitive(),
+            GenericDataType::LargeBinary => self.validate_primitive(),
+            GenericDataType::FixedSizeBinary(_) => self.validate_fixed_size_binary(),
+            GenericDataType::Date32 => self.validate_date32(),
             GenericDataType::Date64 => self.validate_date64(),
             GenericDataType::Struct => self.validate_struct(),
             GenericDataType::Map => self.validate_map(),
             GenericDataType::List => self.validate_list(),
             GenericDataType::LargeList => self.validate_list(),
-            GenericDataType::Union => self.validate_union(),
+            GenericDataType::FixedSizeList(_) => self.validate_list(),
+            GenericDataType::Union(_) => self.validate_union(),
             GenericDataType::Dictionary => self.validate_dictionary(),
+            GenericDataType::RunEndEncoded => self.validate_run_end_encoded(),
             GenericDataType::Timestamp(_, _) => self.validate_timestamp(),
+            GenericDataType::Time32(_) => self.validate_time(),
+            GenericDataType::Time64(_) => self.validate_time(),
+            GenericDataType::Duration(_) => self.validate_duration(),
+            GenericDataType::Interval(_) => self.validate_interval(),
             GenericDataType::Decimal128(_, _) => self.validate_primitive(),
+            GenericDataType::Decimal256(_, _) => self.validate_primitive(),
         }
     }
 
@@ -758,13 +1098,38 @@ impl GenericField {
         self.strategy = strategy;
         self
     }
+
+    pub fn with_metadata(mut self, key: &str, value: &str) -> Self {
+        self.metadata.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn with_extension(mut self, name: &str, metadata: Option<&str>) -> Self {
+        self.extension_name = Some(name.to_string());
+        self.extension_metadata = metadata.map(str::to_string);
+        self
+    }
+
+    /// Use the strategy registered for the extension type, if no strategy is
+    /// set explicitly
+    pub(crate) fn with_extension_strategy(mut self) -> Self {
+        if self.strategy.is_none() {
+            self.strategy = self
+                .extension_name
+                .as_deref()
+                .and_then(get_extension_strategy);
+        }
+        self
+    }
 }
 
 impl GenericField {
     pub(crate) fn validate_null(&self) -> Result<()> {
         if !matches!(
             self.strategy,
-            None | Some(Strategy::InconsistentTypes) | Some(Strategy::UnknownVariant)
+            None | Some(Strategy::InconsistentTypes)
+                | Some(Strategy::UnknownVariant)
+                | Some(Strategy::NullVariant)
         ) {
             fail!(
                 "invalid strategy for Null field: {}",
@@ -791,6 +1156,34 @@ impl GenericField {
         Ok(())
     }
 
+    pub(crate) fn validate_fixed_size_binary(&self) -> Result<()> {
+        if let GenericDataType::FixedSizeBinary(n) = &self.data_type {
+            if *n < 0 {
+                fail!("invalid byte width for FixedSizeBinary field: {n}");
+            }
+            if matches!(self.strategy, Some(Strategy::UuidStrAsFixedSizeBinary)) {
+                if *n != 16 {
+                    fail!("UuidStrAsFixedSizeBinary requires FixedSizeBinary(16), found: {n}");
+                }
+                if !self.children.is_empty() {
+                    fail!("{} field must not have children", self.data_type);
+                }
+                return Ok(());
+            }
+        }
+        self.validate_primitive()
+    }
+
+    pub(crate) fn validate_date32(&self) -> Result<()> {
+        if !matches!(self.strategy, None | Some(Strategy::NaiveStrAsDate32)) {
+            fail!(
+                "invalid strategy for Date32 field: {}",
+                self.strategy.as_ref().unwrap()
+            );
+        }
+        Ok(())
+    }
+
     pub(crate) fn validate_date64(&self) -> Result<()> {
         if !matches!(
             self.strategy,
@@ -808,21 +1201,18 @@ impl GenericField {
         match &self.strategy {
             None => Ok(()),
             Some(strategy @ Strategy::UtcStrAsDate64) => {
-                if !matches!(&self.data_type, GenericDataType::Timestamp(GenericTimeUnit::Millisecond, Some(tz)) if tz.to_uppercase() == "UTC")
-                {
+                let GenericDataType::Timestamp(_, Some(tz)) = &self.data_type else {
                     fail!(
                         "invalid strategy for timestamp field {}: {}",
                         self.data_type,
                         strategy,
                     );
-                }
+                };
+                Timezone::parse(tz)?;
                 Ok(())
             }
             Some(strategy @ Strategy::NaiveStrAsDate64) => {
-                if !matches!(
-                    &self.data_type,
-                    GenericDataType::Timestamp(GenericTimeUnit::Millisecond, None)
-                ) {
+                if !matches!(&self.data_type, GenericDataType::Timestamp(_, None)) {
                     fail!(
                         "invalid strategy for timestamp field {}: {}",
                         self.data_type,
@@ -839,6 +1229,60 @@ impl GenericField {
         }
     }
 
+    pub(crate) fn validate_time(&self) -> Result<()> {
+        match &self.data_type {
+            GenericDataType::Time32(GenericTimeUnit::Second | GenericTimeUnit::Millisecond)
+            | GenericDataType::Time64(GenericTimeUnit::Microsecond | GenericTimeUnit::Nanosecond) =>
+                {}
+            dt => fail!("invalid time unit for {dt}"),
+        }
+        if !matches!(self.strategy, None | Some(Strategy::NaiveStrAsTime)) {
+            fail!(
+                "invalid strategy for {} field: {}",
+                self.data_type,
+                self.strategy.as_ref().unwrap()
+            );
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_duration(&self) -> Result<()> {
+        if !matches!(self.strategy, None | Some(Strategy::SecsNanosAsDuration)) {
+            fail!(
+                "invalid strategy for {} field: {}",
+                self.data_type,
+                self.strategy.as_ref().unwrap()
+            );
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_interval(&self) -> Result<()> {
+        match (&self.data_type, &self.strategy) {
+            (GenericDataType::Interval(GenericIntervalUnit::YearMonth), None) => {}
+            (
+                GenericDataType::Interval(
+                    GenericIntervalUnit::DayTime | GenericIntervalUnit::MonthDayNano,
+                ),
+                None | Some(Strategy::TupleAsStruct),
+            ) => {}
+            (_, Some(strategy)) => {
+                fail!("invalid strategy for {} field: {strategy}", self.data_type)
+            }
+            (dt, None) => fail!("invalid data type for interval field: {dt}"),
+        }
+        if !self.children.is_empty() {
+            fail!("{} field must not have children", self.data_type);
+        }
+        Ok(())
+    }
+
     pub(crate) fn validate_struct(&self) -> Result<()> {
         // NOTE: do not check number of children: arrow-rs can 0 children, arrow2 not
         if !matches!(
@@ -889,6 +1333,11 @@ impl GenericField {
     }
 
     pub(crate) fn validate_list(&self) -> Result<()> {
+        if let GenericDataType::FixedSizeList(n) = &self.data_type {
+            if *n < 0 {
+                fail!("invalid number of elements for FixedSizeList field: {n}");
+            }
+        }
         if self.strategy.is_some() {
             fail!(
                 "invalid strategy for List field: {}",
@@ -916,12 +1365,68 @@ impl GenericField {
         if self.children.is_empty() {
             fail!("Union field without children");
         }
+        if let Some(type_ids) = self.type_ids.as_ref() {
+            if type_ids.len() != self.children.len() {
+                fail!(
+                    "invalid number of type ids for Union field. Expected {}, found: {}",
+                    self.children.len(),
+                    type_ids.len()
+                );
+            }
+            for (idx, type_id) in type_ids.iter().enumerate() {
+                if *type_id < 0 {
+                    fail!(
+                        "invalid type id {type_id} for Union field, type ids must not be negative"
+                    );
+                }
+                if type_ids[..idx].contains(type_id) {
+                    fail!("duplicate type id {type_id} for Union field");
+                }
+            }
+        }
+        let num_null_variants = self
+            .children
+            .iter()
+            .filter(|child| matches!(child.strategy, Some(Strategy::NullVariant)))
+            .count();
+        if num_null_variants > 1 {
+            fail!("Union field with more than one null variant");
+        }
+        if num_null_variants == 1 && !self.nullable {
+            fail!("Union field with a null variant must be nullable");
+        }
         for child in &self.children {
             child.validate()?;
         }
         Ok(())
     }
 
+    /// Get the type ids of the children of a union
+    ///
+    /// Without explicit type ids, the type id of each child is its index.
+    pub(crate) fn get_union_type_ids(&self) -> Result<Vec<i8>> {
+        match self.type_ids.as_ref() {
+            Some(type_ids) => Ok(type_ids.clone()),
+            None => (0..self.children.len())
+                .map(|idx| Ok(i8::try_from(idx)?))
+                .collect(),
+        }
+    }
+
+    /// Get the index of the child that encodes missing values of a union
+    ///
+    /// Nullable unions store missing values in a child of type `Null` with the
+    /// [`NullVariant`][Strategy::NullVariant] strategy, that does not
+    /// correspond to a variant.
+    pub(crate) fn get_union_null_variant(&self) -> Option<usize> {
+        if !self.nullable || !matches!(self.data_type, GenericDataType::Union(_)) {
+            return None;
+        }
+        self.children
+            .iter()
+            .position(|child| matches!(child.strategy, Some(Strategy::NullVariant)))
+    }
+
     pub(crate) fn validate_dictionary(&self) -> Result<()> {
         if self.strategy.is_some() {
             fail!(
@@ -953,10 +1458,67 @@ impl GenericField {
         }
         if !matches!(
             self.children[1].data_type,
-            GenericDataType::Utf8 | GenericDataType::LargeUtf8
+            GenericDataType::U8
+                | GenericDataType::U16
+                | GenericDataType::U32
+                | GenericDataType::U64
+                | GenericDataType::I8
+                | GenericDataType::I16
+                | GenericDataType::I32
+                | GenericDataType::I64
+                | GenericDataType::F16
+                | GenericDataType::F32
+                | GenericDataType::F64
+                | GenericDataType::Utf8
+                | GenericDataType::LargeUtf8
+                | GenericDataType::Binary
+                | GenericDataType::LargeBinary
         ) {
             fail!(
-                "invalid child for Dictionary. Expected string values, found: {}",
+                "invalid child for Dictionary. Expected primitive, string or binary values, found: {}",
+                self.children[1].data_type
+            );
+        }
+        for child in &self.children {
+            child.validate()?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn validate_run_end_encoded(&self) -> Result<()> {
+        if let Some(strategy) = self.strategy.as_ref() {
+            fail!("invalid strategy for RunEndEncoded field: {strategy}");
+        }
+        if self.children.len() != 2 {
+            fail!(
+                "invalid number of children for RunEndEncoded field. Expected 2, found: {}",
+                self.children.len()
+            );
+        }
+        if !matches!(
+            self.children[0].data_type,
+            GenericDataType::I16 | GenericDataType::I32 | GenericDataType::I64
+        ) {
+            fail!(
+                "invalid child for RunEndEncoded. Expected I16, I32 or I64 run ends, found: {}",
+                self.children[0].data_type
+            );
+        }
+        if self.children[0].nullable {
+            fail!("invalid child for RunEndEncoded. The run ends must not be nullable");
+        }
+        if matches!(
+            self.children[1].data_type,
+            GenericDataType::Struct
+                | GenericDataType::List
+                | GenericDataType::LargeList
+                | GenericDataType::FixedSizeList(_)
+                | GenericDataType::Map
+                | GenericDataType::Union(_)
+                | GenericDataType::RunEndEncoded
+        ) {
+            fail!(
+                "invalid child for RunEndEncoded. Expected non-nested values, found: {}",
                 self.children[1].data_type
             );
         }
@@ -991,10 +1553,20 @@ fn field_is_compatible(left: &GenericField, right: &GenericField) -> bool {
             &right.data_type,
             D::I32 | D::I64 | D::U8 | D::U16 | D::U32 | D::U64
         ),
-        D::I32 => matches!(&right.data_type, D::I64 | D::U8 | D::U16 | D::U32 | D::U64),
+        D::I32 => matches!(
+            &right.data_type,
+            D::I64
+                | D::U8
+                | D::U16
+                | D::U32
+                | D::U64
+                | D::Date32
+                | D::Time32(_)
+                | D::Interval(GenericIntervalUnit::YearMonth)
+        ),
         D::I64 => matches!(
             &right.data_type,
-            D::U8 | D::U16 | D::U32 | D::U64 | D::Date64
+            D::U8 | D::U16 | D::U32 | D::U64 | D::Date64 | D::Time64(_) | D::Duration(_)
         ),
         D::U8 => matches!(&right.data_type, D::U16 | D::U32 | D::U64),
         D::U16 => matches!(&right.data_type, D::U32 | D::U64),
@@ -1002,6 +1574,10 @@ fn field_is_compatible(left: &GenericField, right: &GenericField) -> bool {
         D::Utf8 => match &right.data_type {
             D::LargeUtf8 => true,
             D::Dictionary => true,
+            D::Date32 => matches!(&right.strategy, Some(Strategy::NaiveStrAsDate32)),
+            D::Time32(_) | D::Time64(_) => {
+                matches!(&right.strategy, Some(Strategy::NaiveStrAsTime))
+            }
             D::Date64 => matches!(
                 &right.strategy,
                 Some(Strategy::NaiveStrAsDate64) | Some(Strategy::UtcStrAsDate64)
@@ -1010,12 +1586,17 @@ fn field_is_compatible(left: &GenericField, right: &GenericField) -> bool {
         },
         D::LargeUtf8 => match &right.data_type {
             D::Dictionary => true,
+            D::Date32 => matches!(&right.strategy, Some(Strategy::NaiveStrAsDate32)),
+            D::Time32(_) | D::Time64(_) => {
+                matches!(&right.strategy, Some(Strategy::NaiveStrAsTime))
+            }
             D::Date64 => matches!(
                 &right.strategy,
                 Some(Strategy::NaiveStrAsDate64) | Some(Strategy::UtcStrAsDate64)
             ),
             _ => false,
         },
+        D::Binary => matches!(&right.data_type, D::LargeBinary),
         D::Dictionary => right.data_type == D::Dictionary,
         _ => false,
     }
@@ -1147,6 +1728,48 @@ mod test_schema_serialization {
         assert_eq!(dt, rt);
     }
 
+    #[test]
+    fn time_serialization() {
+        use super::{GenericDataType as DT, GenericIntervalUnit as I, GenericTimeUnit as U};
+
+        for (dt, expected) in [
+            (DT::Time32(U::Second), r#""Time32(Second)""#),
+            (DT::Time32(U::Millisecond), r#""Time32(Millisecond)""#),
+            (DT::Time64(U::Microsecond), r#""Time64(Microsecond)""#),
+            (DT::Time64(U::Nanosecond), r#""Time64(Nanosecond)""#),
+            (DT::Duration(U::Second), r#""Duration(Second)""#),
+            (DT::Duration(U::Nanosecond), r#""Duration(Nanosecond)""#),
+            (DT::Interval(I::YearMonth), r#""Interval(YearMonth)""#),
+            (DT::Interval(I::DayTime), r#""Interval(DayTime)""#),
+            (DT::Interval(I::MonthDayNano), r#""Interval(MonthDayNano)""#),
+        ] {
+            let s = serde_json::to_string(&dt).unwrap();
+            assert_eq!(s, expected);
+
+            let rt: DT = serde_json::from_str(&s).unwrap();
+            assert_eq!(dt, rt);
+        }
+    }
+
+    #[test]
+    fn union_serialization() {
+        use super::{GenericDataType as DT, GenericUnionMode as M};
+
+        for (dt, expected) in [
+            (DT::Union(M::Dense), r#""Union""#),
+            (DT::Union(M::Sparse), r#""Union(Sparse)""#),
+        ] {
+            let s = serde_json::to_string(&dt).unwrap();
+            assert_eq!(s, expected);
+
+            let rt: DT = serde_json::from_str(&s).unwrap();
+            assert_eq!(dt, rt);
+        }
+
+        let rt: DT = serde_json::from_str(r#""Union(Dense)""#).unwrap();
+        assert_eq!(rt, DT::Union(M::Dense));
+    }
+
     #[test]
     fn test_long_form_types() {
         use super::GenericDataType as DT;
@@ -1172,6 +1795,22 @@ mod test_schema_serialization {
             DT::from_str("Decimal128( 8 , -2 )").unwrap(),
             DT::Decimal128(8, -2)
         );
+        assert_eq!(
+            DT::from_str("Decimal256(76, 10)").unwrap(),
+            DT::Decimal256(76, 10)
+        );
+        assert_eq!(
+            DT::from_str("FixedSizeBinary(16)").unwrap(),
+            DT::FixedSizeBinary(16)
+        );
+        assert_eq!(
+            DT::from_str("FixedSizeBinary( 32 )").unwrap(),
+            DT::FixedSizeBinary(32)
+        );
+        assert_eq!(
+            DT::from_str("FixedSizeList(3)").unwrap(),
+            DT::FixedSizeList(3)
+        );
     }
 
     macro_rules! test_data_type {
@@ -1195,7 +1834,30 @@ mod test_schema_serialization {
     }
 
     test_data_type!(
-        Null, Bool, I8, I16, I32, I64, U8, U16, U32, U64, F16, F32, F64, Utf8, LargeUtf8, List,
-        LargeList, Struct, Dictionary, Union, Map, Date64,
+        Null,
+        Bool,
+        I8,
+        I16,
+        I32,
+        I64,
+        U8,
+        U16,
+        U32,
+        U64,
+        F16,
+        F32,
+        F64,
+        Utf8,
+        LargeUtf8,
+        Binary,
+        LargeBinary,
+        List,
+        LargeList,
+        Struct,
+        Dictionary,
+        RunEndEncoded,
+        Map,
+        Date32,
+        Date64,
     );
 }
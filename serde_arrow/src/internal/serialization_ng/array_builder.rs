@@ -5,22 +5,31 @@ use crate::{
     internal::{
         error::fail,
         schema::{GenericDataType, GenericField, GenericTimeUnit},
+        timezone::Timezone,
     },
     schema::{SerdeArrowSchema, Strategy},
     Result,
 };
 
 use super::{
+    binary_builder::BinaryBuilder,
     bool_builder::BoolBuilder,
+    date32_builder::Date32Builder,
     date64_builder::Date64Builder,
-    decimal_builder::DecimalBuilder,
-    dictionary_utf8_builder::DictionaryUtf8Builder,
+    decimal_builder::{Decimal256Builder, DecimalBuilder},
+    dictionary_builder::DictionaryBuilder,
+    duration_builder::DurationBuilder,
+    fixed_size_binary_builder::FixedSizeBinaryBuilder,
+    fixed_size_list_builder::FixedSizeListBuilder,
     float_builder::FloatBuilder,
     int_builder::IntBuilder,
+    interval_builder::IntervalBuilder,
     list_builder::ListBuilder,
     map_builder::MapBuilder,
     null_builder::NullBuilder,
+    run_end_encoded_builder::RunEndEncodedBuilder,
     struct_builder::StructBuilder,
+    time_builder::TimeBuilder,
     union_builder::UnionBuilder,
     unknown_variant_builder::UnknownVariantBuilder,
     utf8_builder::Utf8Builder,
@@ -43,15 +52,26 @@ pub enum ArrayBuilder {
     F16(FloatBuilder<f16>),
     F32(FloatBuilder<f32>),
     F64(FloatBuilder<f64>),
+    Date32(Date32Builder),
     Date64(Date64Builder),
+    Time32(TimeBuilder<i32>),
+    Time64(TimeBuilder<i64>),
+    Duration(DurationBuilder),
+    Interval(IntervalBuilder),
     Decimal128(DecimalBuilder),
+    Decimal256(Decimal256Builder),
     List(ListBuilder<i32>),
     LargeList(ListBuilder<i64>),
+    FixedSizeList(FixedSizeListBuilder),
     Map(MapBuilder),
     Struct(StructBuilder),
     Utf8(Utf8Builder<i32>),
     LargeUtf8(Utf8Builder<i64>),
-    DictionaryUtf8(DictionaryUtf8Builder),
+    Binary(BinaryBuilder<i32>),
+    LargeBinary(BinaryBuilder<i64>),
+    FixedSizeBinary(FixedSizeBinaryBuilder),
+    Dictionary(DictionaryBuilder),
+    RunEndEncoded(RunEndEncodedBuilder),
     Union(UnionBuilder),
     UnknownVariant(UnknownVariantBuilder),
 }
@@ -72,15 +92,26 @@ macro_rules! dispatch {
             $wrapper::F16($name) => $expr,
             $wrapper::F32($name) => $expr,
             $wrapper::F64($name) => $expr,
+            $wrapper::Date32($name) => $expr,
             $wrapper::Date64($name) => $expr,
+            $wrapper::Time32($name) => $expr,
+            $wrapper::Time64($name) => $expr,
+            $wrapper::Duration($name) => $expr,
+            $wrapper::Interval($name) => $expr,
             $wrapper::Decimal128($name) => $expr,
+            $wrapper::Decimal256($name) => $expr,
             $wrapper::Utf8($name) => $expr,
             $wrapper::LargeUtf8($name) => $expr,
+            $wrapper::Binary($name) => $expr,
+            $wrapper::LargeBinary($name) => $expr,
+            $wrapper::FixedSizeBinary($name) => $expr,
             $wrapper::List($name) => $expr,
             $wrapper::LargeList($name) => $expr,
+            $wrapper::FixedSizeList($name) => $expr,
             $wrapper::Map($name) => $expr,
             $wrapper::Struct($name) => $expr,
-            $wrapper::DictionaryUtf8($name) => $expr,
+            $wrapper::Dictionary($name) => $expr,
+            $wrapper::RunEndEncoded($name) => $expr,
             $wrapper::Union($name) => $expr,
             $wrapper::UnknownVariant($name) => $expr,
         }
@@ -122,7 +153,7 @@ impl ArrayBuilder {
             let builder = match &field.data_type {
                 T::Null => {
                     if matches!(&field.strategy, Some(Strategy::UnknownVariant)) {
-                        A::UnknownVariant(UnknownVariantBuilder)
+                        A::UnknownVariant(UnknownVariantBuilder::default())
                     } else {
                         A::Null(NullBuilder::new())
                     }
@@ -139,34 +170,98 @@ impl ArrayBuilder {
                 T::F16 => A::F16(FloatBuilder::new(field.nullable)),
                 T::F32 => A::F32(FloatBuilder::new(field.nullable)),
                 T::F64 => A::F64(FloatBuilder::new(field.nullable)),
-                T::Date64 => match field.strategy.as_ref() {
-                    Some(Strategy::NaiveStrAsDate64) => {
-                        A::Date64(Date64Builder::new(field.clone(), false, field.nullable))
-                    }
-                    Some(Strategy::UtcStrAsDate64) => {
-                        A::Date64(Date64Builder::new(field.clone(), true, field.nullable))
+                T::Date32 => match field.strategy.as_ref() {
+                    None | Some(Strategy::NaiveStrAsDate32) => {
+                        A::Date32(Date32Builder::new(field.nullable))
                     }
-                    None => A::Date64(Date64Builder::new(field.clone(), false, field.nullable)),
+                    Some(st) => fail!("Cannot builder Date32 builder with strategy {st}"),
+                },
+                T::Date64 => match field.strategy.as_ref() {
+                    Some(Strategy::NaiveStrAsDate64) => A::Date64(Date64Builder::new(
+                        field.clone(),
+                        GenericTimeUnit::Millisecond,
+                        false,
+                        field.nullable,
+                    )),
+                    Some(Strategy::UtcStrAsDate64) => A::Date64(Date64Builder::new(
+                        field.clone(),
+                        GenericTimeUnit::Millisecond,
+                        true,
+                        field.nullable,
+                    )),
+                    None => A::Date64(Date64Builder::new(
+                        field.clone(),
+                        GenericTimeUnit::Millisecond,
+                        false,
+                        field.nullable,
+                    )),
                     Some(st) => fail!("Cannot builder Date64 builder with strategy {st}"),
                 },
-                T::Timestamp(unit, tz) => {
-                    if !matches!(unit, GenericTimeUnit::Millisecond) {
-                        fail!("Only timestamps with millisecond unit are supported");
+                T::Time32(unit) => {
+                    if !matches!(unit, GenericTimeUnit::Second | GenericTimeUnit::Millisecond) {
+                        fail!("Time32 only supports second or millisecond units");
                     }
-
-                    match tz.as_deref() {
-                        None => A::Date64(Date64Builder::new(field.clone(), false, field.nullable)),
-                        Some(tz) if tz.to_uppercase() == "UTC" => {
-                            A::Date64(Date64Builder::new(field.clone(), true, field.nullable))
-                        }
-                        Some(tz) => fail!("Timezone {tz} is not supported"),
+                    A::Time32(TimeBuilder::new(
+                        field.clone(),
+                        unit.clone(),
+                        field.nullable,
+                    ))
+                }
+                T::Time64(unit) => {
+                    if !matches!(
+                        unit,
+                        GenericTimeUnit::Microsecond | GenericTimeUnit::Nanosecond
+                    ) {
+                        fail!("Time64 only supports microsecond or nanosecond units");
                     }
+                    A::Time64(TimeBuilder::new(
+                        field.clone(),
+                        unit.clone(),
+                        field.nullable,
+                    ))
+                }
+                T::Duration(unit) => A::Duration(DurationBuilder::new(
+                    field.clone(),
+                    unit.clone(),
+                    field.nullable,
+                )),
+                T::Interval(unit) => {
+                    A::Interval(IntervalBuilder::new(unit.clone(), field.nullable))
                 }
+                T::Timestamp(unit, tz) => match tz.as_deref() {
+                    None => A::Date64(Date64Builder::new(
+                        field.clone(),
+                        unit.clone(),
+                        false,
+                        field.nullable,
+                    )),
+                    Some(tz) => {
+                        Timezone::parse(tz)?;
+                        A::Date64(Date64Builder::new(
+                            field.clone(),
+                            unit.clone(),
+                            true,
+                            field.nullable,
+                        ))
+                    }
+                },
                 T::Decimal128(precision, scale) => {
                     A::Decimal128(DecimalBuilder::new(*precision, *scale, field.nullable))
                 }
+                T::Decimal256(precision, scale) => {
+                    A::Decimal256(Decimal256Builder::new(*precision, *scale, field.nullable))
+                }
                 T::Utf8 => A::Utf8(Utf8Builder::new(field.nullable)),
                 T::LargeUtf8 => A::LargeUtf8(Utf8Builder::new(field.nullable)),
+                T::Binary => A::Binary(BinaryBuilder::new(field.nullable)),
+                T::LargeBinary => A::LargeBinary(BinaryBuilder::new(field.nullable)),
+                T::FixedSizeBinary(n) => {
+                    let Ok(n) = usize::try_from(*n) else {
+                        fail!("Invalid byte width for FixedSizeBinary: {n}");
+                    };
+                    let uuid = matches!(field.strategy, Some(Strategy::UuidStrAsFixedSizeBinary));
+                    A::FixedSizeBinary(FixedSizeBinaryBuilder::new(n, uuid, field.nullable))
+                }
                 T::List => {
                     let Some(child) = field.children.first() else {
                         fail!("cannot build a list without an element field");
@@ -187,6 +282,20 @@ impl ArrayBuilder {
                         field.nullable,
                     ))
                 }
+                T::FixedSizeList(n) => {
+                    let Some(child) = field.children.first() else {
+                        fail!("cannot build fixed size list without an element field");
+                    };
+                    let Ok(n) = usize::try_from(*n) else {
+                        fail!("Invalid number of elements for FixedSizeList: {n}");
+                    };
+                    A::FixedSizeList(FixedSizeListBuilder::new(
+                        child.clone(),
+                        build_builder(child)?,
+                        n,
+                        field.nullable,
+                    ))
+                }
                 T::Map => {
                     let Some(entry_field) = field.children.first() else {
                         fail!("Cannot build a map with an entry field");
@@ -198,6 +307,7 @@ impl ArrayBuilder {
                         entry_field.clone(),
                         build_builder(entry_field)?,
                         field.nullable,
+                        field.sorted,
                     ))
                 }
                 T::Struct => build_struct(&field.children, field.nullable)?,
@@ -208,26 +318,32 @@ impl ArrayBuilder {
                     let Some(values) = field.children.get(1) else {
                         fail!("Cannot build a dictionary without values field");
                     };
-                    if !matches!(values.data_type, T::Utf8 | T::LargeUtf8) {
-                        fail!("At the moment only string dictionaries are supported");
-                    }
                     // TODO: figure out how arrow encodes nullability and fix this
                     let mut indices = indices.clone();
                     indices.nullable = field.nullable;
 
-                    A::DictionaryUtf8(DictionaryUtf8Builder::new(
+                    A::Dictionary(DictionaryBuilder::new(
                         field.clone(),
                         build_builder(&indices)?,
                         build_builder(values)?,
                     ))
                 }
-                T::Union => {
+                T::RunEndEncoded => {
+                    let Some(values) = field.children.get(1) else {
+                        fail!("Cannot build a run-end encoded array without values field");
+                    };
+                    A::RunEndEncoded(RunEndEncodedBuilder::new(
+                        field.clone(),
+                        build_builder(values)?,
+                    ))
+                }
+                T::Union(mode) => {
                     let mut fields = Vec::new();
                     for field in &field.children {
                         fields.push(build_builder(field)?);
                     }
 
-                    A::Union(UnionBuilder::new(field.clone(), fields)?)
+                    A::Union(UnionBuilder::new(field.clone(), fields, *mode)?)
                 }
             };
             Ok(builder)
@@ -251,15 +367,26 @@ impl ArrayBuilder {
             Self::F16(_) => "F16",
             Self::F32(_) => "F32",
             Self::F64(_) => "F64",
+            Self::Date32(_) => "Date32",
             Self::Date64(_) => "Date64",
+            Self::Time32(_) => "Time32",
+            Self::Time64(_) => "Time64",
+            Self::Duration(_) => "Duration",
+            Self::Interval(_) => "Interval",
             Self::Decimal128(_) => "Decimal128",
+            Self::Decimal256(_) => "Decimal256",
             Self::Utf8(_) => "Utf8",
             Self::LargeUtf8(_) => "LargeUtf8",
+            Self::Binary(_) => "Binary",
+            Self::LargeBinary(_) => "LargeBinary",
+            Self::FixedSizeBinary(_) => "FixedSizeBinary",
             Self::List(_) => "List",
             Self::LargeList(_) => "LargeList",
+            Self::FixedSizeList(_) => "FixedSizeList",
             Self::Struct(_) => "Struct",
             Self::Map(_) => "Map",
-            Self::DictionaryUtf8(_) => "DictionaryUtf8",
+            Self::Dictionary(_) => "Dictionary",
+            Self::RunEndEncoded(_) => "RunEndEncoded",
             Self::Union(_) => "Union",
             Self::UnknownVariant(_) => "UnknownVariant",
         }
@@ -268,6 +395,11 @@ impl ArrayBuilder {
     pub fn is_nullable(&self) -> bool {
         dispatch!(self, Self(builder) => builder.is_nullable())
     }
+
+    /// Estimate the number of bytes of the buffers filled so far
+    pub fn num_bytes(&self) -> usize {
+        dispatch!(self, Self(builder) => builder.num_bytes())
+    }
 }
 
 impl ArrayBuilder {
@@ -290,6 +422,14 @@ impl ArrayBuilder {
         Ok(result)
     }
 
+    /// Estimate the number of bytes of the records, for a `large_list<struct>>` builder
+    pub fn num_record_bytes(&self) -> Result<usize> {
+        let ArrayBuilder::LargeList(inner) = self else {
+            fail!("cannot estimate the records without an outer LargeList<..>");
+        };
+        Ok(inner.element.num_bytes())
+    }
+
     /// Take the contained array builder, while leaving structure intact
     pub fn take(&mut self) -> ArrayBuilder {
         match self {
@@ -306,15 +446,26 @@ impl ArrayBuilder {
             Self::F16(builder) => Self::F16(builder.take()),
             Self::F32(builder) => Self::F32(builder.take()),
             Self::F64(builder) => Self::F64(builder.take()),
+            Self::Date32(builder) => Self::Date32(builder.take()),
             Self::Date64(builder) => Self::Date64(builder.take()),
+            Self::Time32(builder) => Self::Time32(builder.take()),
+            Self::Time64(builder) => Self::Time64(builder.take()),
+            Self::Duration(builder) => Self::Duration(builder.take()),
+            Self::Interval(builder) => Self::Interval(builder.take()),
             Self::Decimal128(builder) => Self::Decimal128(builder.take()),
+            Self::Decimal256(builder) => Self::Decimal256(builder.take()),
             Self::Utf8(builder) => Self::Utf8(builder.take()),
             Self::LargeUtf8(builder) => Self::LargeUtf8(builder.take()),
+            Self::Binary(builder) => Self::Binary(builder.take()),
+            Self::LargeBinary(builder) => Self::LargeBinary(builder.take()),
+            Self::FixedSizeBinary(builder) => Self::FixedSizeBinary(builder.take()),
             Self::List(builder) => Self::List(builder.take()),
             Self::LargeList(builder) => Self::LargeList(builder.take()),
+            Self::FixedSizeList(builder) => Self::FixedSizeList(builder.take()),
             Self::Struct(builder) => Self::Struct(builder.take()),
             Self::Map(builder) => Self::Map(builder.take()),
-            Self::DictionaryUtf8(builder) => Self::DictionaryUtf8(builder.take()),
+            Self::Dictionary(builder) => Self::Dictionary(builder.take()),
+            Self::RunEndEncoded(builder) => Self::RunEndEncoded(builder.take()),
             Self::Union(builder) => Self::Union(builder.take()),
             Self::UnknownVariant(builder) => Self::UnknownVariant(builder.take()),
         }
@@ -389,6 +540,14 @@ impl SimpleSerializer for ArrayBuilder {
         dispatch!(self, Self(builder) => builder.serialize_u64(v))
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_i128(v))
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        dispatch!(self, Self(builder) => builder.serialize_u128(v))
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
         dispatch!(self, Self(builder) => builder.serialize_f32(v))
     }
@@ -0,0 +1,65 @@
+use crate::{
+    internal::common::{MutableBitBuffer, MutableOffsetBuffer, Offset},
+    Result,
+};
+
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
+
+#[derive(Debug, Clone)]
+pub struct BinaryBuilder<O> {
+    pub validity: Option<MutableBitBuffer>,
+    pub offsets: MutableOffsetBuffer<O>,
+    pub buffer: Vec<u8>,
+}
+
+impl<O: Offset> BinaryBuilder<O> {
+    pub fn new(is_nullable: bool) -> Self {
+        Self {
+            validity: is_nullable.then(MutableBitBuffer::default),
+            offsets: MutableOffsetBuffer::default(),
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            validity: self.validity.as_mut().map(std::mem::take),
+            offsets: std::mem::take(&mut self.offsets),
+            buffer: std::mem::take(&mut self.buffer),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.offsets.num_bytes() + self.buffer.len()
+    }
+}
+
+impl<O: Offset> SimpleSerializer for BinaryBuilder<O> {
+    fn name(&self) -> &str {
+        "BinaryBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.offsets.push_current_items();
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.offsets.push_current_items();
+        Ok(())
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        push_validity(&mut self.validity, true)?;
+        self.offsets.push(v.len())?;
+        self.buffer.extend(v);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{internal::common::MutableBitBuffer, Result};
 
-use super::utils::{push_validity, push_validity_default, SimpleSerializer};
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
 
 #[derive(Debug, Clone)]
 pub struct BoolBuilder {
@@ -26,6 +26,10 @@ impl BoolBuilder {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.buffer.num_bytes()
+    }
 }
 
 impl SimpleSerializer for BoolBuilder {
@@ -0,0 +1,505 @@
+use serde::{
+    ser::{
+        SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize, Serializer,
+};
+
+use crate::{internal::error::fail, Error, Result};
+
+/// An owned copy of a serialized value
+///
+/// The content can be serialized again and results in the same sequence of
+/// serializer calls as the original value. It is used to buffer the entries of
+/// sorted maps until they can be serialized in the order of their keys.
+#[derive(Debug, Clone)]
+pub enum Content {
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    F32(f32),
+    F64(f64),
+    Char(char),
+    Str(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    UnitStruct(&'static str),
+    UnitVariant(&'static str, u32, &'static str),
+    NewtypeStruct(&'static str, Box<Content>),
+    NewtypeVariant(&'static str, u32, &'static str, Box<Content>),
+    Seq(Vec<Content>),
+    Tuple(Vec<Content>),
+    TupleStruct(&'static str, Vec<Content>),
+    TupleVariant(&'static str, u32, &'static str, Vec<Content>),
+    Map(Vec<(Content, Content)>),
+    Struct(&'static str, Vec<(&'static str, Content)>),
+    StructVariant(
+        &'static str,
+        u32,
+        &'static str,
+        Vec<(&'static str, Content)>,
+    ),
+}
+
+impl Content {
+    pub fn new<V: Serialize + ?Sized>(value: &V) -> Result<Self> {
+        value.serialize(ContentSerializer)
+    }
+}
+
+impl Serialize for Content {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Content::Bool(v) => serializer.serialize_bool(*v),
+            Content::I8(v) => serializer.serialize_i8(*v),
+            Content::I16(v) => serializer.serialize_i16(*v),
+            Content::I32(v) => serializer.serialize_i32(*v),
+            Content::I64(v) => serializer.serialize_i64(*v),
+            Content::I128(v) => serializer.serialize_i128(*v),
+            Content::U8(v) => serializer.serialize_u8(*v),
+            Content::U16(v) => serializer.serialize_u16(*v),
+            Content::U32(v) => serializer.serialize_u32(*v),
+            Content::U64(v) => serializer.serialize_u64(*v),
+            Content::U128(v) => serializer.serialize_u128(*v),
+            Content::F32(v) => serializer.serialize_f32(*v),
+            Content::F64(v) => serializer.serialize_f64(*v),
+            Content::Char(v) => serializer.serialize_char(*v),
+            Content::Str(v) => serializer.serialize_str(v),
+            Content::Bytes(v) => serializer.serialize_bytes(v),
+            Content::None => serializer.serialize_none(),
+            Content::Some(v) => serializer.serialize_some(v.as_ref()),
+            Content::Unit => serializer.serialize_unit(),
+            Content::UnitStruct(name) => serializer.serialize_unit_struct(name),
+            Content::UnitVariant(name, idx, variant) => {
+                serializer.serialize_unit_variant(name, *idx, variant)
+            }
+            Content::NewtypeStruct(name, v) => {
+                serializer.serialize_newtype_struct(name, v.as_ref())
+            }
+            Content::NewtypeVariant(name, idx, variant, v) => {
+                serializer.serialize_newtype_variant(name, *idx, variant, v.as_ref())
+            }
+            Content::Seq(elements) => {
+                let mut seq = serializer.serialize_seq(Some(elements.len()))?;
+                for element in elements {
+                    seq.serialize_element(element)?;
+                }
+                seq.end()
+            }
+            Content::Tuple(elements) => {
+                let mut tuple = serializer.serialize_tuple(elements.len())?;
+                for element in elements {
+                    tuple.serialize_element(element)?;
+                }
+                tuple.end()
+            }
+            Content::TupleStruct(name, fields) => {
+                let mut tuple = serializer.serialize_tuple_struct(name, fields.len())?;
+                for field in fields {
+                    tuple.serialize_field(field)?;
+                }
+                tuple.end()
+            }
+            Content::TupleVariant(name, idx, variant, fields) => {
+                let mut tuple =
+                    serializer.serialize_tuple_variant(name, *idx, variant, fields.len())?;
+                for field in fields {
+                    tuple.serialize_field(field)?;
+                }
+                tuple.end()
+            }
+            Content::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+            Content::Struct(name, fields) => {
+                let mut struct_ = serializer.serialize_struct(name, fields.len())?;
+                for (key, value) in fields {
+                    struct_.serialize_field(key, value)?;
+                }
+                struct_.end()
+            }
+            Content::StructVariant(name, idx, variant, fields) => {
+                let mut struct_ =
+                    serializer.serialize_struct_variant(name, *idx, variant, fields.len())?;
+                for (key, value) in fields {
+                    struct_.serialize_field(key, value)?;
+                }
+                struct_.end()
+            }
+        }
+    }
+}
+
+struct ContentSerializer;
+
+impl Serializer for ContentSerializer {
+    type Ok = Content;
+    type Error = Error;
+
+    type SerializeSeq = SeqContent;
+    type SerializeTuple = SeqContent;
+    type SerializeTupleStruct = SeqContent;
+    type SerializeTupleVariant = SeqContent;
+    type SerializeMap = MapContent;
+    type SerializeStruct = StructContent;
+    type SerializeStructVariant = StructContent;
+
+    fn serialize_bool(self, v: bool) -> Result<Content> {
+        Ok(Content::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Content> {
+        Ok(Content::I8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Content> {
+        Ok(Content::I16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Content> {
+        Ok(Content::I32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Content> {
+        Ok(Content::I64(v))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Content> {
+        Ok(Content::I128(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Content> {
+        Ok(Content::U8(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Content> {
+        Ok(Content::U16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Content> {
+        Ok(Content::U32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Content> {
+        Ok(Content::U64(v))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Content> {
+        Ok(Content::U128(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Content> {
+        Ok(Content::F32(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Content> {
+        Ok(Content::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Content> {
+        Ok(Content::Char(v))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Content> {
+        Ok(Content::Str(v.to_owned()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Content> {
+        Ok(Content::Bytes(v.to_owned()))
+    }
+
+    fn serialize_none(self) -> Result<Content> {
+        Ok(Content::None)
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<Content> {
+        Ok(Content::Some(Box::new(Content::new(value)?)))
+    }
+
+    fn serialize_unit(self) -> Result<Content> {
+        Ok(Content::Unit)
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Content> {
+        Ok(Content::UnitStruct(name))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Content> {
+        Ok(Content::UnitVariant(name, variant_index, variant))
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &V,
+    ) -> Result<Content> {
+        Ok(Content::NewtypeStruct(name, Box::new(Content::new(value)?)))
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &V,
+    ) -> Result<Content> {
+        Ok(Content::NewtypeVariant(
+            name,
+            variant_index,
+            variant,
+            Box::new(Content::new(value)?),
+        ))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqContent> {
+        Ok(SeqContent::new(SeqKind::Seq, len.unwrap_or_default()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqContent> {
+        Ok(SeqContent::new(SeqKind::Tuple, len))
+    }
+
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<SeqContent> {
+        Ok(SeqContent::new(SeqKind::TupleStruct(name), len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqContent> {
+        Ok(SeqContent::new(
+            SeqKind::TupleVariant(name, variant_index, variant),
+            len,
+        ))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<MapContent> {
+        Ok(MapContent {
+            entries: Vec::with_capacity(len.unwrap_or_default()),
+            key: None,
+        })
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<StructContent> {
+        Ok(StructContent::new(StructKind::Struct(name), len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<StructContent> {
+        Ok(StructContent::new(
+            StructKind::StructVariant(name, variant_index, variant),
+            len,
+        ))
+    }
+}
+
+enum SeqKind {
+    Seq,
+    Tuple,
+    TupleStruct(&'static str),
+    TupleVariant(&'static str, u32, &'static str),
+}
+
+struct SeqContent {
+    kind: SeqKind,
+    elements: Vec<Content>,
+}
+
+impl SeqContent {
+    fn new(kind: SeqKind, len: usize) -> Self {
+        Self {
+            kind,
+            elements: Vec::with_capacity(len),
+        }
+    }
+
+    fn push<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.elements.push(Content::new(value)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Content> {
+        Ok(match self.kind {
+            SeqKind::Seq => Content::Seq(self.elements),
+            SeqKind::Tuple => Content::Tuple(self.elements),
+            SeqKind::TupleStruct(name) => Content::TupleStruct(name, self.elements),
+            SeqKind::TupleVariant(name, idx, variant) => {
+                Content::TupleVariant(name, idx, variant, self.elements)
+            }
+        })
+    }
+}
+
+impl SerializeSeq for SeqContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
+
+impl SerializeTuple for SeqContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleStruct for SeqContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleVariant for SeqContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
+
+struct MapContent {
+    entries: Vec<(Content, Content)>,
+    key: Option<Content>,
+}
+
+impl SerializeMap for MapContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        self.key = Some(Content::new(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        let Some(key) = self.key.take() else {
+            fail!("serialize_value called without a key");
+        };
+        self.entries.push((key, Content::new(value)?));
+        Ok(())
+    }
+
+    fn end(self) -> Result<Content> {
+        Ok(Content::Map(self.entries))
+    }
+}
+
+enum StructKind {
+    Struct(&'static str),
+    StructVariant(&'static str, u32, &'static str),
+}
+
+struct StructContent {
+    kind: StructKind,
+    fields: Vec<(&'static str, Content)>,
+}
+
+impl StructContent {
+    fn new(kind: StructKind, len: usize) -> Self {
+        Self {
+            kind,
+            fields: Vec::with_capacity(len),
+        }
+    }
+
+    fn push<V: Serialize + ?Sized>(&mut self, key: &'static str, value: &V) -> Result<()> {
+        self.fields.push((key, Content::new(value)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Content> {
+        Ok(match self.kind {
+            StructKind::Struct(name) => Content::Struct(name, self.fields),
+            StructKind::StructVariant(name, idx, variant) => {
+                Content::StructVariant(name, idx, variant, self.fields)
+            }
+        })
+    }
+}
+
+impl SerializeStruct for StructContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for StructContent {
+    type Ok = Content;
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Content> {
+        self.finish()
+    }
+}
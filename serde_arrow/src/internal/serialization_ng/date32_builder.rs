@@ -0,0 +1,76 @@
+use chrono::NaiveDate;
+
+use crate::{
+    internal::{common::MutableBitBuffer, error::fail},
+    Result,
+};
+
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
+
+#[derive(Debug, Clone)]
+pub struct Date32Builder {
+    pub validity: Option<MutableBitBuffer>,
+    pub buffer: Vec<i32>,
+}
+
+impl Date32Builder {
+    pub fn new(nullable: bool) -> Self {
+        Self {
+            validity: nullable.then(MutableBitBuffer::default),
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            validity: self.validity.as_mut().map(std::mem::take),
+            buffer: std::mem::take(&mut self.buffer),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
+}
+
+impl SimpleSerializer for Date32Builder {
+    fn name(&self) -> &str {
+        "Date32Builder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.buffer.push(0);
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.buffer.push(0);
+        Ok(())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        let Some(unix_epoch) = NaiveDate::from_ymd_opt(1970, 1, 1) else {
+            fail!("Cannot construct the unix epoch");
+        };
+        let days_since_epoch = v
+            .parse::<NaiveDate>()?
+            .signed_duration_since(unix_epoch)
+            .num_days();
+
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(i32::try_from(days_since_epoch)?);
+        Ok(())
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(v);
+        Ok(())
+    }
+}
@@ -1,22 +1,30 @@
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+
 use crate::{
-    internal::{common::MutableBitBuffer, schema::GenericField},
+    internal::{
+        common::MutableBitBuffer,
+        error::error,
+        schema::{GenericField, GenericTimeUnit},
+    },
     Result,
 };
 
-use super::utils::{push_validity, push_validity_default, SimpleSerializer};
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
 
 #[derive(Debug, Clone)]
 pub struct Date64Builder {
     pub field: GenericField,
+    pub unit: GenericTimeUnit,
     pub utc: bool,
     pub validity: Option<MutableBitBuffer>,
     pub buffer: Vec<i64>,
 }
 
 impl Date64Builder {
-    pub fn new(field: GenericField, utc: bool, nullable: bool) -> Self {
+    pub fn new(field: GenericField, unit: GenericTimeUnit, utc: bool, nullable: bool) -> Self {
         Self {
             field,
+            unit,
             utc,
             validity: nullable.then(MutableBitBuffer::default),
             buffer: Vec::new(),
@@ -26,6 +34,7 @@ impl Date64Builder {
     pub fn take(&mut self) -> Self {
         Self {
             field: self.field.clone(),
+            unit: self.unit.clone(),
             utc: self.utc,
             validity: self.validity.as_mut().map(std::mem::take),
             buffer: std::mem::take(&mut self.buffer),
@@ -35,6 +44,10 @@ impl Date64Builder {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
 }
 
 impl SimpleSerializer for Date64Builder {
@@ -55,13 +68,34 @@ impl SimpleSerializer for Date64Builder {
     }
 
     fn serialize_str(&mut self, v: &str) -> Result<()> {
-        let timestamp = if self.utc {
-            use chrono::{DateTime, Utc};
-            v.parse::<DateTime<Utc>>()?.timestamp_millis()
+        let date_time = if self.utc {
+            v.parse::<DateTime<Utc>>()?
         } else {
-            use chrono::NaiveDateTime;
-            v.parse::<NaiveDateTime>()?.timestamp_millis()
+            Utc.from_utc_datetime(&v.parse::<NaiveDateTime>()?)
         };
+
+        let seconds = date_time.timestamp();
+        let nanoseconds = i64::from(date_time.timestamp_subsec_nanos());
+
+        let timestamp = match self.unit {
+            GenericTimeUnit::Second => Some(seconds),
+            GenericTimeUnit::Millisecond => seconds
+                .checked_mul(1_000)
+                .and_then(|v| v.checked_add(nanoseconds / 1_000_000)),
+            GenericTimeUnit::Microsecond => seconds
+                .checked_mul(1_000_000)
+                .and_then(|v| v.checked_add(nanoseconds / 1_000)),
+            GenericTimeUnit::Nanosecond => seconds
+                .checked_mul(1_000_000_000)
+                .and_then(|v| v.checked_add(nanoseconds)),
+        };
+        let timestamp = timestamp.ok_or_else(|| {
+            error!(
+                "Timestamp {v} cannot be represented with unit {}",
+                self.unit
+            )
+        })?;
+
         push_validity(&mut self.validity, true)?;
         self.buffer.push(timestamp);
         Ok(())
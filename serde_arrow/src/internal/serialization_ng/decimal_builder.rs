@@ -2,11 +2,12 @@ use crate::{
     internal::{
         common::MutableBitBuffer,
         decimal::{self, DecimalParser},
+        error::fail,
     },
     Result,
 };
 
-use super::utils::{push_validity, push_validity_default, SimpleSerializer};
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
 
 #[derive(Debug, Clone)]
 pub struct DecimalBuilder {
@@ -47,6 +48,10 @@ impl DecimalBuilder {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
 }
 
 impl SimpleSerializer for DecimalBuilder {
@@ -78,6 +83,44 @@ impl SimpleSerializer for DecimalBuilder {
         Ok(())
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        let val = if self.scale >= 0 {
+            10_i128
+                .checked_pow(self.scale as u32)
+                .and_then(|factor| v.checked_mul(factor))
+        } else {
+            10_i128
+                .checked_pow(self.scale.unsigned_abs() as u32)
+                .map(|factor| v / factor)
+        };
+        let bound = 10_u128.checked_pow(self.precision as u32);
+        let Some(val) = val.filter(|val| match bound {
+            Some(bound) => val.unsigned_abs() < bound,
+            None => true,
+        }) else {
+            fail!(
+                "Value {v} exceeds the range of Decimal128({}, {})",
+                self.precision,
+                self.scale
+            );
+        };
+
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(val);
+        Ok(())
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        let Ok(v) = i128::try_from(v) else {
+            fail!(
+                "Value {v} exceeds the range of Decimal128({}, {})",
+                self.precision,
+                self.scale
+            );
+        };
+        self.serialize_i128(v)
+    }
+
     fn serialize_str(&mut self, v: &str) -> Result<()> {
         let mut parse_buffer = [0; decimal::BUFFER_SIZE_I128];
         let val = self
@@ -90,6 +133,87 @@ impl SimpleSerializer for DecimalBuilder {
     }
 }
 
+/// Build 256 bit decimal arrays
+///
+/// Values are stored as 256 bit integers in little endian byte order. Floats
+/// are converted via their decimal representation to avoid overflows of
+/// intermediate values.
+#[derive(Debug, Clone)]
+pub struct Decimal256Builder {
+    pub precision: u8,
+    pub scale: i8,
+    pub validity: Option<MutableBitBuffer>,
+    pub buffer: Vec<[u8; 32]>,
+    pub parser: DecimalParser,
+}
+
+impl Decimal256Builder {
+    pub fn new(precision: u8, scale: i8, nullable: bool) -> Self {
+        Self {
+            precision,
+            scale,
+            validity: nullable.then(MutableBitBuffer::default),
+            buffer: Vec::new(),
+            parser: DecimalParser::new(precision, scale, true),
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            precision: self.precision,
+            scale: self.scale,
+            validity: self.validity.as_mut().map(std::mem::take),
+            buffer: std::mem::take(&mut self.buffer),
+            parser: self.parser,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
+}
+
+impl SimpleSerializer for Decimal256Builder {
+    fn name(&self) -> &str {
+        "Decimal256Builder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.buffer.push([0; 32]);
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.buffer.push([0; 32]);
+        Ok(())
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.serialize_str(&v.to_string())
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.serialize_str(&v.to_string())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        let mut parse_buffer = [0; decimal::BUFFER_SIZE_I256];
+        let val = self
+            .parser
+            .parse_decimal256(&mut parse_buffer, v.as_bytes())?;
+
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(val);
+        Ok(())
+    }
+}
+
 /*
 
     fn accept_f32(
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::internal::{error::Result, schema::GenericField};
+
+use super::{
+    array_builder::ArrayBuilder,
+    utils::{Mut, SimpleSerializer},
+};
+
+/// Build dictionary encoded arrays
+///
+/// Each distinct value is added only once to the values builder. Values are
+/// deduplicated by their byte representation: integers by their numeric
+/// value, floats by their bit pattern and strings / bytes by their content.
+/// As the values builder accepts a single kind of value, the representations
+/// of different kinds never have to be distinguished.
+#[derive(Debug, Clone)]
+pub struct DictionaryBuilder {
+    pub field: GenericField,
+    pub indices: Box<ArrayBuilder>,
+    pub values: Box<ArrayBuilder>,
+    pub index: HashMap<Vec<u8>, usize>,
+}
+
+impl DictionaryBuilder {
+    pub fn new(field: GenericField, indices: ArrayBuilder, values: ArrayBuilder) -> Self {
+        Self {
+            field,
+            indices: Box::new(indices),
+            values: Box::new(values),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            field: self.field.clone(),
+            indices: Box::new(self.indices.take()),
+            values: Box::new(self.values.take()),
+            index: std::mem::take(&mut self.index),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.indices.is_nullable()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        self.indices.num_bytes() + self.values.num_bytes()
+    }
+}
+
+impl DictionaryBuilder {
+    /// Push the index of the entry, add the value if it is not yet known
+    fn push_entry<F>(&mut self, entry: &[u8], push_value: F) -> Result<()>
+    where
+        F: FnOnce(&mut ArrayBuilder) -> Result<()>,
+    {
+        let idx = match self.index.get(entry) {
+            Some(idx) => *idx,
+            None => {
+                let idx = self.index.len();
+                push_value(self.values.as_mut())?;
+                self.index.insert(entry.to_vec(), idx);
+                idx
+            }
+        };
+        idx.serialize(Mut(self.indices.as_mut()))
+    }
+}
+
+impl SimpleSerializer for DictionaryBuilder {
+    fn name(&self) -> &str {
+        "Dictionary"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        self.indices.serialize_none()
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        self.indices.serialize_none()
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_i8(v))
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_i16(v))
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_i32(v))
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_i64(v))
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_u8(v))
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_u16(v))
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_u32(v))
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.push_entry(&i128::from(v).to_le_bytes(), |b| b.serialize_u64(v))
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.push_entry(&v.to_bits().to_le_bytes(), |b| b.serialize_f32(v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.push_entry(&v.to_bits().to_le_bytes(), |b| b.serialize_f64(v))
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.push_entry(v.as_bytes(), |b| b.serialize_str(v))
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.push_entry(v, |b| b.serialize_bytes(v))
+    }
+}
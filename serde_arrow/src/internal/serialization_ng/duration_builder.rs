@@ -0,0 +1,201 @@
+use serde::Serialize;
+
+use crate::{
+    internal::{
+        common::MutableBitBuffer,
+        error::{error, fail},
+        schema::{GenericField, GenericTimeUnit},
+    },
+    Result,
+};
+
+use super::utils::{
+    push_validity, push_validity_default, validity_num_bytes, ComponentSerializer, Mut,
+    SimpleSerializer,
+};
+
+/// Build duration arrays from integers or `{secs, nanos}` structs
+///
+/// The struct shape corresponds to the serde implementation of
+/// `std::time::Duration`. Integers are interpreted in the unit of the field.
+#[derive(Debug, Clone)]
+pub struct DurationBuilder {
+    pub field: GenericField,
+    pub unit: GenericTimeUnit,
+    pub validity: Option<MutableBitBuffer>,
+    pub buffer: Vec<i64>,
+    pub secs: Option<i64>,
+    pub nanos: Option<i64>,
+    pub next: usize,
+}
+
+impl DurationBuilder {
+    pub fn new(field: GenericField, unit: GenericTimeUnit, nullable: bool) -> Self {
+        Self {
+            field,
+            unit,
+            validity: nullable.then(MutableBitBuffer::default),
+            buffer: Vec::new(),
+            secs: None,
+            nanos: None,
+            next: 0,
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            field: self.field.clone(),
+            unit: self.unit.clone(),
+            validity: self.validity.as_mut().map(std::mem::take),
+            buffer: std::mem::take(&mut self.buffer),
+            secs: None,
+            nanos: None,
+            next: 0,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
+}
+
+impl DurationBuilder {
+    fn push_value<V: TryInto<i64>>(&mut self, v: V) -> Result<()> {
+        let Ok(v) = v.try_into() else {
+            fail!("Duration value is out of range for i64");
+        };
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(v);
+        Ok(())
+    }
+
+    fn start(&mut self) {
+        self.secs = None;
+        self.nanos = None;
+        self.next = 0;
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, idx: usize, value: &V) -> Result<()> {
+        let mut component = ComponentSerializer(None);
+        value.serialize(Mut(&mut component))?;
+
+        match idx {
+            0 => self.secs = component.0,
+            1 => self.nanos = component.0,
+            _ => fail!("Duration values must have exactly two components (secs, nanos)"),
+        }
+        self.next = idx + 1;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        let (Some(secs), Some(nanos)) = (self.secs.take(), self.nanos.take()) else {
+            fail!("Duration values require both the secs and the nanos component");
+        };
+
+        let value = match self.unit {
+            GenericTimeUnit::Second => Some(secs),
+            GenericTimeUnit::Millisecond => secs
+                .checked_mul(1_000)
+                .and_then(|v| v.checked_add(nanos / 1_000_000)),
+            GenericTimeUnit::Microsecond => secs
+                .checked_mul(1_000_000)
+                .and_then(|v| v.checked_add(nanos / 1_000)),
+            GenericTimeUnit::Nanosecond => secs
+                .checked_mul(1_000_000_000)
+                .and_then(|v| v.checked_add(nanos)),
+        };
+        let value =
+            value.ok_or_else(|| error!("Duration value is out of range for {}", self.unit))?;
+
+        self.push_value(value)
+    }
+}
+
+impl SimpleSerializer for DurationBuilder {
+    fn name(&self) -> &str {
+        "DurationBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.buffer.push(0);
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.buffer.push(0);
+        Ok(())
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.push_value(v)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        self.start();
+        Ok(())
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        match key {
+            "secs" => self.element(0, value),
+            "nanos" => self.element(1, value),
+            _ => fail!("Unknown field {key:?} for Duration, expected \"secs\" or \"nanos\""),
+        }
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        self.end()
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.start();
+        Ok(())
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(self.next, value)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        self.end()
+    }
+}
@@ -0,0 +1,196 @@
+use serde::Serialize;
+
+use crate::{
+    internal::{common::MutableBitBuffer, error::fail},
+    Result,
+};
+
+use super::utils::{
+    push_validity, push_validity_default, validity_num_bytes, ComponentSerializer, Mut,
+    SimpleSerializer,
+};
+
+/// Build fixed size binary arrays
+///
+/// Values are accepted either as bytes (`serialize_bytes`) or as tuples /
+/// sequences of `u8` values. In both cases the number of bytes must match the
+/// byte width of the field. With the `UuidStrAsFixedSizeBinary` strategy,
+/// strings containing UUIDs are accepted as well. 128 bit integers are stored
+/// as their 16 little endian bytes.
+#[derive(Debug, Clone)]
+pub struct FixedSizeBinaryBuilder {
+    pub validity: Option<MutableBitBuffer>,
+    pub n: usize,
+    pub uuid: bool,
+    pub len: usize,
+    pub buffer: Vec<u8>,
+    current_n: usize,
+}
+
+impl FixedSizeBinaryBuilder {
+    pub fn new(n: usize, uuid: bool, is_nullable: bool) -> Self {
+        Self {
+            validity: is_nullable.then(MutableBitBuffer::default),
+            n,
+            uuid,
+            len: 0,
+            buffer: Vec::new(),
+            current_n: 0,
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            validity: self.validity.as_mut().map(std::mem::take),
+            n: self.n,
+            uuid: self.uuid,
+            len: std::mem::take(&mut self.len),
+            buffer: std::mem::take(&mut self.buffer),
+            current_n: 0,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.buffer.len()
+    }
+}
+
+impl FixedSizeBinaryBuilder {
+    fn start(&mut self) -> Result<()> {
+        self.current_n = 0;
+        Ok(())
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        if self.current_n >= self.n {
+            fail!(
+                "Invalid number of bytes for FixedSizeBinary({}), found more than {} bytes",
+                self.n,
+                self.n
+            );
+        }
+
+        let mut component = ComponentSerializer(None);
+        value.serialize(Mut(&mut component))?;
+        let Some(Ok(byte)) = component.0.map(u8::try_from) else {
+            fail!("FixedSizeBinary elements must be integers in the range of u8");
+        };
+
+        self.buffer.push(byte);
+        self.current_n += 1;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        if self.current_n != self.n {
+            fail!(
+                "Invalid number of bytes for FixedSizeBinary({}), found {}",
+                self.n,
+                self.current_n
+            );
+        }
+        push_validity(&mut self.validity, true)?;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl SimpleSerializer for FixedSizeBinaryBuilder {
+    fn name(&self) -> &str {
+        "FixedSizeBinaryBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.buffer.resize(self.buffer.len() + self.n, 0);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.buffer.resize(self.buffer.len() + self.n, 0);
+        self.len += 1;
+        Ok(())
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        if v.len() != self.n {
+            fail!(
+                "Invalid number of bytes for FixedSizeBinary({}), found {}",
+                self.n,
+                v.len()
+            );
+        }
+        push_validity(&mut self.validity, true)?;
+        self.buffer.extend(v);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        self.serialize_bytes(&v.to_le_bytes())
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        self.serialize_bytes(&v.to_le_bytes())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        if !self.uuid {
+            fail!("FixedSizeBinaryBuilder only accepts strings with the UuidStrAsFixedSizeBinary strategy");
+        }
+        self.serialize_bytes(&parse_uuid(v)?)
+    }
+
+    fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(value)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        self.end()
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(value)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        self.end()
+    }
+}
+
+/// Parse a UUID in the hyphenated (`67e55044-10b1-426f-9247-bb680e5fe0c8`) or
+/// the simple (`67e5504410b1426f9247bb680e5fe0c8`) format
+fn parse_uuid(s: &str) -> Result<[u8; 16]> {
+    let digits = match s.len() {
+        32 => s.to_string(),
+        36 if [8, 13, 18, 23].iter().all(|&idx| s.as_bytes()[idx] == b'-') => s.replace('-', ""),
+        _ => fail!("Invalid UUID {s:?}"),
+    };
+
+    let mut res = [0_u8; 16];
+    for (idx, byte) in res.iter_mut().enumerate() {
+        let Some(Ok(value)) = digits
+            .get(2 * idx..2 * idx + 2)
+            .map(|hex| u8::from_str_radix(hex, 16))
+        else {
+            fail!("Invalid UUID {s:?}");
+        };
+        *byte = value;
+    }
+    Ok(res)
+}
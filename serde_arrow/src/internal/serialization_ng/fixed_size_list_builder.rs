@@ -0,0 +1,148 @@
+use serde::Serialize;
+
+use crate::{
+    internal::{common::MutableBitBuffer, error::fail, schema::GenericField},
+    Result,
+};
+
+use super::{
+    array_builder::ArrayBuilder,
+    utils::{push_validity, push_validity_default, validity_num_bytes, Mut, SimpleSerializer},
+};
+
+/// Build fixed size list arrays
+///
+/// Every list must contain exactly `n` elements. For missing lists `n`
+/// default elements are added to the child array.
+#[derive(Debug, Clone)]
+pub struct FixedSizeListBuilder {
+    pub field: GenericField,
+    pub validity: Option<MutableBitBuffer>,
+    pub n: usize,
+    pub len: usize,
+    pub element: Box<ArrayBuilder>,
+    current_n: usize,
+}
+
+impl FixedSizeListBuilder {
+    pub fn new(field: GenericField, element: ArrayBuilder, n: usize, is_nullable: bool) -> Self {
+        Self {
+            field,
+            validity: is_nullable.then(MutableBitBuffer::default),
+            n,
+            len: 0,
+            element: Box::new(element),
+            current_n: 0,
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            field: self.field.clone(),
+            validity: self.validity.as_mut().map(std::mem::take),
+            n: self.n,
+            len: std::mem::take(&mut self.len),
+            element: Box::new(self.element.take()),
+            current_n: 0,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.element.num_bytes()
+    }
+}
+
+impl FixedSizeListBuilder {
+    fn start(&mut self) -> Result<()> {
+        self.current_n = 0;
+        push_validity(&mut self.validity, true)
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        if self.current_n >= self.n {
+            fail!(
+                "Invalid number of elements for FixedSizeList({}), found more than {}",
+                self.n,
+                self.n
+            );
+        }
+        self.current_n += 1;
+        value.serialize(Mut(self.element.as_mut()))
+    }
+
+    fn end(&mut self) -> Result<()> {
+        if self.current_n != self.n {
+            fail!(
+                "Invalid number of elements for FixedSizeList({}), found {}",
+                self.n,
+                self.current_n
+            );
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    fn push_default_elements(&mut self) -> Result<()> {
+        for _ in 0..self.n {
+            self.element.serialize_default()?;
+        }
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl SimpleSerializer for FixedSizeListBuilder {
+    fn name(&self) -> &str {
+        "FixedSizeListBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.push_default_elements()
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.push_default_elements()
+    }
+
+    fn serialize_seq_start(&mut self, _: Option<usize>) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_seq_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(value)
+    }
+
+    fn serialize_seq_end(&mut self) -> Result<()> {
+        self.end()
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(value)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        self.end()
+    }
+
+    fn serialize_tuple_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_tuple_struct_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(value)
+    }
+
+    fn serialize_tuple_struct_end(&mut self) -> Result<()> {
+        self.end()
+    }
+}
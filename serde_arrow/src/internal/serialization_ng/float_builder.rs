@@ -2,7 +2,9 @@ use half::f16;
 
 use crate::{internal::common::MutableBitBuffer, Result};
 
-use super::utils::{push_validity, push_validity_default, Mut, SimpleSerializer};
+use super::utils::{
+    push_validity, push_validity_default, validity_num_bytes, Mut, SimpleSerializer,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct FloatBuilder<I> {
@@ -29,6 +31,10 @@ impl<I> FloatBuilder<I> {
         self.validity.is_some()
     }
 
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
+
     fn serialize_value(&mut self, value: I) -> Result<()> {
         push_validity(&mut self.validity, true)?;
         self.buffer.push(value);
@@ -1,6 +1,6 @@
 use crate::{internal::common::MutableBitBuffer, Error, Result};
 
-use super::utils::{push_validity, push_validity_default, SimpleSerializer};
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
 
 #[derive(Debug, Clone, Default)]
 pub struct IntBuilder<I> {
@@ -26,6 +26,10 @@ impl<I> IntBuilder<I> {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
 }
 
 impl<I> SimpleSerializer for IntBuilder<I>
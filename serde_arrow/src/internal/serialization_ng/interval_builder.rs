@@ -0,0 +1,256 @@
+use serde::Serialize;
+
+use crate::{
+    internal::{common::MutableBitBuffer, error::fail, schema::GenericIntervalUnit},
+    Result,
+};
+
+use super::utils::{
+    push_validity, push_validity_default, validity_num_bytes, ComponentSerializer, Mut,
+    SimpleSerializer,
+};
+
+/// Build interval arrays
+///
+/// The expected Rust-side shape depends on the unit:
+///
+/// - `YearMonth`: an integer with the number of months
+/// - `DayTime`: a struct with the fields `days` and `millis` or a tuple
+///   `(days, millis)`
+/// - `MonthDayNano`: a struct with the fields `months`, `days` and `nanos` or
+///   a tuple `(months, days, nanos)`
+///
+/// The components are stored separately and packed into the native layout of
+/// the respective arrow implementation, when building the array.
+#[derive(Debug, Clone)]
+pub struct IntervalBuilder {
+    pub unit: GenericIntervalUnit,
+    pub validity: Option<MutableBitBuffer>,
+    pub months: Vec<i32>,
+    pub days: Vec<i32>,
+    /// The milliseconds for `DayTime` and nanoseconds for `MonthDayNano`
+    pub time: Vec<i64>,
+    components: [Option<i64>; 3],
+    next: usize,
+}
+
+impl IntervalBuilder {
+    pub fn new(unit: GenericIntervalUnit, nullable: bool) -> Self {
+        Self {
+            unit,
+            validity: nullable.then(MutableBitBuffer::default),
+            months: Vec::new(),
+            days: Vec::new(),
+            time: Vec::new(),
+            components: [None; 3],
+            next: 0,
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            unit: self.unit.clone(),
+            validity: self.validity.as_mut().map(std::mem::take),
+            months: std::mem::take(&mut self.months),
+            days: std::mem::take(&mut self.days),
+            time: std::mem::take(&mut self.time),
+            components: [None; 3],
+            next: 0,
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity)
+            + std::mem::size_of_val(self.months.as_slice())
+            + std::mem::size_of_val(self.days.as_slice())
+            + std::mem::size_of_val(self.time.as_slice())
+    }
+}
+
+impl IntervalBuilder {
+    fn component_names(&self) -> &'static [&'static str] {
+        match self.unit {
+            GenericIntervalUnit::YearMonth => &["months"],
+            GenericIntervalUnit::DayTime => &["days", "millis"],
+            GenericIntervalUnit::MonthDayNano => &["months", "days", "nanos"],
+        }
+    }
+
+    fn push_months<V: TryInto<i32>>(&mut self, v: V) -> Result<()> {
+        if !matches!(self.unit, GenericIntervalUnit::YearMonth) {
+            fail!(
+                "Interval({}) values must be structs or tuples, not integers",
+                self.unit
+            );
+        }
+        let Ok(v) = v.try_into() else {
+            fail!("Interval months are out of range for i32");
+        };
+        push_validity(&mut self.validity, true)?;
+        self.months.push(v);
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<()> {
+        if matches!(self.unit, GenericIntervalUnit::YearMonth) {
+            fail!("Interval(YearMonth) values must be integers");
+        }
+        self.components = [None; 3];
+        self.next = 0;
+        Ok(())
+    }
+
+    fn element<V: Serialize + ?Sized>(&mut self, idx: usize, value: &V) -> Result<()> {
+        let names = self.component_names();
+        if idx >= names.len() {
+            fail!(
+                "Interval({}) values must have exactly {} components ({})",
+                self.unit,
+                names.len(),
+                names.join(", "),
+            );
+        }
+
+        let mut component = ComponentSerializer(None);
+        value.serialize(Mut(&mut component))?;
+
+        self.components[idx] = component.0;
+        self.next = idx + 1;
+        Ok(())
+    }
+
+    fn end(&mut self) -> Result<()> {
+        let names = self.component_names();
+        let components = std::mem::take(&mut self.components);
+        let mut values = [0_i64; 3];
+        for (idx, name) in names.iter().enumerate() {
+            let Some(value) = components[idx] else {
+                fail!(
+                    "Interval({}) value is missing the {name} component",
+                    self.unit
+                );
+            };
+            values[idx] = value;
+        }
+
+        let (months, days, time) = match self.unit {
+            GenericIntervalUnit::DayTime => {
+                let Ok(millis) = i32::try_from(values[1]) else {
+                    fail!("Interval milliseconds are out of range for i32");
+                };
+                (0, values[0], i64::from(millis))
+            }
+            _ => (values[0], values[1], values[2]),
+        };
+        let (Ok(months), Ok(days)) = (i32::try_from(months), i32::try_from(days)) else {
+            fail!("Interval months or days are out of range for i32");
+        };
+
+        push_validity(&mut self.validity, true)?;
+        self.months.push(months);
+        self.days.push(days);
+        self.time.push(time);
+        Ok(())
+    }
+
+    fn push_null(&mut self) {
+        match self.unit {
+            GenericIntervalUnit::YearMonth => self.months.push(0),
+            GenericIntervalUnit::DayTime | GenericIntervalUnit::MonthDayNano => {
+                self.months.push(0);
+                self.days.push(0);
+                self.time.push(0);
+            }
+        }
+    }
+}
+
+impl SimpleSerializer for IntervalBuilder {
+    fn name(&self) -> &str {
+        "IntervalBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.push_null();
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.push_null();
+        Ok(())
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.push_months(v)
+    }
+
+    fn serialize_struct_start(&mut self, _: &'static str, _: usize) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_struct_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        let names = self.component_names();
+        let Some(idx) = names.iter().position(|name| *name == key) else {
+            fail!(
+                "Unknown field {key:?} for Interval({}), expected one of {}",
+                self.unit,
+                names.join(", "),
+            );
+        };
+        self.element(idx, value)
+    }
+
+    fn serialize_struct_end(&mut self) -> Result<()> {
+        self.end()
+    }
+
+    fn serialize_tuple_start(&mut self, _: usize) -> Result<()> {
+        self.start()
+    }
+
+    fn serialize_tuple_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        self.element(self.next, value)
+    }
+
+    fn serialize_tuple_end(&mut self) -> Result<()> {
+        self.end()
+    }
+}
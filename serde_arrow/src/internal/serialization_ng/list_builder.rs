@@ -10,7 +10,7 @@ use crate::{
 
 use super::{
     array_builder::ArrayBuilder,
-    utils::{push_validity, push_validity_default, Mut, SimpleSerializer},
+    utils::{push_validity, push_validity_default, validity_num_bytes, Mut, SimpleSerializer},
 };
 
 #[derive(Debug, Clone)]
@@ -44,6 +44,10 @@ impl<O: Offset> ListBuilder<O> {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.offsets.num_bytes() + self.element.num_bytes()
+    }
 }
 
 impl<O: Offset> ListBuilder<O> {
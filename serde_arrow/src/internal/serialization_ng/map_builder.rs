@@ -3,6 +3,7 @@ use serde::Serialize;
 use crate::{
     internal::{
         common::{MutableBitBuffer, MutableOffsetBuffer},
+        error::fail,
         schema::GenericField,
     },
     Result,
@@ -10,24 +11,41 @@ use crate::{
 
 use super::{
     array_builder::ArrayBuilder,
-    utils::{push_validity, push_validity_default, SimpleSerializer},
+    content::Content,
+    utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer},
 };
 
+/// Build map arrays
+///
+/// For sorted maps, the entries of each map are buffered and appended in the
+/// order of their keys. Duplicate keys result in an error.
 #[derive(Debug, Clone)]
 pub struct MapBuilder {
     pub entry_field: GenericField,
     pub validity: Option<MutableBitBuffer>,
     pub offsets: MutableOffsetBuffer<i32>,
     pub entry: Box<ArrayBuilder>,
+    pub sorted: bool,
+    /// The buffered entries of the current map, only used for sorted maps
+    entries: Vec<(SortKey, Content, Content)>,
+    key: Option<(SortKey, Content)>,
 }
 
 impl MapBuilder {
-    pub fn new(entry_field: GenericField, entry: ArrayBuilder, is_nullable: bool) -> Self {
+    pub fn new(
+        entry_field: GenericField,
+        entry: ArrayBuilder,
+        is_nullable: bool,
+        sorted: bool,
+    ) -> Self {
         Self {
             entry_field,
             validity: is_nullable.then(MutableBitBuffer::default),
             offsets: MutableOffsetBuffer::default(),
             entry: Box::new(entry),
+            sorted,
+            entries: Vec::new(),
+            key: None,
         }
     }
 
@@ -37,12 +55,65 @@ impl MapBuilder {
             validity: self.validity.as_mut().map(std::mem::take),
             offsets: std::mem::take(&mut self.offsets),
             entry: Box::new(self.entry.take()),
+            sorted: self.sorted,
+            entries: Vec::new(),
+            key: None,
         }
     }
 
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.offsets.num_bytes() + self.entry.num_bytes()
+    }
+}
+
+impl MapBuilder {
+    fn push_entry<K: Serialize + ?Sized, V: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<()> {
+        self.offsets.inc_current_items()?;
+        self.entry.serialize_tuple_start(2)?;
+        self.entry.serialize_tuple_element(key)?;
+        self.entry.serialize_tuple_element(value)?;
+        self.entry.serialize_tuple_end()
+    }
+
+    fn push_sorted_entries(&mut self) -> Result<()> {
+        let mut entries = std::mem::take(&mut self.entries);
+
+        let mut incomparable = false;
+        entries.sort_by(|(a, _, _), (b, _, _)| {
+            a.partial_cmp(b).unwrap_or_else(|| {
+                incomparable = true;
+                std::cmp::Ordering::Equal
+            })
+        });
+        if incomparable {
+            fail!("The keys of sorted maps must be comparable");
+        }
+        for pair in entries.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                fail!(
+                    "The keys of sorted maps must be unique, found {:?} twice",
+                    pair[0].0
+                );
+            }
+        }
+
+        for (_, key, value) in &entries {
+            self.push_entry(key, value)?;
+        }
+
+        // reuse the allocation for the next map
+        entries.clear();
+        self.entries = entries;
+        Ok(())
+    }
 }
 
 impl SimpleSerializer for MapBuilder {
@@ -62,23 +133,77 @@ impl SimpleSerializer for MapBuilder {
     }
 
     fn serialize_map_start(&mut self, _: Option<usize>) -> Result<()> {
+        self.entries.clear();
+        self.key = None;
         push_validity(&mut self.validity, true)?;
         Ok(())
     }
 
     fn serialize_map_key<V: Serialize + ?Sized>(&mut self, key: &V) -> Result<()> {
+        if self.sorted {
+            let key = Content::new(key)?;
+            self.key = Some((SortKey::new(&key)?, key));
+            return Ok(());
+        }
         self.offsets.inc_current_items()?;
         self.entry.serialize_tuple_start(2)?;
         self.entry.serialize_tuple_element(key)
     }
 
     fn serialize_map_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        if self.sorted {
+            let Some((sort_key, key)) = self.key.take() else {
+                fail!("serialize_map_value called without a key");
+            };
+            self.entries.push((sort_key, key, Content::new(value)?));
+            return Ok(());
+        }
         self.entry.serialize_tuple_element(value)?;
         self.entry.serialize_tuple_end()
     }
 
     fn serialize_map_end(&mut self) -> Result<()> {
+        if self.sorted {
+            self.push_sorted_entries()?;
+        }
         self.offsets.push_current_items();
         Ok(())
     }
 }
+
+/// A comparable representation of map keys
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+enum SortKey {
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Bytes(Vec<u8>),
+}
+
+impl SortKey {
+    fn new(key: &Content) -> Result<Self> {
+        Ok(match key {
+            Content::Bool(v) => Self::Bool(*v),
+            Content::I8(v) => Self::Int((*v).into()),
+            Content::I16(v) => Self::Int((*v).into()),
+            Content::I32(v) => Self::Int((*v).into()),
+            Content::I64(v) => Self::Int((*v).into()),
+            Content::I128(v) => Self::Int(*v),
+            Content::U8(v) => Self::Int((*v).into()),
+            Content::U16(v) => Self::Int((*v).into()),
+            Content::U32(v) => Self::Int((*v).into()),
+            Content::U64(v) => Self::Int((*v).into()),
+            Content::U128(v) => Self::Int((*v).try_into()?),
+            Content::F32(v) => Self::Float((*v).into()),
+            Content::F64(v) => Self::Float(*v),
+            Content::Char(v) => Self::Str(v.to_string()),
+            Content::Str(v) => Self::Str(v.clone()),
+            Content::Bytes(v) => Self::Bytes(v.clone()),
+            // enums are ordered by their variant
+            Content::UnitVariant(_, variant_index, _) => Self::Int((*variant_index).into()),
+            Content::Some(v) | Content::NewtypeStruct(_, v) => Self::new(v)?,
+            _ => fail!("Sorted maps require keys of primitive type"),
+        })
+    }
+}
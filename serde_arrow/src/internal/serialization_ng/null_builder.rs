@@ -21,6 +21,10 @@ impl NullBuilder {
     pub fn is_nullable(&self) -> bool {
         true
     }
+
+    pub fn num_bytes(&self) -> usize {
+        0
+    }
 }
 
 impl SimpleSerializer for NullBuilder {
@@ -0,0 +1,181 @@
+use crate::internal::{error::Result, schema::GenericField};
+
+use super::{array_builder::ArrayBuilder, utils::SimpleSerializer};
+
+/// Build run-end encoded arrays
+///
+/// Consecutive equal values are collapsed into a single run: the value is
+/// added only once to the values builder and the end of the run is extended.
+/// As for dictionaries, values are compared by their byte representation.
+/// Values pushed via `serialize_default` always start a new run.
+#[derive(Debug, Clone)]
+pub struct RunEndEncodedBuilder {
+    pub field: GenericField,
+    pub run_ends: Vec<i64>,
+    pub values: Box<ArrayBuilder>,
+    last_value: Option<RunValue>,
+}
+
+/// The value of the current run
+#[derive(Debug, Clone, PartialEq)]
+enum RunValue {
+    Null,
+    Value(Vec<u8>),
+}
+
+impl RunEndEncodedBuilder {
+    pub fn new(field: GenericField, values: ArrayBuilder) -> Self {
+        Self {
+            field,
+            run_ends: Vec::new(),
+            values: Box::new(values),
+            last_value: None,
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            field: self.field.clone(),
+            run_ends: std::mem::take(&mut self.run_ends),
+            values: Box::new(self.values.take()),
+            last_value: self.last_value.take(),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.values.is_nullable()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        std::mem::size_of_val(self.run_ends.as_slice()) + self.values.num_bytes()
+    }
+}
+
+impl RunEndEncodedBuilder {
+    /// Extend the current run if the value is unchanged, start a new run otherwise
+    fn push_entry<F>(&mut self, entry: Option<&[u8]>, push_value: F) -> Result<()>
+    where
+        F: FnOnce(&mut ArrayBuilder) -> Result<()>,
+    {
+        let is_current_run = match (&self.last_value, entry) {
+            (Some(RunValue::Null), None) => true,
+            (Some(RunValue::Value(last)), Some(entry)) => last.as_slice() == entry,
+            _ => false,
+        };
+
+        if is_current_run {
+            if let Some(run_end) = self.run_ends.last_mut() {
+                *run_end += 1;
+                return Ok(());
+            }
+        }
+
+        push_value(self.values.as_mut())?;
+        let run_end = self.run_ends.last().copied().unwrap_or_default() + 1;
+        self.run_ends.push(run_end);
+        self.last_value = Some(match entry {
+            Some(entry) => RunValue::Value(entry.to_vec()),
+            None => RunValue::Null,
+        });
+        Ok(())
+    }
+}
+
+impl SimpleSerializer for RunEndEncodedBuilder {
+    fn name(&self) -> &str {
+        "RunEndEncoded"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        self.values.serialize_default()?;
+        let run_end = self.run_ends.last().copied().unwrap_or_default() + 1;
+        self.run_ends.push(run_end);
+        self.last_value = None;
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        self.push_entry(None, |b| b.serialize_none())
+    }
+
+    fn serialize_unit(&mut self) -> Result<()> {
+        self.push_entry(None, |b| b.serialize_unit())
+    }
+
+    fn serialize_bool(&mut self, v: bool) -> Result<()> {
+        self.push_entry(Some(&[u8::from(v)]), |b| b.serialize_bool(v))
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_i8(v))
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_i16(v))
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_i32(v))
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_i64(v))
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_u8(v))
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_u16(v))
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_u32(v))
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.push_entry(Some(&i128::from(v).to_le_bytes()), |b| b.serialize_u64(v))
+    }
+
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        self.push_entry(Some(&v.to_le_bytes()), |b| b.serialize_i128(v))
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        self.push_entry(Some(&v.to_le_bytes()), |b| b.serialize_u128(v))
+    }
+
+    fn serialize_f32(&mut self, v: f32) -> Result<()> {
+        self.push_entry(Some(&v.to_bits().to_le_bytes()), |b| b.serialize_f32(v))
+    }
+
+    fn serialize_f64(&mut self, v: f64) -> Result<()> {
+        self.push_entry(Some(&v.to_bits().to_le_bytes()), |b| b.serialize_f64(v))
+    }
+
+    fn serialize_char(&mut self, v: char) -> Result<()> {
+        let mut buffer = [0; 4];
+        let entry = v.encode_utf8(&mut buffer).as_bytes();
+        self.push_entry(Some(entry), |b| b.serialize_char(v))
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        self.push_entry(Some(v.as_bytes()), |b| b.serialize_str(v))
+    }
+
+    fn serialize_bytes(&mut self, v: &[u8]) -> Result<()> {
+        self.push_entry(Some(v), |b| b.serialize_bytes(v))
+    }
+
+    fn serialize_unit_variant(
+        &mut self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.push_entry(Some(variant.as_bytes()), |b| {
+            b.serialize_unit_variant(name, variant_index, variant)
+        })
+    }
+}
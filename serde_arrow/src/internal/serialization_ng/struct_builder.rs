@@ -9,7 +9,9 @@ use crate::{
 
 use super::{
     array_builder::ArrayBuilder,
-    utils::{push_validity, push_validity_default, take_swap, Mut, SimpleSerializer},
+    utils::{
+        push_validity, push_validity_default, take_swap, validity_num_bytes, Mut, SimpleSerializer,
+    },
 };
 
 const UNKNOWN_KEY: usize = usize::MAX;
@@ -84,6 +86,15 @@ impl StructBuilder {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity)
+            + self
+                .named_fields
+                .iter()
+                .map(|(_, builder)| builder.num_bytes())
+                .sum::<usize>()
+    }
 }
 
 impl StructBuilder {
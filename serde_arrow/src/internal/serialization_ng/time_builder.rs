@@ -0,0 +1,99 @@
+use chrono::{NaiveTime, Timelike};
+
+use crate::{
+    internal::{
+        common::MutableBitBuffer,
+        schema::{GenericField, GenericTimeUnit},
+    },
+    Error, Result,
+};
+
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
+
+#[derive(Debug, Clone)]
+pub struct TimeBuilder<I> {
+    pub field: GenericField,
+    pub unit: GenericTimeUnit,
+    pub validity: Option<MutableBitBuffer>,
+    pub buffer: Vec<I>,
+}
+
+impl<I> TimeBuilder<I> {
+    pub fn new(field: GenericField, unit: GenericTimeUnit, nullable: bool) -> Self {
+        Self {
+            field,
+            unit,
+            validity: nullable.then(MutableBitBuffer::default),
+            buffer: Vec::new(),
+        }
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            field: self.field.clone(),
+            unit: self.unit.clone(),
+            validity: self.validity.as_mut().map(std::mem::take),
+            buffer: std::mem::take(&mut self.buffer),
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + std::mem::size_of_val(self.buffer.as_slice())
+    }
+}
+
+impl<I> SimpleSerializer for TimeBuilder<I>
+where
+    I: Default + TryFrom<i32> + TryFrom<i64>,
+    Error: From<<I as TryFrom<i32>>::Error>,
+    Error: From<<I as TryFrom<i64>>::Error>,
+{
+    fn name(&self) -> &str {
+        "TimeBuilder"
+    }
+
+    fn serialize_default(&mut self) -> Result<()> {
+        push_validity_default(&mut self.validity);
+        self.buffer.push(I::default());
+        Ok(())
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        push_validity(&mut self.validity, false)?;
+        self.buffer.push(I::default());
+        Ok(())
+    }
+
+    fn serialize_str(&mut self, v: &str) -> Result<()> {
+        let time = v.parse::<NaiveTime>()?;
+        let seconds = i64::from(time.num_seconds_from_midnight());
+        let nanoseconds = i64::from(time.nanosecond());
+
+        let value = match self.unit {
+            GenericTimeUnit::Second => seconds,
+            GenericTimeUnit::Millisecond => seconds * 1_000 + nanoseconds / 1_000_000,
+            GenericTimeUnit::Microsecond => seconds * 1_000_000 + nanoseconds / 1_000,
+            GenericTimeUnit::Nanosecond => seconds * 1_000_000_000 + nanoseconds,
+        };
+
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(I::try_from(value)?);
+        Ok(())
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(I::try_from(v)?);
+        Ok(())
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        push_validity(&mut self.validity, true)?;
+        self.buffer.push(I::try_from(v)?);
+        Ok(())
+    }
+}
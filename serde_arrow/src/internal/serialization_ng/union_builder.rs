@@ -1,5 +1,8 @@
 use crate::{
-    internal::{error::fail, schema::GenericField},
+    internal::{
+        error::fail,
+        schema::{GenericField, GenericUnionMode},
+    },
     Result,
 };
 
@@ -8,9 +11,18 @@ use super::{
     ArrayBuilder,
 };
 
+/// Build union arrays
+///
+/// In sparse mode, a default value is added to all children except the one of
+/// the serialized variant and no offsets are recorded. Missing values of
+/// nullable unions are written to the trailing null variant. The variant index
+/// is mapped to the type id of the corresponding child.
 #[derive(Debug, Clone)]
 pub struct UnionBuilder {
     pub field: GenericField,
+    pub mode: GenericUnionMode,
+    pub null_variant: Option<usize>,
+    pub type_ids: Vec<i8>,
     pub fields: Vec<ArrayBuilder>,
     pub types: Vec<i8>,
     pub offsets: Vec<i32>,
@@ -18,9 +30,16 @@ pub struct UnionBuilder {
 }
 
 impl UnionBuilder {
-    pub fn new(field: GenericField, fields: Vec<ArrayBuilder>) -> Result<Self> {
+    pub fn new(
+        field: GenericField,
+        fields: Vec<ArrayBuilder>,
+        mode: GenericUnionMode,
+    ) -> Result<Self> {
         Ok(Self {
+            null_variant: field.get_union_null_variant(),
+            type_ids: field.get_union_type_ids()?,
             field,
+            mode,
             current_offset: vec![0; fields.len()],
             types: Vec::new(),
             offsets: Vec::new(),
@@ -31,6 +50,9 @@ impl UnionBuilder {
     pub fn take(&mut self) -> Self {
         Self {
             field: self.field.clone(),
+            mode: self.mode,
+            null_variant: self.null_variant,
+            type_ids: self.type_ids.clone(),
             fields: self.fields.iter_mut().map(|field| field.take()).collect(),
             types: std::mem::take(&mut self.types),
             offsets: std::mem::take(&mut self.offsets),
@@ -39,22 +61,51 @@ impl UnionBuilder {
     }
 
     pub fn is_nullable(&self) -> bool {
-        false
+        self.null_variant.is_some()
+    }
+
+    pub fn num_bytes(&self) -> usize {
+        std::mem::size_of_val(self.types.as_slice())
+            + std::mem::size_of_val(self.offsets.as_slice())
+            + self
+                .fields
+                .iter()
+                .map(ArrayBuilder::num_bytes)
+                .sum::<usize>()
     }
 }
 
 impl UnionBuilder {
     pub fn serialize_variant(&mut self, variant_index: u32) -> Result<&mut ArrayBuilder> {
         let variant_index = variant_index as usize;
-        let Some(variant_builder) = self.fields.get_mut(variant_index) else {
+        if variant_index >= self.fields.len() || Some(variant_index) == self.null_variant {
             fail!("Unknown variant {variant_index}");
-        };
+        }
+        self.push_type(variant_index)
+    }
 
-        self.offsets.push(self.current_offset[variant_index]);
-        self.types.push(i8::try_from(variant_index)?);
-        self.current_offset[variant_index] += 1;
+    fn push_type(&mut self, variant_index: usize) -> Result<&mut ArrayBuilder> {
+        self.types.push(self.type_ids[variant_index]);
+        match self.mode {
+            GenericUnionMode::Dense => {
+                self.offsets.push(self.current_offset[variant_index]);
+                self.current_offset[variant_index] += 1;
+            }
+            GenericUnionMode::Sparse => {
+                for (idx, builder) in self.fields.iter_mut().enumerate() {
+                    if idx == variant_index {
+                        continue;
+                    }
+                    match builder {
+                        // unknown variants cannot hold values, only count the slots
+                        ArrayBuilder::UnknownVariant(builder) => builder.count += 1,
+                        builder => builder.serialize_default()?,
+                    }
+                }
+            }
+        }
 
-        Ok(variant_builder)
+        Ok(&mut self.fields[variant_index])
     }
 }
 
@@ -63,6 +114,25 @@ impl SimpleSerializer for UnionBuilder {
         "UnionBuilder"
     }
 
+    fn serialize_default(&mut self) -> Result<()> {
+        if let Some(null_variant) = self.null_variant {
+            self.push_type(null_variant)?.serialize_default()
+        } else {
+            // use the default of the first variant
+            self.serialize_variant(0)?.serialize_default()
+        }
+    }
+
+    fn serialize_none(&mut self) -> Result<()> {
+        let Some(null_variant) = self.null_variant else {
+            fail!(
+                "Cannot serialize None for union {} without null variant",
+                self.field.name
+            );
+        };
+        self.push_type(null_variant)?.serialize_none()
+    }
+
     fn serialize_unit_variant(
         &mut self,
         _: &'static str,
@@ -4,17 +4,29 @@ use crate::{internal::error::fail, Result};
 
 use super::{utils::SimpleSerializer, ArrayBuilder};
 
-#[derive(Debug, Clone)]
-pub struct UnknownVariantBuilder;
+/// A placeholder for variants not encountered during tracing
+///
+/// Any value results in an error. `count` tracks the number of placeholder
+/// slots added by sparse unions for the other variants.
+#[derive(Debug, Clone, Default)]
+pub struct UnknownVariantBuilder {
+    pub count: usize,
+}
 
 impl UnknownVariantBuilder {
     pub fn take(&mut self) -> Self {
-        UnknownVariantBuilder
+        Self {
+            count: std::mem::take(&mut self.count),
+        }
     }
 
     pub fn is_nullable(&self) -> bool {
         false
     }
+
+    pub fn num_bytes(&self) -> usize {
+        0
+    }
 }
 
 impl SimpleSerializer for UnknownVariantBuilder {
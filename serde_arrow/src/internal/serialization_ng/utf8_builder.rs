@@ -3,7 +3,7 @@ use crate::{
     Result,
 };
 
-use super::utils::{push_validity, push_validity_default, SimpleSerializer};
+use super::utils::{push_validity, push_validity_default, validity_num_bytes, SimpleSerializer};
 
 #[derive(Debug, Clone)]
 pub struct Utf8Builder<O> {
@@ -32,6 +32,10 @@ impl<O: Offset> Utf8Builder<O> {
     pub fn is_nullable(&self) -> bool {
         self.validity.is_some()
     }
+
+    pub fn num_bytes(&self) -> usize {
+        validity_num_bytes(&self.validity) + self.offsets.num_bytes() + self.buffer.len()
+    }
 }
 
 impl<O: Offset> SimpleSerializer for Utf8Builder<O> {
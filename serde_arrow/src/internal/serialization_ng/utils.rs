@@ -36,6 +36,13 @@ pub fn push_validity_default(buffer: &mut Option<MutableBitBuffer>) {
     }
 }
 
+pub fn validity_num_bytes(buffer: &Option<MutableBitBuffer>) -> usize {
+    buffer
+        .as_ref()
+        .map(MutableBitBuffer::num_bytes)
+        .unwrap_or_default()
+}
+
 /// A simplified serialization trait with default implementations raising an
 /// error
 ///
@@ -104,6 +111,14 @@ pub trait SimpleSerializer: Sized {
         fail!("serialize_i64 is not implemented for {}", self.name())
     }
 
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        fail!("serialize_i128 is not implemented for {}", self.name())
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        fail!("serialize_u128 is not implemented for {}", self.name())
+    }
+
     fn serialize_f32(&mut self, v: f32) -> Result<()> {
         fail!("serialize_f32 is not implemented for {}", self.name())
     }
@@ -348,6 +363,14 @@ impl<'a, T: SimpleSerializer> Serializer for Mut<'a, T> {
         self.0.serialize_i64(v)
     }
 
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.0.serialize_i128(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.0.serialize_u128(v)
+    }
+
     fn serialize_f32(self, v: f32) -> Result<()> {
         self.0.serialize_f32(v)
     }
@@ -554,3 +577,63 @@ impl<'a, T: SimpleSerializer> SerializeTupleVariant for Mut<'a, T> {
         self.0.serialize_tuple_struct_end()
     }
 }
+
+/// Capture a single integer component of a composite value, e.g., the `secs`
+/// of a `{secs, nanos}` duration
+pub struct ComponentSerializer(pub Option<i64>);
+
+impl ComponentSerializer {
+    fn set<V: TryInto<i64>>(&mut self, v: V) -> Result<()> {
+        let Ok(v) = v.try_into() else {
+            fail!("Component is out of range for i64");
+        };
+        self.0 = Some(v);
+        Ok(())
+    }
+}
+
+impl SimpleSerializer for ComponentSerializer {
+    fn name(&self) -> &str {
+        "ComponentSerializer"
+    }
+
+    fn serialize_i8(&mut self, v: i8) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_i16(&mut self, v: i16) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_i32(&mut self, v: i32) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_i64(&mut self, v: i64) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_u8(&mut self, v: u8) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_u16(&mut self, v: u16) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_u32(&mut self, v: u32) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_u64(&mut self, v: u64) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_i128(&mut self, v: i128) -> Result<()> {
+        self.set(v)
+    }
+
+    fn serialize_u128(&mut self, v: u128) -> Result<()> {
+        self.set(v)
+    }
+}
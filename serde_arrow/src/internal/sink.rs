@@ -91,16 +91,19 @@ pub trait EventSink {
     fn accept_null(&mut self) -> Result<()>;
     fn accept_default(&mut self) -> Result<()>;
     fn accept_str(&mut self, val: &str) -> Result<()>;
+    fn accept_bytes(&mut self, val: &[u8]) -> Result<()>;
     fn accept_variant(&mut self, name: &str, idx: usize) -> Result<()>;
     fn accept_bool(&mut self, val: bool) -> Result<()>;
     fn accept_i8(&mut self, val: i8) -> Result<()>;
     fn accept_i16(&mut self, val: i16) -> Result<()>;
     fn accept_i32(&mut self, val: i32) -> Result<()>;
     fn accept_i64(&mut self, val: i64) -> Result<()>;
+    fn accept_i128(&mut self, val: i128) -> Result<()>;
     fn accept_u8(&mut self, val: u8) -> Result<()>;
     fn accept_u16(&mut self, val: u16) -> Result<()>;
     fn accept_u32(&mut self, val: u32) -> Result<()>;
     fn accept_u64(&mut self, val: u64) -> Result<()>;
+    fn accept_u128(&mut self, val: u128) -> Result<()>;
     fn accept_f32(&mut self, val: f32) -> Result<()>;
     fn accept_f64(&mut self, val: f64) -> Result<()>;
     fn accept(&mut self, event: Event<'_>) -> Result<()>;
@@ -154,6 +157,10 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
         self.0.accept_i64(val)
     }
 
+    fn serialize_i128(self, val: i128) -> Result<()> {
+        self.0.accept_i128(val)
+    }
+
     fn serialize_u8(self, val: u8) -> Result<()> {
         self.0.accept_u8(val)
     }
@@ -170,6 +177,10 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
         self.0.accept_u64(val)
     }
 
+    fn serialize_u128(self, val: u128) -> Result<()> {
+        self.0.accept_u128(val)
+    }
+
     fn serialize_f32(self, val: f32) -> Result<()> {
         self.0.accept_f32(val)
     }
@@ -187,13 +198,7 @@ impl<'a, S: EventSink> Serializer for EventSerializer<'a, S> {
     }
 
     fn serialize_bytes(self, val: &[u8]) -> Result<()> {
-        self.0.accept_start_sequence()?;
-        for &b in val {
-            self.0.accept_item()?;
-            self.0.accept_u8(b)?;
-        }
-        self.0.accept_end_sequence()?;
-        Ok(())
+        self.0.accept_bytes(val)
     }
 
     fn serialize_none(self) -> Result<()> {
@@ -33,13 +33,16 @@
 //!   - [accept_u16][super::EventSink::accept_u16]
 //!   - [accept_u32][super::EventSink::accept_u32]
 //!   - [accept_u64][super::EventSink::accept_u64]
+//!   - [accept_u128][super::EventSink::accept_u128]
 //!   - [accept_i8][super::EventSink::accept_i8]
 //!   - [accept_i16][super::EventSink::accept_i16]
 //!   - [accept_i32][super::EventSink::accept_i32]
 //!   - [accept_i64][super::EventSink::accept_i64]
+//!   - [accept_i128][super::EventSink::accept_i128]
 //!   - [accept_f32][super::EventSink::accept_f32]
 //!   - [accept_f64][super::EventSink::accept_f64]
 //!   - [accept_str][super::EventSink::accept_str]
+//!   - [accept_bytes][super::EventSink::accept_bytes]
 //!  
 //! The `accept_*` macros must be called as in:
 //!
@@ -114,6 +117,10 @@ macro_rules! forward_specialized_to_generic {
             self.accept($crate::internal::event::Event::Str(val))
         }
 
+        fn accept_bytes(&mut self, val: &[u8]) -> $crate::internal::error::Result<()> {
+            self.accept($crate::internal::event::Event::Bytes(val))
+        }
+
         fn accept_variant(
             &mut self,
             name: &str,
@@ -142,6 +149,10 @@ macro_rules! forward_specialized_to_generic {
             self.accept($crate::internal::event::Event::I64(val))
         }
 
+        fn accept_i128(&mut self, val: i128) -> $crate::internal::error::Result<()> {
+            self.accept($crate::internal::event::Event::I128(val))
+        }
+
         fn accept_u8(&mut self, val: u8) -> $crate::internal::error::Result<()> {
             self.accept($crate::internal::event::Event::U8(val))
         }
@@ -158,6 +169,10 @@ macro_rules! forward_specialized_to_generic {
             self.accept($crate::internal::event::Event::U64(val))
         }
 
+        fn accept_u128(&mut self, val: u128) -> $crate::internal::error::Result<()> {
+            self.accept($crate::internal::event::Event::U128(val))
+        }
+
         fn accept_f32(&mut self, val: f32) -> $crate::internal::error::Result<()> {
             self.accept($crate::internal::event::Event::F32(val))
         }
@@ -287,6 +302,17 @@ macro_rules! accept_value {
             $block
         }
 
+        fn accept_bytes(&mut self, val: &[u8]) -> Result<()> {
+            let $this = self;
+            let $ev = Event::Bytes(val);
+            let $val = val;
+            fn $next<E: EventSink + ?Sized>(next: &mut E, val: &[u8]) -> Result<()> {
+                next.accept_bytes(val)
+            }
+
+            $block
+        }
+
         fn accept_bool(&mut self, val: bool) -> Result<()> {
             let $this = self;
             let $ev = Event::Bool(val);
@@ -342,6 +368,17 @@ macro_rules! accept_value {
             $block
         }
 
+        fn accept_i128(&mut self, val: i128) -> Result<()> {
+            let $this = self;
+            let $ev = Event::I128(val);
+            let $val = val;
+            fn $next<E: EventSink + ?Sized>(next: &mut E, val: i128) -> Result<()> {
+                next.accept_i128(val)
+            }
+
+            $block
+        }
+
         fn accept_u8(&mut self, val: u8) -> Result<()> {
             let $this = self;
             let $ev = Event::U8(val);
@@ -386,6 +423,17 @@ macro_rules! accept_value {
             $block
         }
 
+        fn accept_u128(&mut self, val: u128) -> Result<()> {
+            let $this = self;
+            let $ev = Event::U128(val);
+            let $val = val;
+            fn $next<E: EventSink + ?Sized>(next: &mut E, val: u128) -> Result<()> {
+                next.accept_u128(val)
+            }
+
+            $block
+        }
+
         fn accept_f32(&mut self, val: f32) -> Result<()> {
             let $this = self;
             let $ev = Event::F32(val);
@@ -514,6 +562,13 @@ macro_rules! fail_on_non_string_primitive {
                 path = self.path
             )
         }
+        fn accept_i128(&mut self, _val: i128) -> Result<()> {
+            fail!(
+                "{} cannot accept Event::I128 [{path}]",
+                $context,
+                path = self.path
+            )
+        }
         fn accept_u8(&mut self, _val: u8) -> Result<()> {
             fail!(
                 "{} cannot accept Event::U8 [{path}]",
@@ -542,6 +597,13 @@ macro_rules! fail_on_non_string_primitive {
                 path = self.path
             )
         }
+        fn accept_u128(&mut self, _val: u128) -> Result<()> {
+            fail!(
+                "{} cannot accept Event::U128 [{path}]",
+                $context,
+                path = self.path
+            )
+        }
         fn accept_f32(&mut self, _val: f32) -> Result<()> {
             fail!(
                 "{} cannot accept Event::F32 [{path}]",
@@ -556,6 +618,13 @@ macro_rules! fail_on_non_string_primitive {
                 path = self.path
             )
         }
+        fn accept_bytes(&mut self, _val: &[u8]) -> Result<()> {
+            fail!(
+                "{} cannot accept Event::Bytes [{path}]",
+                $context,
+                path = self.path
+            )
+        }
     };
 }
 
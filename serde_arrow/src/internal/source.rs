@@ -33,6 +33,32 @@ pub fn deserialize_from_source<
     Ok(res)
 }
 
+/// Deserialize a single item from an [EventSource]
+///
+/// In contrast to [deserialize_from_source], the source does not need to be
+/// exhausted after the item. This allows to deserialize the items of a sequence
+/// one by one.
+///
+pub fn deserialize_item_from_source<
+    'de,
+    'event,
+    T: Deserialize<'de>,
+    S: EventSource<'event> + 'event,
+>(
+    source: S,
+) -> Result<T> {
+    let mut deserializer = Deserializer {
+        source: PeekableEventSource::new(source),
+    };
+    let res = T::deserialize(&mut deserializer)?;
+
+    if deserializer.source.peeked.is_some() {
+        fail!("deserialize_item_from_source: unconsumed event after the item");
+    }
+
+    Ok(res)
+}
+
 /// A source of [Events][Event] that can be used to deserialize rust objects
 ///
 /// **Note**: implementations are not expected to yield `Some` events for
@@ -96,6 +122,22 @@ pub struct Deserializer<'event, S: EventSource<'event>> {
     source: PeekableEventSource<'event, S>,
 }
 
+impl<'event, S: EventSource<'event>> Deserializer<'event, S> {
+    /// Deserialize a byte event as a sequence of `u8` values, e.g., to
+    /// support `[u8; N]` or `Vec<u8>` without `serde_bytes`
+    fn deserialize_bytes_as_seq<'de, V: Visitor<'de>>(&mut self, visitor: V) -> Result<V::Value> {
+        match required(self.source.next()?)? {
+            Event::Bytes(val) => visitor.visit_seq(de::value::SeqDeserializer::<_, Error>::new(
+                val.iter().copied(),
+            )),
+            Event::OwnedBytes(val) => {
+                visitor.visit_seq(de::value::SeqDeserializer::<_, Error>::new(val.into_iter()))
+            }
+            ev => fail!("Invalid event {}, expected bytes", ev),
+        }
+    }
+}
+
 impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     for &'a mut Deserializer<'event, S>
 {
@@ -108,14 +150,18 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
             Some(Event::I16(_)) => self.deserialize_i16(visitor),
             Some(Event::I32(_)) => self.deserialize_i32(visitor),
             Some(Event::I64(_)) => self.deserialize_i64(visitor),
+            Some(Event::I128(_)) => self.deserialize_i128(visitor),
             Some(Event::U8(_)) => self.deserialize_u8(visitor),
             Some(Event::U16(_)) => self.deserialize_u16(visitor),
             Some(Event::U32(_)) => self.deserialize_u32(visitor),
             Some(Event::U64(_)) => self.deserialize_u64(visitor),
+            Some(Event::U128(_)) => self.deserialize_u128(visitor),
             Some(Event::F32(_)) => self.deserialize_f32(visitor),
             Some(Event::F64(_)) => self.deserialize_f64(visitor),
             Some(Event::Str(_)) => self.deserialize_str(visitor),
             Some(Event::OwnedStr(_)) => self.deserialize_string(visitor),
+            Some(Event::Bytes(_)) => self.deserialize_bytes(visitor),
+            Some(Event::OwnedBytes(_)) => self.deserialize_byte_buf(visitor),
             Some(Event::StartStruct) => self.deserialize_struct("", &[], visitor),
             Some(Event::StartMap) => self.deserialize_map(visitor),
             Some(Event::StartSequence) => self.deserialize_seq(visitor),
@@ -148,6 +194,10 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
         visitor.visit_i64(required(self.source.next()?)?.try_into()?)
     }
 
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_i128(required(self.source.next()?)?.try_into()?)
+    }
+
     fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         visitor.visit_u8(required(self.source.next()?)?.try_into()?)
     }
@@ -164,6 +214,10 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
         visitor.visit_u64(required(self.source.next()?)?.try_into()?)
     }
 
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_u128(required(self.source.next()?)?.try_into()?)
+    }
+
     fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         visitor.visit_f32(required(self.source.next()?)?.try_into()?)
     }
@@ -200,12 +254,20 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
         }
     }
 
-    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
-        fail!("deserialize_bytes: Bytes are not supported at the moment")
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match required(self.source.next()?)? {
+            Event::Bytes(val) => visitor.visit_bytes(val),
+            Event::OwnedBytes(val) => visitor.visit_bytes(&val),
+            ev => fail!("Invalid event {}, expected bytes", ev),
+        }
     }
 
-    fn deserialize_byte_buf<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
-        fail!("deserialize_byte_buf: Bytes are not supported at the moment")
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match required(self.source.next()?)? {
+            Event::Bytes(val) => visitor.visit_byte_buf(val.to_owned()),
+            Event::OwnedBytes(val) => visitor.visit_byte_buf(val),
+            ev => fail!("Invalid event {}, expected bytes", ev),
+        }
     }
 
     fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -246,6 +308,9 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Some(Event::Bytes(_) | Event::OwnedBytes(_)) = self.source.peek()? {
+            return self.deserialize_bytes_as_seq(visitor);
+        }
         if !matches!(self.source.next()?, Some(Event::StartSequence)) {
             fail!("Expected start of sequence");
         }
@@ -259,6 +324,13 @@ impl<'de, 'a, 'event, S: EventSource<'event>> de::Deserializer<'de>
     }
 
     fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        if let Some(Event::Bytes(_) | Event::OwnedBytes(_)) = self.source.peek()? {
+            return self.deserialize_bytes_as_seq(visitor);
+        }
+        // support fixed-length arrays stored as sequences, e.g., fixed size lists
+        if let Some(Event::StartSequence) = self.source.peek()? {
+            return self.deserialize_seq(visitor);
+        }
         if !matches!(self.source.next()?, Some(Event::StartTuple)) {
             fail!("Expected start of tuple");
         }
@@ -0,0 +1,141 @@
+//! Support for the timezones of timestamp fields
+//!
+//! Timestamps are always stored as offsets from the UTC epoch. The timezone
+//! of the field is only used when rendering timestamps as strings.
+use chrono::{DateTime, FixedOffset, Utc};
+
+use crate::internal::error::{fail, Result};
+
+/// The timezone of a timestamp field
+///
+/// Supported are `"UTC"`, fixed offsets (e.g., `"+02:00"`) and, with the
+/// `chrono-tz` feature, IANA names (e.g., `"Europe/Berlin"`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Timezone {
+    Utc,
+    Fixed(FixedOffset),
+    #[cfg(feature = "chrono-tz")]
+    Named(chrono_tz::Tz),
+}
+
+impl Timezone {
+    pub fn parse(tz: &str) -> Result<Self> {
+        if tz.to_uppercase() == "UTC" {
+            Ok(Self::Utc)
+        } else if let Some(offset) = parse_fixed_offset(tz)? {
+            Ok(Self::Fixed(offset))
+        } else {
+            parse_named(tz)
+        }
+    }
+
+    /// Format the timestamp as a string in this timezone
+    pub fn format(&self, date_time: DateTime<Utc>) -> String {
+        // NOTE: chrono documents that Debug, not Display, can be parsed
+        match self {
+            Self::Utc => format!("{:?}", date_time),
+            Self::Fixed(offset) => format!("{:?}", date_time.with_timezone(offset)),
+            #[cfg(feature = "chrono-tz")]
+            Self::Named(tz) => {
+                use chrono::Offset;
+
+                // use the numeric offset, as abbreviations cannot be parsed
+                let date_time = date_time.with_timezone(tz);
+                format!("{:?}", date_time.with_timezone(&date_time.offset().fix()))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono-tz")]
+fn parse_named(tz: &str) -> Result<Timezone> {
+    match tz.parse::<chrono_tz::Tz>() {
+        Ok(tz) => Ok(Timezone::Named(tz)),
+        Err(err) => fail!("Timezone {tz} is not supported: {err}"),
+    }
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+fn parse_named(tz: &str) -> Result<Timezone> {
+    fail!("Timezone {tz} is not supported, named timezones require the chrono-tz feature")
+}
+
+/// Parse offsets of the form `"+HH:MM"`, `"+HHMM"` or `"+HH"`
+///
+/// Returns `None` if the string does not start with a sign.
+fn parse_fixed_offset(tz: &str) -> Result<Option<FixedOffset>> {
+    let (sign, rest) = match tz.as_bytes().first() {
+        Some(b'+') => (1, &tz[1..]),
+        Some(b'-') => (-1, &tz[1..]),
+        _ => return Ok(None),
+    };
+
+    let (hours, minutes) = match rest.len() {
+        2 => (rest, "00"),
+        4 => (&rest[..2], &rest[2..]),
+        5 if rest.as_bytes()[2] == b':' => (&rest[..2], &rest[3..]),
+        _ => fail!("Invalid timezone offset {tz}"),
+    };
+    if !hours
+        .bytes()
+        .chain(minutes.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        fail!("Invalid timezone offset {tz}");
+    }
+
+    let seconds = hours.parse::<i32>()? * 3600 + minutes.parse::<i32>()? * 60;
+    let Some(offset) = FixedOffset::east_opt(sign * seconds) else {
+        fail!("Invalid timezone offset {tz}");
+    };
+    Ok(Some(offset))
+}
+
+#[test]
+fn test_parse_timezone() {
+    assert_eq!(Timezone::parse("UTC").unwrap(), Timezone::Utc);
+    assert_eq!(Timezone::parse("utc").unwrap(), Timezone::Utc);
+    assert_eq!(
+        Timezone::parse("+02:00").unwrap(),
+        Timezone::Fixed(FixedOffset::east_opt(2 * 3600).unwrap())
+    );
+    assert_eq!(
+        Timezone::parse("-0530").unwrap(),
+        Timezone::Fixed(FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap())
+    );
+    assert_eq!(
+        Timezone::parse("+01").unwrap(),
+        Timezone::Fixed(FixedOffset::east_opt(3600).unwrap())
+    );
+
+    assert!(Timezone::parse("+2:00").is_err());
+    assert!(Timezone::parse("+0a:00").is_err());
+    assert!(Timezone::parse("+25:00").is_err());
+}
+
+#[test]
+fn test_format_timezone() {
+    use chrono::TimeZone;
+
+    let date_time = Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap();
+
+    assert_eq!(Timezone::Utc.format(date_time), "2020-12-24T08:30:00Z");
+    assert_eq!(
+        Timezone::parse("+02:00").unwrap().format(date_time),
+        "2020-12-24T10:30:00+02:00"
+    );
+    assert_eq!(
+        Timezone::parse("-05:30").unwrap().format(date_time),
+        "2020-12-24T03:00:00-05:30"
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn test_named_timezone() {
+    use chrono::TimeZone;
+
+    let date_time = Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap();
+    let tz = Timezone::parse("Europe/Berlin").unwrap();
+    assert_eq!(tz.format(date_time), "2020-12-24T09:30:00+01:00");
+}
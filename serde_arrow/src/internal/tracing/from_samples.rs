@@ -9,7 +9,7 @@ use serde::Serialize;
 use crate::internal::{
     error::{fail, Result},
     event::Event,
-    schema::{GenericDataType, Strategy},
+    schema::{GenericDataType, GenericTimeUnit, Strategy},
     sink::macros,
     sink::{serialize_into_sink, EventSink},
     tracing::tracer::{
@@ -70,14 +70,18 @@ impl<E: EventSink> EventSink for StripOuterSequenceSink<E> {
             I16(val) => self.accept_i16(val),
             I32(val) => self.accept_i32(val),
             I64(val) => self.accept_i64(val),
+            I128(val) => self.accept_i128(val),
             U8(val) => self.accept_u8(val),
             U16(val) => self.accept_u16(val),
             U32(val) => self.accept_u32(val),
             U64(val) => self.accept_u64(val),
+            U128(val) => self.accept_u128(val),
             F32(val) => self.accept_f32(val),
             F64(val) => self.accept_f64(val),
             Str(val) => self.accept_str(val),
             OwnedStr(val) => self.accept_str(&val),
+            Bytes(val) => self.accept_bytes(val),
+            OwnedBytes(val) => self.accept_bytes(&val),
             Variant(name, idx) => self.accept_variant(name, idx),
             OwnedVariant(name, idx) => self.accept_variant(&name, idx),
         }
@@ -179,14 +183,18 @@ impl EventSink for Tracer {
                 | Event::I16(_)
                 | Event::I32(_)
                 | Event::I64(_)
+                | Event::I128(_)
                 | Event::U8(_)
                 | Event::U16(_)
                 | Event::U32(_)
                 | Event::U64(_)
+                | Event::U128(_)
                 | Event::F32(_)
                 | Event::F64(_)
                 | Event::Str(_)
-                | Event::OwnedStr(_) => {
+                | Event::OwnedStr(_)
+                | Event::Bytes(_)
+                | Event::OwnedBytes(_) => {
                     let mut tracer = PrimitiveTracer::new(
                         tracer.path.clone(),
                         tracer.options.clone(),
@@ -511,7 +519,10 @@ impl EventSink for UnionTracer {
                     self.ensure_variant(variant, idx)?;
                     S::InVariant(idx, 0)
                 }
-                E::Some => fail!("Nullable unions are not supported"),
+                E::Null | E::Some => {
+                    self.nullable = true;
+                    S::WaitForVariant
+                }
                 E::OwnedVariant(variant, idx) => {
                     self.ensure_variant(variant, idx)?;
                     S::InVariant(idx, 0)
@@ -654,13 +665,13 @@ impl EventSink for PrimitiveTracer {
 
     fn accept(&mut self, event: Event<'_>) -> Result<()> {
         use GenericDataType::*;
-        use Strategy as S;
 
         let (ev_type, ev_strategy) = match event {
             Event::Some | Event::Null => (Null, None),
             Event::Bool(_) => (Bool, None),
             Event::Str(s) => self.get_string_type_and_strategy(s),
             Event::OwnedStr(s) => self.get_string_type_and_strategy(&s),
+            Event::Bytes(_) | Event::OwnedBytes(_) => (LargeBinary, None),
             Event::U8(_) => (U8, None),
             Event::U16(_) => (U16, None),
             Event::U32(_) => (U32, None),
@@ -669,6 +680,7 @@ impl EventSink for PrimitiveTracer {
             Event::I16(_) => (I16, None),
             Event::I32(_) => (I32, None),
             Event::I64(_) => (I64, None),
+            Event::I128(_) | Event::U128(_) => (self.options.int128_data_type(), None),
             Event::F32(_) => (F32, None),
             Event::F64(_) => (F64, None),
             ev => fail!("Cannot handle event {ev} in primitive tracer"),
@@ -695,29 +707,28 @@ impl EventSink for PrimitiveTracer {
             ((U64, None), (U64, None)) => (U64, None),
             ((F32, None), (F32, None)) => (F32, None),
             ((F64, None), (F64, None)) => (F64, None),
-            ((Date64, Some(S::NaiveStrAsDate64)), (Date64, Some(S::NaiveStrAsDate64))) => {
-                (Date64, Some(S::NaiveStrAsDate64))
-            }
-            ((Date64, Some(S::UtcStrAsDate64)), (Date64, Some(S::UtcStrAsDate64))) => {
-                (Date64, Some(S::UtcStrAsDate64))
+            ((Decimal128(38, 0), None), (Decimal128(38, 0), None)) => (Decimal128(38, 0), None),
+            ((FixedSizeBinary(16), None), (FixedSizeBinary(16), None)) => {
+                (FixedSizeBinary(16), None)
             }
-            ((Date64, Some(S::NaiveStrAsDate64)), (Date64, Some(S::UtcStrAsDate64))) => {
-                (LargeUtf8, None)
+            ((this_ty, Some(this_strategy)), (ev_ty, Some(ev_strategy)))
+                if is_string_strategy(this_strategy) && is_string_strategy(&ev_strategy) =>
+            {
+                if *this_ty == ev_ty && *this_strategy == ev_strategy {
+                    (ev_ty, Some(ev_strategy))
+                } else {
+                    // incompatible strategies, coerce to string
+                    (LargeUtf8, None)
+                }
             }
-            // incompatible strategies, coerce to string
-            ((Date64, Some(S::UtcStrAsDate64)), (Date64, Some(S::NaiveStrAsDate64))) => {
+            ((LargeUtf8, None), (LargeUtf8, None)) => (LargeUtf8, None),
+            ((LargeBinary, None), (LargeBinary, None)) => (LargeBinary, None),
+            ((_, Some(this_strategy)), (LargeUtf8, None)) if is_string_strategy(this_strategy) => {
                 (LargeUtf8, None)
             }
-            (
-                (LargeUtf8, None) | (Date64, Some(S::NaiveStrAsDate64) | Some(S::UtcStrAsDate64)),
-                (LargeUtf8, None),
-            ) => (LargeUtf8, None),
-            (
-                (LargeUtf8, None),
-                (Date64, strategy @ (Some(S::NaiveStrAsDate64) | Some(S::UtcStrAsDate64))),
-            ) => {
+            ((LargeUtf8, None), (ev_ty, Some(ev_strategy))) if is_string_strategy(&ev_strategy) => {
                 if self.seen_samples == 0 {
-                    (Date64, strategy)
+                    (ev_ty, Some(ev_strategy))
                 } else {
                     (LargeUtf8, None)
                 }
@@ -753,9 +764,27 @@ impl EventSink for PrimitiveTracer {
     }
 }
 
+/// Test whether the strategy is used for values serialized as strings
+fn is_string_strategy(strategy: &Strategy) -> bool {
+    matches!(
+        strategy,
+        Strategy::NaiveStrAsDate32
+            | Strategy::NaiveStrAsDate64
+            | Strategy::UtcStrAsDate64
+            | Strategy::NaiveStrAsTime
+    )
+}
+
 impl PrimitiveTracer {
     fn get_string_type_and_strategy(&self, s: &str) -> (GenericDataType, Option<Strategy>) {
-        if self.options.guess_dates && matches_naive_datetime(s) {
+        if self.options.guess_dates && matches_naive_date(s) {
+            (GenericDataType::Date32, Some(Strategy::NaiveStrAsDate32))
+        } else if self.options.guess_dates && matches_naive_time(s) {
+            (
+                GenericDataType::Time64(GenericTimeUnit::Nanosecond),
+                Some(Strategy::NaiveStrAsTime),
+            )
+        } else if self.options.guess_dates && matches_naive_datetime(s) {
             (GenericDataType::Date64, Some(Strategy::NaiveStrAsDate64))
         } else if self.options.guess_dates && matches_utc_datetime(s) {
             (GenericDataType::Date64, Some(Strategy::UtcStrAsDate64))
@@ -789,18 +818,20 @@ mod parsing {
         s.strip_prefix(c).ok_or(s)
     }
 
-    pub fn matches_naive_datetime_with_sep<'a>(
-        s: &'a str,
-        sep: &'_ [char],
-    ) -> Result<&'a str, &'a str> {
-        let s = s.trim();
+    pub fn match_naive_date(s: &str) -> Result<&str, &str> {
         let s = match_optional_sign(s)?;
         let s = match_one_or_more_digits(s)?;
         let s = match_char(s, '-')?;
         let s = match_one_or_two_digits(s)?;
         let s = match_char(s, '-')?;
-        let s = match_one_or_two_digits(s)?;
-        let s = s.strip_prefix(sep).ok_or(s)?;
+        match_one_or_two_digits(s)
+    }
+
+    pub fn matches_naive_date(s: &str) -> Result<&str, &str> {
+        match_naive_date(s.trim())
+    }
+
+    pub fn match_naive_time(s: &str) -> Result<&str, &str> {
         let s = match_one_or_two_digits(s)?;
         let s = match_char(s, ':')?;
         let s = match_one_or_two_digits(s)?;
@@ -814,6 +845,20 @@ mod parsing {
         }
     }
 
+    pub fn matches_naive_time(s: &str) -> Result<&str, &str> {
+        match_naive_time(s.trim())
+    }
+
+    pub fn matches_naive_datetime_with_sep<'a>(
+        s: &'a str,
+        sep: &'_ [char],
+    ) -> Result<&'a str, &'a str> {
+        let s = s.trim();
+        let s = match_naive_date(s)?;
+        let s = s.strip_prefix(sep).ok_or(s)?;
+        match_naive_time(s)
+    }
+
     pub fn matches_naive_datetime(s: &str) -> Result<&str, &str> {
         matches_naive_datetime_with_sep(s, &['T'])
     }
@@ -833,6 +878,18 @@ mod parsing {
     }
 }
 
+pub fn matches_naive_date(s: &str) -> bool {
+    parsing::matches_naive_date(s)
+        .map(|s| s.is_empty())
+        .unwrap_or_default()
+}
+
+pub fn matches_naive_time(s: &str) -> bool {
+    parsing::matches_naive_time(s)
+        .map(|s| s.is_empty())
+        .unwrap_or_default()
+}
+
 pub fn matches_naive_datetime(s: &str) -> bool {
     parsing::matches_naive_datetime(s)
         .map(|s| s.is_empty())
@@ -845,6 +902,57 @@ pub fn matches_utc_datetime(s: &str) -> bool {
         .unwrap_or_default()
 }
 
+#[cfg(test)]
+mod test_matches_naive_date {
+    macro_rules! test {
+        ($( ( $name:ident, $s:expr, $expected:expr ), )*) => {
+            $(
+                #[test]
+                fn $name() {
+                    if $expected {
+                        assert_eq!(super::parsing::matches_naive_date($s), Ok(""));
+                    }
+                    assert_eq!(super::matches_naive_date($s), $expected);
+                }
+            )*
+        };
+    }
+
+    test!(
+        (example_chrono_docs_1, "2015-09-18", true),
+        (example_chrono_docs_2, "+12345-6-7", true),
+        (surrounding_space, "   2015-09-18   ", true),
+        (datetime, "2015-09-18T23:56:04", false),
+        (not_a_date, "not a date", false),
+    );
+}
+
+#[cfg(test)]
+mod test_matches_naive_time {
+    macro_rules! test {
+        ($( ( $name:ident, $s:expr, $expected:expr ), )*) => {
+            $(
+                #[test]
+                fn $name() {
+                    if $expected {
+                        assert_eq!(super::parsing::matches_naive_time($s), Ok(""));
+                    }
+                    assert_eq!(super::matches_naive_time($s), $expected);
+                }
+            )*
+        };
+    }
+
+    test!(
+        (example_chrono_docs_1, "23:56:04", true),
+        (example_chrono_docs_2, "7:59:60.5", true),
+        (fractional_seconds, "12:30:00.123456789", true),
+        (surrounding_space, "   23:56:04   ", true),
+        (missing_seconds, "23:56", false),
+        (datetime, "2015-09-18T23:56:04", false),
+    );
+}
+
 #[cfg(test)]
 mod test_matches_naive_datetime {
     macro_rules! test {
@@ -75,6 +75,11 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
         visitor.visit_i64(Default::default())
     }
 
+    fn deserialize_i128<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.ensure_int128()?;
+        visitor.visit_i128(Default::default())
+    }
+
     fn deserialize_u8<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         self.0.ensure_u8()?;
         visitor.visit_u8(Default::default())
@@ -95,6 +100,11 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
         visitor.visit_u64(Default::default())
     }
 
+    fn deserialize_u128<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.ensure_int128()?;
+        visitor.visit_u128(Default::default())
+    }
+
     fn deserialize_f32<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
         self.0.ensure_f32()?;
         visitor.visit_f32(Default::default())
@@ -120,12 +130,14 @@ impl<'de, 'a> serde::de::Deserializer<'de> for TraceAny<'a> {
         visitor.visit_string(Default::default())
     }
 
-    fn deserialize_bytes<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
-        todo!()
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.ensure_large_binary()?;
+        visitor.visit_bytes(&[])
     }
 
-    fn deserialize_byte_buf<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
-        todo!()
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.0.ensure_large_binary()?;
+        visitor.visit_byte_buf(Default::default())
     }
 
     fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
@@ -4,6 +4,8 @@ pub mod tracer;
 
 pub use tracer::Tracer;
 
+use crate::internal::schema::GenericDataType;
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TracingMode {
     Unknown,
@@ -74,15 +76,36 @@ pub struct TracingOptions {
 
     /// If `true`, try to auto detect datetimes in string columns
     ///
-    /// Currently the naive datetime (`YYYY-MM-DDThh:mm:ss`) and UTC datetimes
+    /// Currently naive dates (`YYYY-MM-DD`), naive times (`hh:mm:ss[.fff]`),
+    /// naive datetimes (`YYYY-MM-DDThh:mm:ss`) and UTC datetimes
     /// (`YYYY-MM-DDThh:mm:ssZ`) are understood.
     ///
     /// For string fields where all values are either missing or conform to one
-    /// of the format the data type is set as `Date64` with strategy
+    /// of the format the data type is set as `Date32` with strategy
+    /// [`NaiveStrAsDate32`][crate::schema::Strategy::NaiveStrAsDate32], as
+    /// `Time64(Nanosecond)` with strategy
+    /// [`NaiveStrAsTime`][crate::schema::Strategy::NaiveStrAsTime] or as
+    /// `Date64` with strategy
     /// [`NaiveStrAsDate64`][crate::schema::Strategy::NaiveStrAsDate64] or
     /// [`UtcStrAsDate64`][crate::schema::Strategy::UtcStrAsDate64].
     pub guess_dates: bool,
 
+    /// If `true`, trace tuples whose elements all share the same type as
+    /// `FixedSizeList(n)`, e.g., `[f32; 3]` or `(f64, f64)`. The default is
+    /// `false`, i.e., tuples are traced as structs with the
+    /// [`TupleAsStruct`][crate::schema::Strategy::TupleAsStruct] strategy.
+    pub tuples_as_fixed_size_lists: bool,
+
+    /// If `true`, trace 128 bit integers (`i128`, `u128`) as
+    /// `FixedSizeBinary(16)` holding the little endian bytes of the value. The
+    /// default is `false`, i.e., 128 bit integers are traced as
+    /// `Decimal128(38, 0)`.
+    ///
+    /// Note: `Decimal128(38, 0)` cannot represent all 128 bit integers, values
+    /// with more than 38 digits (e.g., `u128::MAX`) cause an error during
+    /// serialization.
+    pub int128_as_fixed_size_binary: bool,
+
     /// How many tracing iterations to perform in `from_type`.
     ///
     /// The default value may be too conservative for deeply nested types or
@@ -102,6 +125,8 @@ impl Default for TracingOptions {
             string_dictionary_encoding: false,
             coerce_numbers: false,
             guess_dates: false,
+            tuples_as_fixed_size_lists: false,
+            int128_as_fixed_size_binary: false,
             from_type_budget: 100,
             tracing_mode: TracingMode::Unknown,
         }
@@ -143,6 +168,18 @@ impl TracingOptions {
         self
     }
 
+    /// Set [`tuples_as_fixed_size_lists`](#structfield.tuples_as_fixed_size_lists)
+    pub fn tuples_as_fixed_size_lists(mut self, value: bool) -> Self {
+        self.tuples_as_fixed_size_lists = value;
+        self
+    }
+
+    /// Set [`int128_as_fixed_size_binary`](#structfield.int128_as_fixed_size_binary)
+    pub fn int128_as_fixed_size_binary(mut self, value: bool) -> Self {
+        self.int128_as_fixed_size_binary = value;
+        self
+    }
+
     /// Set [`from_type_budget`](#structfield.from_type_budget)
     pub fn from_type_budget(mut self, value: usize) -> Self {
         self.from_type_budget = value;
@@ -153,4 +190,13 @@ impl TracingOptions {
         self.tracing_mode = value;
         self
     }
+
+    /// The data type used for 128 bit integers
+    pub(crate) fn int128_data_type(&self) -> GenericDataType {
+        if self.int128_as_fixed_size_binary {
+            GenericDataType::FixedSizeBinary(16)
+        } else {
+            GenericDataType::Decimal128(38, 0)
+        }
+    }
 }
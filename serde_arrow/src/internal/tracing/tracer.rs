@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::internal::{
     error::{fail, Result},
-    schema::{GenericDataType, GenericField, SerdeArrowSchema, Strategy},
+    schema::{GenericDataType, GenericField, GenericUnionMode, SerdeArrowSchema, Strategy},
     tracing::TracingOptions,
 };
 
@@ -337,6 +337,9 @@ impl Tracer {
             (item_type, strategy),
             (GenericDataType::LargeUtf8, None)
                 | (GenericDataType::Utf8, None)
+                | (GenericDataType::Date32, Some(Strategy::NaiveStrAsDate32))
+                | (GenericDataType::Time32(_), Some(Strategy::NaiveStrAsTime))
+                | (GenericDataType::Time64(_), Some(Strategy::NaiveStrAsTime))
                 | (GenericDataType::Date64, Some(Strategy::UtcStrAsDate64))
                 | (GenericDataType::Date64, Some(Strategy::NaiveStrAsDate64))
         );
@@ -399,8 +402,33 @@ impl_primitive_ensures!(
     (ensure_u64, U64),
     (ensure_f32, F32),
     (ensure_f64, F64),
+    (ensure_large_binary, LargeBinary),
 );
 
+impl Tracer {
+    pub fn ensure_int128(&mut self) -> Result<()> {
+        let data_type = self.get_options().int128_data_type();
+        match self {
+            this @ Self::Unknown(_) => {
+                let tracer = PrimitiveTracer::new(
+                    this.get_path().to_owned(),
+                    this.get_options().clone(),
+                    data_type,
+                    this.get_nullable(),
+                );
+                *this = Self::Primitive(tracer);
+                Ok(())
+            }
+            Self::Primitive(tracer) if tracer.item_type == data_type => Ok(()),
+            _ => fail!(
+                "mismatched types, previous {:?}, current {:?}",
+                self.get_type(),
+                data_type
+            ),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct UnknownTracer {
     pub path: String,
@@ -683,6 +711,12 @@ impl TupleTracer {
             fail!("Cannot build field {name} from unfinished tracer");
         }
 
+        if self.options.tuples_as_fixed_size_lists {
+            if let Some(field) = self.to_fixed_size_list_field(name)? {
+                return Ok(field);
+            }
+        }
+
         let mut field = GenericField::new(name, GenericDataType::Struct, self.nullable);
         for (idx, tracer) in self.field_tracers.iter().enumerate() {
             field.children.push(tracer.to_field(&idx.to_string())?);
@@ -692,6 +726,25 @@ impl TupleTracer {
         Ok(field)
     }
 
+    /// Build a `FixedSizeList` field, if all elements share the same type
+    fn to_fixed_size_list_field(&self, name: &str) -> Result<Option<GenericField>> {
+        let Some((first, rest)) = self.field_tracers.split_first() else {
+            return Ok(None);
+        };
+        let element = first.to_field("element")?;
+        for tracer in rest {
+            if tracer.to_field("element")? != element {
+                return Ok(None);
+            }
+        }
+
+        let n = i32::try_from(self.field_tracers.len())?;
+        let mut field = GenericField::new(name, GenericDataType::FixedSizeList(n), self.nullable);
+        field.children.push(element);
+
+        Ok(Some(field))
+    }
+
     pub fn get_type(&self) -> Option<&GenericDataType> {
         Some(&GenericDataType::Struct)
     }
@@ -916,7 +969,7 @@ impl UnionTracer {
     }
 
     pub fn get_type(&self) -> Option<&GenericDataType> {
-        Some(&GenericDataType::Union)
+        Some(&GenericDataType::Union(GenericUnionMode::Dense))
     }
 
     pub fn get_strategy(&self) -> Option<&Strategy> {
@@ -928,7 +981,11 @@ impl UnionTracer {
             fail!("Cannot build field {name} from unfinished tracer");
         }
 
-        let mut field = GenericField::new(name, GenericDataType::Union, self.nullable);
+        let mut field = GenericField::new(
+            name,
+            GenericDataType::Union(GenericUnionMode::Dense),
+            self.nullable,
+        );
         for variant in &self.variants {
             if let Some(variant) = variant {
                 field.children.push(variant.tracer.to_field(&variant.name)?);
@@ -939,6 +996,13 @@ impl UnionTracer {
                 );
             };
         }
+        if self.nullable {
+            // missing values are encoded by a trailing null variant
+            field.children.push(
+                GenericField::new("null", GenericDataType::Null, true)
+                    .with_strategy(Strategy::NullVariant),
+            );
+        }
 
         Ok(field)
     }
@@ -28,11 +28,17 @@
     doc = r#"
 ## Overview
 
-| Operation        | [`arrow-*`](#features) | [`arrow2-*`](#features) |
-|------------------|------------------|-------------------|
-| Rust to Arrow    | [`to_arrow`]     | [`to_arrow2`]     |
-| Arrow to Rust    | [`from_arrow`]   | [`from_arrow2`]   |
-| Array Builder    | [`ArrowBuilder`] | [`Arrow2Builder`] |
+| Operation               | [`arrow-*`](#features)  | [`arrow2-*`](#features) |
+|-------------------------|-------------------------|-------------------------|
+| Rust to Arrow           | [`to_arrow`]            | [`to_arrow2`]           |
+| Arrow to Rust           | [`from_arrow`]          | [`from_arrow2`]         |
+| Arrow to Rust (lazy)    | [`from_arrow_iter`]     |                         |
+| Arrow to Rust (chunked) | [`from_arrow_chunks`]   |                         |
+| Rust to Batch           | [`to_record_batch`]     | [`to_arrow2_chunk`]     |
+| Batch to Rust           | [`from_record_batch`]   | [`from_arrow2_chunk`]   |
+| Batches to Rust         | [`from_record_batches`] | [`from_arrow2_chunks`]  |
+| Array Builder           | [`ArrowBuilder`]        | [`Arrow2Builder`]       |
+| Batch Builder           | [`ArrowBatchBuilder`]   | [`Arrow2BatchBuilder`]  |
 "#
 )]
 //!
@@ -79,17 +85,14 @@
 //! # fn main() { }
 //! ```
 //!
-//! The generated arrays can then be written to disk, e.g., as parquet:
+//! The records can also be converted into a chunk and its schema, that can
+//! then be written to disk, e.g., as parquet:
 //!
 //! ```rust,ignore
-//! use arrow2::{chunk::Chunk, datatypes::Schema};
+//! let (schema, chunk) = serde_arrow::to_arrow2_chunk(&fields, &records)?;
 //!
 //! // see https://jorgecarleitao.github.io/arrow2/io/parquet_write.html
-//! write_chunk(
-//!     "example.pq",
-//!     Schema::from(fields),
-//!     Chunk::new(arrays),
-//! )?;
+//! write_chunk("example.pq", schema, chunk)?;
 //! ```
 //!
 //! # Features:
@@ -124,6 +127,10 @@
 //! | `arrow-37`    | `arrow=37`    |
 //! | `arrow2-0-17` | `arrow2=0.17` |
 //! | `arrow2-0-16` | `arrow2=0.16` |
+//!
+//! Further, the `chrono-tz` feature enables named timezones, e.g.,
+//! `"Europe/Berlin"`, for `Timestamp` fields. Without it, only `"UTC"` and
+//! fixed offsets, e.g., `"+02:00"`, are supported.
 
 // be more forgiving without any active implementation
 #[cfg_attr(all(not(has_arrow), not(has_arrow2)), allow(unused))]
@@ -161,10 +168,12 @@ pub mod _impl {
                 }
                 pub mod array {
                     pub use $arrow_array::array::{
-                        make_array, Array, ArrayRef, ArrowPrimitiveType, BooleanArray,
-                        DictionaryArray, GenericListArray, LargeStringArray, MapArray, NullArray,
-                        OffsetSizeTrait, PrimitiveArray, StringArray, StructArray, UnionArray,
+                        make_array, Array, ArrayRef, ArrowPrimitiveType, BinaryArray, BooleanArray,
+                        DictionaryArray, FixedSizeBinaryArray, FixedSizeListArray, GenericListArray,
+                        LargeBinaryArray, LargeStringArray, MapArray, NullArray, OffsetSizeTrait,
+                        PrimitiveArray, RunArray, StringArray, StructArray, UnionArray,
                     };
+                    pub use $arrow_array::RecordBatch;
                     pub use $arrow_data::ArrayData;
                 }
                 pub mod buffer {
@@ -172,12 +181,12 @@ pub mod _impl {
                 }
                 pub mod datatypes {
                     pub use $arrow_array::types::{
-                        ArrowPrimitiveType, Date64Type, Decimal128Type, Float16Type, Float32Type, Float64Type,
-                        Int16Type, Int32Type, Int64Type, Int8Type, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
+                        ArrowPrimitiveType, Date32Type, Date64Type, Decimal128Type, Decimal256Type, DurationMicrosecondType, DurationMillisecondType, DurationNanosecondType, DurationSecondType, Float16Type, Float32Type, Float64Type,
+                        Int16Type, Int32Type, Int64Type, Int8Type, IntervalDayTimeType, IntervalMonthDayNanoType, IntervalYearMonthType, Time32MillisecondType, Time32SecondType, Time64MicrosecondType, Time64NanosecondType, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type,
                         UInt64Type, UInt8Type,
                     };
-                    pub use $arrow_buffer::ArrowNativeType;
-                    pub use $arrow_schema::{DataType, Field, TimeUnit, UnionMode};
+                    pub use $arrow_buffer::{i256, ArrowNativeType};
+                    pub use $arrow_schema::{DataType, Field, IntervalUnit, Schema, SchemaRef, TimeUnit, UnionMode};
                 }
                 pub mod error {
                     pub use $arrow_schema::ArrowError;
@@ -239,13 +248,19 @@ pub use crate::internal::error::{Error, Result};
 mod arrow_impl;
 
 #[cfg(has_arrow)]
-pub use arrow_impl::api::{from_arrow, to_arrow, ArrowBuilder};
+pub use arrow_impl::api::{
+    from_arrow, from_arrow_chunks, from_arrow_iter, from_record_batch, from_record_batches,
+    to_arrow, to_record_batch, ArrowBatchBuilder, ArrowBuilder,
+};
 
 #[cfg(has_arrow2)]
 mod arrow2_impl;
 
 #[cfg(has_arrow2)]
-pub use arrow2_impl::api::{from_arrow2, to_arrow2, Arrow2Builder};
+pub use arrow2_impl::api::{
+    from_arrow2, from_arrow2_chunk, from_arrow2_chunks, to_arrow2, to_arrow2_chunk,
+    Arrow2BatchBuilder, Arrow2Builder,
+};
 
 #[deny(missing_docs)]
 pub mod schema;
@@ -39,8 +39,22 @@
 //! # #[cfg(not(feature="has_arrow2"))]
 //! # fn main() {}
 //! ```
+//!
+//! Extension types are kept in the `extension_name` and `extension_metadata`
+//! attributes of fields, which are stored under [`EXTENSION_NAME_KEY`] and
+//! [`EXTENSION_METADATA_KEY`] in arrow and as `DataType::Extension` in arrow2.
+//! To handle all fields of an extension type with a given strategy, use
+//! [`register_extension_strategy`]:
+//!
+//! ```rust
+//! # use serde_arrow::schema::{register_extension_strategy, Strategy};
+//! register_extension_strategy("arrow.uuid", Strategy::UuidStrAsFixedSizeBinary);
+//! ```
 pub use crate::internal::{
-    schema::{SchemaLike, SerdeArrowSchema, Strategy, STRATEGY_KEY},
+    schema::{
+        register_extension_strategy, SchemaLike, SerdeArrowSchema, Strategy,
+        EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, STRATEGY_KEY,
+    },
     tracing::TracingOptions,
 };
 
@@ -0,0 +1,115 @@
+//! Test the chunk API of the arrow2 backend
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as serde_arrow,
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions, STRATEGY_KEY},
+    Arrow2Builder,
+};
+
+use crate::_impl::arrow2::datatypes::{DataType, Field};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Record {
+    date: String,
+    value: Option<f32>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            date: String::from("2024-03-01T12:00:00"),
+            value: Some(1.0),
+        },
+        Record {
+            date: String::from("2024-03-02T08:30:00"),
+            value: None,
+        },
+    ]
+}
+
+#[test]
+fn round_trip() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default().guess_dates(true))?;
+    let (schema, chunk) = serde_arrow::to_arrow2_chunk(&fields, &items)?;
+
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.arrays().len(), 2);
+    assert_eq!(schema.fields, fields);
+    assert_eq!(schema.fields[0].data_type, DataType::Date64);
+    assert_eq!(
+        schema.fields[0]
+            .metadata
+            .get(STRATEGY_KEY)
+            .map(String::as_str),
+        Some("NaiveStrAsDate64")
+    );
+
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow2_chunk(&schema, &chunk)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn builder() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let mut builder = Arrow2Builder::new(&fields)?;
+
+    builder.extend(&items)?;
+    let (_, chunk) = builder.build_chunk()?;
+    assert_eq!(chunk.len(), 2);
+
+    builder.push(&items[0])?;
+    let (schema, chunk) = builder.build_chunk()?;
+    assert_eq!(chunk.len(), 1);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow2_chunk(&schema, &chunk)?;
+    assert_eq!(round_tripped, items[..1]);
+
+    Ok(())
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+enum Value {
+    A(i32),
+    B(String),
+    C,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct UnionRecord {
+    value: Value,
+}
+
+#[test]
+fn sliced_dense_unions() -> PanicOnError<()> {
+    let items: Vec<UnionRecord> = [
+        Value::A(1),
+        Value::B(String::from("y")),
+        Value::B(String::from("x")),
+        Value::C,
+        Value::A(5),
+    ]
+    .into_iter()
+    .map(|value| UnionRecord { value })
+    .collect();
+
+    let fields =
+        Vec::<Field>::from_type::<UnionRecord>(TracingOptions::default().allow_null_fields(true))?;
+    let arrays = serde_arrow::to_arrow2(&fields, &items)?;
+    let arrays = arrays
+        .iter()
+        .map(|array| array.sliced(2, 3))
+        .collect::<Vec<_>>();
+
+    let round_tripped: Vec<UnionRecord> = serde_arrow::from_arrow2(&fields, &arrays)?;
+    assert_eq!(round_tripped, &items[2..]);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+//! Test the batching mode of the array builders
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as serde_arrow,
+    _impl::{arrow, arrow2},
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions},
+    Arrow2BatchBuilder, ArrowBatchBuilder,
+};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Record {
+    id: u64,
+    value: Option<f32>,
+}
+
+fn records(n: u64) -> Vec<Record> {
+    (0..n)
+        .map(|id| Record {
+            id,
+            value: (id % 2 == 0).then_some(id as f32),
+        })
+        .collect()
+}
+
+#[test]
+fn arrow_max_rows() -> PanicOnError<()> {
+    let items = records(7);
+    let fields = Vec::<arrow::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let mut builder = ArrowBatchBuilder::new(&fields)?.with_max_rows(3);
+
+    let mut batches = builder.push(&items[0])?;
+    assert!(batches.is_empty());
+
+    batches.extend(builder.extend(&items[1..])?);
+    assert_eq!(batches.len(), 2);
+
+    batches.extend(builder.flush()?);
+    assert!(builder.flush()?.is_empty());
+
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![3, 3, 1]);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn arrow_max_bytes() -> PanicOnError<()> {
+    let items = records(10);
+    let fields = Vec::<arrow::datatypes::Field>::from_samples(
+        &items.iter().map(|r| (r.id,)).collect::<Vec<_>>(),
+        TracingOptions::default(),
+    )?;
+
+    // each row requires 8 bytes for the u64 value
+    let mut builder = ArrowBatchBuilder::new(&fields)?.with_max_bytes(32);
+    let mut batches = builder.extend(items.iter().map(|r| (r.id,)))?;
+    batches.extend(builder.flush()?);
+
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![4, 4, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn arrow_callback() -> PanicOnError<()> {
+    let items = records(5);
+    let fields = Vec::<arrow::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let mut batches = Vec::new();
+    let mut builder = ArrowBatchBuilder::new(&fields)?
+        .with_max_rows(2)
+        .with_callback(|batch| {
+            batches.push(batch);
+            Ok(())
+        });
+
+    assert!(builder.extend(&items)?.is_empty());
+    assert!(builder.flush()?.is_empty());
+    drop(builder);
+
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2, 2, 1]);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn arrow2_max_rows() -> PanicOnError<()> {
+    let items = records(7);
+    let fields = Vec::<arrow2::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let mut builder = Arrow2BatchBuilder::new(&fields)?.with_max_rows(3);
+    let mut chunks = builder.extend(&items)?;
+    chunks.extend(builder.flush()?);
+
+    let num_rows = chunks.iter().map(|c| c.len()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![3, 3, 1]);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow2_chunks(&builder.schema(), &chunks)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn arrow2_max_bytes_and_callback() -> PanicOnError<()> {
+    let items = records(10);
+    let fields = Vec::<arrow2::datatypes::Field>::from_samples(
+        &items.iter().map(|r| (r.id,)).collect::<Vec<_>>(),
+        TracingOptions::default(),
+    )?;
+
+    let mut num_rows = Vec::new();
+    let mut builder = Arrow2BatchBuilder::new(&fields)?
+        .with_max_bytes(32)
+        .with_callback(|chunk| {
+            num_rows.push(chunk.len());
+            Ok(())
+        });
+
+    assert!(builder.extend(items.iter().map(|r| (r.id,)))?.is_empty());
+    assert!(builder.flush()?.is_empty());
+    drop(builder);
+
+    assert_eq!(num_rows, vec![4, 4, 2]);
+
+    Ok(())
+}
+
+/// A row that fails to serialize, if its id is not a number
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Row {
+    Valid(Record),
+    Invalid { id: &'static str },
+}
+
+fn rows_with_error() -> Vec<Row> {
+    let mut rows = records(3).into_iter().map(Row::Valid).collect::<Vec<_>>();
+    rows.push(Row::Invalid { id: "invalid" });
+    rows
+}
+
+#[test]
+fn arrow_errors_keep_completed_batches() -> PanicOnError<()> {
+    let fields = Vec::<arrow::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+    let mut builder = ArrowBatchBuilder::new(&fields)?.with_max_rows(2);
+
+    assert!(builder.extend(rows_with_error()).is_err());
+
+    // the completed batch is kept, the incomplete batch is discarded
+    let batches = builder.flush()?;
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2]);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+    assert_eq!(round_tripped, records(2));
+
+    // the builder can be used after the error
+    let mut batches = builder.extend(records(3))?;
+    batches.extend(builder.flush()?);
+    let num_rows = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2, 1]);
+
+    Ok(())
+}
+
+#[test]
+fn arrow2_errors_keep_completed_chunks() -> PanicOnError<()> {
+    let fields = Vec::<arrow2::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+    let mut builder = Arrow2BatchBuilder::new(&fields)?.with_max_rows(2);
+
+    assert!(builder.extend(rows_with_error()).is_err());
+
+    let chunks = builder.flush()?;
+    let num_rows = chunks.iter().map(|c| c.len()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2]);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow2_chunks(&builder.schema(), &chunks)?;
+    assert_eq!(round_tripped, records(2));
+
+    let mut chunks = builder.extend(records(3))?;
+    chunks.extend(builder.flush()?);
+    let num_rows = chunks.iter().map(|c| c.len()).collect::<Vec<_>>();
+    assert_eq!(num_rows, vec![2, 1]);
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Test the lazy deserialization of arrow arrays
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as serde_arrow,
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+};
+
+use crate::_impl::arrow::datatypes::Field;
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Nested {
+    name: String,
+    values: Vec<i32>,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+enum Value {
+    Int(i64),
+    Text(String),
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Record {
+    id: u64,
+    nested: Option<Nested>,
+    value: Value,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            id: 0,
+            nested: Some(Nested {
+                name: String::from("foo"),
+                values: vec![1, 2, 3],
+            }),
+            value: Value::Int(13),
+        },
+        Record {
+            id: 1,
+            nested: None,
+            value: Value::Text(String::from("bar")),
+        },
+        Record {
+            id: 2,
+            nested: Some(Nested {
+                name: String::from("baz"),
+                values: vec![],
+            }),
+            value: Value::Int(-1),
+        },
+    ]
+}
+
+#[test]
+fn iterate_all_items() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default())?;
+    let arrays = serde_arrow::to_arrow(&fields, &items)?;
+
+    let round_tripped = serde_arrow::from_arrow_iter::<Record, _>(&fields, &arrays)?
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn stop_early() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default())?;
+    let arrays = serde_arrow::to_arrow(&fields, &items)?;
+
+    let mut iter = serde_arrow::from_arrow_iter::<Record, _>(&fields, &arrays)?;
+    assert_eq!(iter.next().transpose()?, Some(items[0].clone()));
+    assert_eq!(iter.next().transpose()?, Some(items[1].clone()));
+    drop(iter);
+
+    let ids = serde_arrow::from_arrow_iter::<Record, _>(&fields, &arrays)?
+        .map(|item| item.map(|item| item.id))
+        .take_while(|id| !matches!(id, Ok(1)))
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(ids, vec![0]);
+
+    Ok(())
+}
+
+#[test]
+fn empty_arrays() -> PanicOnError<()> {
+    let items: Vec<Record> = Vec::new();
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let arrays = serde_arrow::to_arrow(&fields, &items)?;
+
+    let mut iter = serde_arrow::from_arrow_iter::<Record, _>(&fields, &arrays)?;
+    assert!(iter.next().is_none());
+    assert!(iter.next().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn errors_end_the_iteration() -> PanicOnError<()> {
+    let items = [Item(1_i64), Item(-1), Item(2)];
+
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default())?;
+    let arrays = serde_arrow::to_arrow(&fields, &items)?;
+
+    let mut iter = serde_arrow::from_arrow_iter::<Item<u8>, _>(&fields, &arrays)?;
+    assert_eq!(iter.next().transpose()?, Some(Item(1)));
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+
+    Ok(())
+}
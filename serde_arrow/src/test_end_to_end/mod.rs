@@ -1,3 +1,8 @@
 //! Test end to end examples to ensure the API works as designed
 //!
+mod arrow2_chunk;
+mod batch_builder;
+mod from_arrow_iter;
 mod issue_90;
+mod multiple_chunks;
+mod record_batch;
@@ -0,0 +1,184 @@
+//! Test the deserialization of multiple record batches / chunks
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as serde_arrow,
+    _impl::{arrow, arrow2},
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions},
+    utils::Item,
+};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Record {
+    id: u64,
+    tags: Vec<String>,
+    value: Option<f32>,
+}
+
+fn records() -> Vec<Record> {
+    (0..7)
+        .map(|id| Record {
+            id,
+            tags: (0..id % 3).map(|i| format!("tag{i}")).collect(),
+            // only the last records contain nulls, to change the layout
+            value: if id >= 5 && id % 2 == 1 {
+                None
+            } else {
+                Some(id as f32)
+            },
+        })
+        .collect()
+}
+
+/// Split the records into chunks of different sizes, including empty chunks
+fn split(items: &[Record]) -> Vec<&[Record]> {
+    vec![&items[..3], &items[3..3], &items[3..5], &items[5..]]
+}
+
+#[test]
+fn record_batches() -> PanicOnError<()> {
+    use arrow::datatypes::Field;
+
+    let items = records();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let batches = split(&items)
+        .into_iter()
+        .map(|chunk| serde_arrow::to_record_batch(&fields, &chunk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batches(&batches)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn arrow_chunks() -> PanicOnError<()> {
+    use arrow::datatypes::Field;
+
+    let items = records();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let chunks = split(&items)
+        .into_iter()
+        .map(|chunk| serde_arrow::to_arrow(&fields, &chunk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow_chunks(&fields, &chunks)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn arrow2_chunks() -> PanicOnError<()> {
+    use arrow2::datatypes::{Field, Schema};
+
+    let items = records();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+
+    let chunks = split(&items)
+        .into_iter()
+        .map(|chunk| Ok(serde_arrow::to_arrow2_chunk(&fields, &chunk)?.1))
+        .collect::<PanicOnError<Vec<_>>>()?;
+
+    let round_tripped: Vec<Record> =
+        serde_arrow::from_arrow2_chunks(&Schema::from(fields), &chunks)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn no_chunks() -> PanicOnError<()> {
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batches(&[])?;
+    assert_eq!(round_tripped, vec![]);
+
+    let fields = Vec::<arrow::datatypes::Field>::from_type::<Record>(TracingOptions::default())?;
+    let chunks: Vec<Vec<arrow::array::ArrayRef>> = Vec::new();
+    let round_tripped: Vec<Record> = serde_arrow::from_arrow_chunks(&fields, &chunks)?;
+    assert_eq!(round_tripped, vec![]);
+
+    Ok(())
+}
+
+#[test]
+fn mismatched_chunks_are_rejected() -> PanicOnError<()> {
+    use arrow::datatypes::Field;
+
+    let int_fields = Vec::<Field>::from_samples(&[Item(1_i64)], TracingOptions::default())?;
+    let str_fields = Vec::<Field>::from_samples(&[Item("a")], TracingOptions::default())?;
+
+    let batches = vec![
+        serde_arrow::to_record_batch(&int_fields, &[Item(1_i64)])?,
+        serde_arrow::to_record_batch(&str_fields, &[Item("a")])?,
+    ];
+    let Err(err) = serde_arrow::from_record_batches::<Vec<Item<i64>>>(&batches) else {
+        panic!("expected an error");
+    };
+    assert!(err.to_string().contains("same schema"), "{err}");
+
+    let chunks = vec![
+        serde_arrow::to_arrow(&int_fields, &[Item(1_i64), Item(2)])?,
+        vec![],
+    ];
+    let Err(err) = serde_arrow::from_arrow_chunks::<Vec<Item<i64>>, _, _>(&int_fields, &chunks)
+    else {
+        panic!("expected an error");
+    };
+    assert!(err.to_string().contains("arrays per chunk"), "{err}");
+
+    let two_fields = [
+        int_fields[0].clone(),
+        int_fields[0].clone().with_name("other"),
+    ];
+    let mut chunk = serde_arrow::to_arrow(&int_fields, &[Item(1_i64), Item(2)])?;
+    chunk.extend(serde_arrow::to_arrow(&int_fields, &[Item(3_i64)])?);
+    let Err(err) = serde_arrow::from_arrow_chunks::<Vec<Item<i64>>, _, _>(&two_fields, &[chunk])
+    else {
+        panic!("expected an error");
+    };
+    assert!(err.to_string().contains("same length"), "{err}");
+
+    Ok(())
+}
+
+#[test]
+fn chunks_with_the_same_layout_share_bytecode() -> PanicOnError<()> {
+    use std::sync::Arc;
+
+    use arrow::datatypes::Field;
+
+    use crate::internal::{deserialization::compile_chunks, schema::GenericField};
+
+    let items = records();
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let generic_fields = fields
+        .iter()
+        .map(GenericField::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let batches = split(&items)
+        .into_iter()
+        .map(|chunk| serde_arrow::to_arrow(&fields, &chunk))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let programs = compile_chunks(
+        &generic_fields,
+        batches
+            .iter()
+            .map(|arrays| arrays.iter().map(AsRef::as_ref).collect::<Vec<_>>()),
+    )?;
+    assert_eq!(programs.len(), 4);
+
+    // the first chunks contain no nulls and share the same layout
+    assert!(Arc::ptr_eq(&programs[0].bytecode, &programs[1].bytecode));
+    assert!(Arc::ptr_eq(&programs[1].bytecode, &programs[2].bytecode));
+
+    // the last chunk contains nulls and requires different bytecode
+    assert!(!Arc::ptr_eq(&programs[2].bytecode, &programs[3].bytecode));
+
+    Ok(())
+}
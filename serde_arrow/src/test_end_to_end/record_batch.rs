@@ -0,0 +1,146 @@
+//! Test the record batch API of the arrow backend
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    self as serde_arrow,
+    internal::error::PanicOnError,
+    schema::{SchemaLike, TracingOptions, STRATEGY_KEY},
+    ArrowBuilder,
+};
+
+use crate::_impl::arrow::datatypes::{DataType, Field, Schema};
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct Record {
+    date: String,
+    value: Option<f32>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            date: String::from("2024-03-01T12:00:00"),
+            value: Some(1.0),
+        },
+        Record {
+            date: String::from("2024-03-02T08:30:00"),
+            value: None,
+        },
+    ]
+}
+
+#[test]
+fn round_trip() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_samples(&items, TracingOptions::default().guess_dates(true))?;
+    let record_batch = serde_arrow::to_record_batch(&fields, &items)?;
+
+    assert_eq!(record_batch.num_rows(), 2);
+    assert_eq!(record_batch.num_columns(), 2);
+
+    let schema = record_batch.schema();
+    let date_field = schema.field_with_name("date")?;
+    assert_eq!(date_field.data_type(), &DataType::Date64);
+    assert_eq!(
+        date_field.metadata().get(STRATEGY_KEY).map(String::as_str),
+        Some("NaiveStrAsDate64")
+    );
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batch(&record_batch)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[test]
+fn builder() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let mut builder = ArrowBuilder::new(&fields)?;
+
+    builder.extend(&items)?;
+    let record_batch = builder.build_record_batch()?;
+    assert_eq!(record_batch.num_rows(), 2);
+    assert_eq!(record_batch.schema().fields().len(), fields.len());
+
+    builder.push(&items[0])?;
+    let record_batch = builder.build_record_batch()?;
+    assert_eq!(record_batch.num_rows(), 1);
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batch(&record_batch)?;
+    assert_eq!(round_tripped, items[..1]);
+
+    Ok(())
+}
+
+#[test]
+fn builder_from_schema_keeps_metadata() -> PanicOnError<()> {
+    let items = records();
+
+    let fields = Vec::<Field>::from_type::<Record>(TracingOptions::default())?;
+    let schema = Schema::new(fields).with_metadata(
+        [(String::from("source"), String::from("test"))]
+            .into_iter()
+            .collect(),
+    );
+    let mut builder = ArrowBuilder::from_schema(schema.clone())?;
+
+    builder.extend(&items)?;
+    let record_batch = builder.build_record_batch()?;
+    assert_eq!(record_batch.schema().as_ref(), &schema);
+    assert_eq!(
+        record_batch
+            .schema()
+            .metadata()
+            .get("source")
+            .map(String::as_str),
+        Some("test")
+    );
+
+    let round_tripped: Vec<Record> = serde_arrow::from_record_batch(&record_batch)?;
+    assert_eq!(round_tripped, items);
+
+    Ok(())
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+enum Value {
+    A(i32),
+    B(String),
+    C,
+}
+
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+struct UnionRecord {
+    value: Value,
+}
+
+fn union_records() -> Vec<UnionRecord> {
+    [
+        Value::A(1),
+        Value::B(String::from("y")),
+        Value::B(String::from("x")),
+        Value::C,
+        Value::A(5),
+    ]
+    .into_iter()
+    .map(|value| UnionRecord { value })
+    .collect()
+}
+
+#[test]
+fn sliced_dense_unions() -> PanicOnError<()> {
+    let items = union_records();
+
+    let fields =
+        Vec::<Field>::from_type::<UnionRecord>(TracingOptions::default().allow_null_fields(true))?;
+    let record_batch = serde_arrow::to_record_batch(&fields, &items)?;
+
+    let round_tripped: Vec<UnionRecord> =
+        serde_arrow::from_record_batch(&record_batch.slice(2, 3))?;
+    assert_eq!(round_tripped, &items[2..]);
+
+    Ok(())
+}
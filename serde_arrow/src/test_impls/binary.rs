@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{schema::TracingOptions, utils::Item};
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Bytes(#[serde(with = "serde_bytes")] Vec<u8>);
+
+#[test]
+fn binary() {
+    let items = [Item(Bytes(b"foo".to_vec())), Item(Bytes(vec![0, 1, 255]))];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Binary"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn large_binary() {
+    let items = [Item(Bytes(b"foo".to_vec())), Item(Bytes(vec![]))];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeBinary"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn large_binary_nullable() {
+    let items = [
+        Item(Some(Bytes(b"foo".to_vec()))),
+        Item(None),
+        Item(Some(Bytes(b"bar".to_vec()))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeBinary",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn byte_buf() {
+    let items = [
+        Item(serde_bytes::ByteBuf::from(b"hello".to_vec())),
+        Item(serde_bytes::ByteBuf::from(vec![42])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Binary"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn trace_from_samples() {
+    let items = [
+        Item(Some(Bytes(b"foo".to_vec()))),
+        Item(None),
+        Item(Some(Bytes(vec![]))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeBinary",
+            "nullable": true,
+        }]))
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn trace_from_type() {
+    let items = [
+        Item(serde_bytes::ByteBuf::from(b"foo".to_vec())),
+        Item(serde_bytes::ByteBuf::from(vec![])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeBinary"}]))
+        .trace_schema_from_type::<Item<serde_bytes::ByteBuf>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn fixed_size_binary() {
+    let items = [
+        Item(Bytes(b"abcd".to_vec())),
+        Item(Bytes(vec![0, 1, 2, 255])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(4)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn fixed_size_binary_nullable() {
+    let items = [
+        Item(Some(Bytes(b"abcd".to_vec()))),
+        Item(None),
+        Item(Some(Bytes(b"efgh".to_vec()))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeBinary(4)",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn fixed_size_binary_arrays() {
+    let items = [Item([1_u8, 2, 3]), Item([4, 5, 6])];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(3)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn fixed_size_binary_invalid_length() {
+    let items = [Item(Bytes(b"abcd".to_vec())), Item(Bytes(b"abc".to_vec()))];
+
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(4)"}]));
+
+    let err = test
+        .try_serialize_arrow(&items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("Invalid number of bytes"));
+
+    let err = test
+        .try_serialize_arrow2(&items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("Invalid number of bytes"));
+
+    let items = [Item([1_u8, 2, 3])];
+    let err = test
+        .try_serialize_arrow(&items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("Invalid number of bytes"));
+}
@@ -1,7 +1,7 @@
 use super::utils::Test;
-use crate::{schema::TracingOptions, utils::Item};
+use crate::{_impl::arrow, schema::TracingOptions, utils::Item};
 
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
@@ -73,6 +73,154 @@ fn naive_as_date64() {
         .check_nulls(&[&[false, false]]);
 }
 
+#[test]
+fn naive_date_as_date32() {
+    let items = [
+        Item(NaiveDate::from_ymd_opt(2024, 3, 14).unwrap()),
+        Item(NaiveDate::from_ymd_opt(1956, 11, 2).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Date32",
+            "strategy": "NaiveStrAsDate32",
+        }]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_date_as_date32_nullable() {
+    let items = [
+        Item(Some(NaiveDate::from_ymd_opt(2024, 3, 14).unwrap())),
+        Item(None),
+        Item(Some(NaiveDate::from_ymd_opt(1956, 11, 2).unwrap())),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Date32",
+            "strategy": "NaiveStrAsDate32",
+            "nullable": true,
+        }]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn date32_as_days_since_epoch() {
+    let items = [Item(19_796_i32), Item(-4_808_i32)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Date32"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_time_as_time64_nanosecond() {
+    let items = [
+        Item(NaiveTime::from_hms_nano_opt(12, 30, 5, 123_456_789).unwrap()),
+        Item(NaiveTime::from_hms_opt(23, 59, 59).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time64(Nanosecond)",
+            "strategy": "NaiveStrAsTime",
+        }]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_time_as_time64_microsecond() {
+    let items = [
+        Item(NaiveTime::from_hms_micro_opt(12, 30, 5, 123_456).unwrap()),
+        Item(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time64(Microsecond)",
+            "strategy": "NaiveStrAsTime",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_time_as_time32_millisecond_nullable() {
+    let items = [
+        Item(Some(NaiveTime::from_hms_milli_opt(8, 15, 0, 250).unwrap())),
+        Item(None),
+        Item(Some(NaiveTime::from_hms_opt(17, 45, 30).unwrap())),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time32(Millisecond)",
+            "strategy": "NaiveStrAsTime",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn naive_time_as_time32_second() {
+    let items = [
+        Item(NaiveTime::from_hms_opt(8, 15, 0).unwrap()),
+        Item(NaiveTime::from_hms_opt(17, 45, 30).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Time32(Second)",
+            "strategy": "NaiveStrAsTime",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn time32_as_integers() {
+    let items = [Item(29_700_i32), Item(63_930_i32)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Time32(Second)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn time64_as_integers() {
+    let items = [Item(29_700_000_000_i64), Item(63_930_000_000_i64)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Time64(Microsecond)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
 #[test]
 fn utc_as_date64_as_millis() {
     #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -249,6 +397,51 @@ fn naive_as_date64_tracing_string_with_invalid() {
         .check_nulls(&[&[false, false, false]]);
 }
 
+#[test]
+fn naive_date_tracing_string_with_invalid() {
+    let items = [
+        Item(String::from("2015-09-18")),
+        Item(String::from("not a date")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn incompatible_date_and_datetime_formats_tracing() {
+    let items = [
+        Item(String::from("2015-09-18")),
+        Item(String::from("2023-08-14T17:00:04")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn incompatible_date_and_time_formats_tracing() {
+    let items = [
+        Item(String::from("2015-09-18")),
+        Item(String::from("12:30:00")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "LargeUtf8"}]))
+        .trace_schema_from_samples(&items, TracingOptions::default().guess_dates(true))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
 #[test]
 fn incompatible_date_formats_tracing() {
     let items = [
@@ -263,3 +456,287 @@ fn incompatible_date_formats_tracing() {
         .deserialize(&items)
         .check_nulls(&[&[false, false]]);
 }
+
+#[test]
+fn utc_as_timestamp_nanoseconds() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct T {
+        #[serde(with = "chrono::serde::ts_nanoseconds")]
+        item: DateTime<Utc>,
+    }
+
+    let items = [
+        T {
+            item: Utc.timestamp_opt(1662921288, 123_456_789).unwrap(),
+        },
+        T {
+            item: Utc.timestamp_opt(-2208936075, 1).unwrap(),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Nanosecond, Some(\"UTC\"))",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn utc_as_timestamp_microseconds() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct T {
+        #[serde(with = "chrono::serde::ts_microseconds")]
+        item: DateTime<Utc>,
+    }
+
+    let items = [
+        T {
+            item: Utc.timestamp_opt(1662921288, 123_456_000).unwrap(),
+        },
+        T {
+            item: Utc.timestamp_opt(-2208936075, 1_000).unwrap(),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Microsecond, Some(\"UTC\"))",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn utc_str_as_timestamp_nanoseconds() {
+    let items = [
+        Item(Utc.timestamp_opt(1662921288, 123_456_789).unwrap()),
+        Item(Utc.timestamp_opt(-2208936075, 999_999_999).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Nanosecond, Some(\"UTC\"))",
+            "strategy": "UtcStrAsDate64",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn utc_str_as_timestamp_microseconds_nullable() {
+    let items = [
+        Item(Some(Utc.timestamp_opt(1662921288, 123_456_000).unwrap())),
+        Item(None),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Microsecond, Some(\"UTC\"))",
+            "strategy": "UtcStrAsDate64",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true]]);
+}
+
+#[test]
+fn utc_str_as_timestamp_seconds() {
+    let items = [
+        Item(Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap()),
+        Item(Utc.with_ymd_and_hms(1900, 1, 1, 12, 0, 30).unwrap()),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"UTC\"))",
+            "strategy": "UtcStrAsDate64",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_as_timestamp_nanoseconds() {
+    let items = [
+        Item(
+            NaiveDate::from_ymd_opt(2022, 9, 11)
+                .unwrap()
+                .and_hms_nano_opt(18, 34, 48, 123_456_789)
+                .unwrap(),
+        ),
+        Item(
+            NaiveDate::from_ymd_opt(1900, 1, 1)
+                .unwrap()
+                .and_hms_nano_opt(0, 0, 0, 1)
+                .unwrap(),
+        ),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Nanosecond, None)",
+            "strategy": "NaiveStrAsDate64",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn naive_as_date64_with_milliseconds() {
+    let items = [
+        Item(
+            NaiveDate::from_ymd_opt(2022, 9, 11)
+                .unwrap()
+                .and_hms_milli_opt(18, 34, 48, 123)
+                .unwrap(),
+        ),
+        Item(
+            NaiveDate::from_ymd_opt(1900, 1, 1)
+                .unwrap()
+                .and_hms_milli_opt(0, 0, 0, 999)
+                .unwrap(),
+        ),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Date64",
+            "strategy": "NaiveStrAsDate64",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn fixed_offset_str_as_timestamp() {
+    let items = [
+        Item(String::from("2020-12-24T10:30:00+02:00")),
+        Item(String::from("2023-05-05T18:06:00.123456+02:00")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Microsecond, Some(\"+02:00\"))",
+            "strategy": "UtcStrAsDate64",
+        }]))
+        .serialize(&items)
+        .also(|it| {
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+            let arr = arrays[0]
+                .as_any()
+                .downcast_ref::<arrow::array::PrimitiveArray<arrow::datatypes::TimestampMicrosecondType>>()
+                .unwrap();
+            assert_eq!(arr.values(), &[1608798600000000, 1683302760123456]);
+        })
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn offsets_are_normalized_to_utc() {
+    let items = [
+        Item(String::from("2020-12-24T10:30:00+02:00")),
+        Item(String::from("2020-12-24T03:00:00-05:30")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Second, Some(\"UTC\"))",
+            "strategy": "UtcStrAsDate64",
+        }]))
+        .serialize(&items)
+        .also(|it| {
+            let arrays = it.arrays.arrow.as_ref().unwrap();
+            let arr = arrays[0]
+                .as_any()
+                .downcast_ref::<arrow::array::PrimitiveArray<arrow::datatypes::TimestampSecondType>>()
+                .unwrap();
+            assert_eq!(arr.values(), &[1608798600, 1608798600]);
+        })
+        .deserialize(&[
+            Item(String::from("2020-12-24T08:30:00Z")),
+            Item(String::from("2020-12-24T08:30:00Z")),
+        ]);
+}
+
+#[test]
+fn fixed_offset_as_timestamp_integers() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct T {
+        #[serde(with = "chrono::serde::ts_milliseconds")]
+        item: DateTime<Utc>,
+    }
+
+    let items = [
+        T {
+            item: Utc.with_ymd_and_hms(2020, 12, 24, 8, 30, 0).unwrap(),
+        },
+        T {
+            item: Utc.with_ymd_and_hms(2023, 5, 5, 16, 6, 0).unwrap(),
+        },
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Millisecond, Some(\"-05:30\"))",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[test]
+fn named_timezones_require_feature() {
+    let items = [Item(String::from("2020-12-24T10:30:00+02:00"))];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "Timestamp(Second, Some(\"Europe/Berlin\"))",
+    }]));
+
+    let err = test
+        .try_serialize_arrow(&items)
+        .expect_err("Expected error");
+    assert!(
+        err.to_string().contains("chrono-tz"),
+        "unexpected error: {err}"
+    );
+}
+
+#[cfg(feature = "chrono-tz")]
+#[test]
+fn named_timezone_str_as_timestamp() {
+    let items = [
+        Item(String::from("2020-12-24T09:30:00+01:00")),
+        Item(String::from("2020-06-24T10:30:00+02:00")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Timestamp(Millisecond, Some(\"Europe/Berlin\"))",
+            "strategy": "UtcStrAsDate64",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
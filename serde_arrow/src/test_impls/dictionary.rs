@@ -1,6 +1,7 @@
 use super::utils::Test;
 use crate::{schema::TracingOptions, utils::Item};
 
+use serde_bytes::ByteBuf;
 use serde_json::json;
 
 #[test]
@@ -78,3 +79,183 @@ fn examples_nullable() {
         }
     }
 }
+
+#[test]
+fn integer_values() {
+    let items = [Item(13_i32), Item(-2_i32), Item(13_i32), Item(13_i32)];
+
+    for index_ty in ["I8", "U32"] {
+        for value_ty in ["I8", "I16", "I32", "I64"] {
+            Test::new()
+                .with_schema(json!([{
+                    "name": "item",
+                    "data_type": "Dictionary",
+                    "children": [
+                        {"name": "key", "data_type": index_ty},
+                        {"name": "value", "data_type": value_ty},
+                    ]
+                }]))
+                .serialize(&items)
+                .also(|it| {
+                    let arrays = it.arrays.arrow.as_ref().unwrap();
+                    assert_eq!(arrays[0].to_data().child_data()[0].len(), 2);
+                })
+                .deserialize(&items);
+        }
+    }
+}
+
+#[test]
+fn unsigned_integer_values_nullable() {
+    let items = [Item(Some(42_u64)), Item(None), Item(Some(42_u64))];
+
+    for value_ty in ["U8", "U16", "U32", "U64"] {
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "nullable": true,
+                "children": [
+                    {"name": "key", "data_type": "U16"},
+                    {"name": "value", "data_type": value_ty},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items)
+            .check_nulls(&[&[false, true, false]]);
+    }
+}
+
+#[test]
+fn float_values() {
+    let items = [Item(0.5_f32), Item(1.25_f32), Item(0.5_f32)];
+
+    for value_ty in ["F16", "F32", "F64"] {
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "U8"},
+                    {"name": "value", "data_type": value_ty},
+                ]
+            }]))
+            .serialize(&items)
+            .deserialize(&items);
+    }
+}
+
+#[test]
+fn binary_values() {
+    let items = [
+        Item(ByteBuf::from(b"foo".to_vec())),
+        Item(ByteBuf::from(b"bar".to_vec())),
+        Item(ByteBuf::from(b"foo".to_vec())),
+    ];
+
+    for value_ty in ["Binary", "LargeBinary"] {
+        Test::new()
+            .with_schema(json!([{
+                "name": "item",
+                "data_type": "Dictionary",
+                "children": [
+                    {"name": "key", "data_type": "I32"},
+                    {"name": "value", "data_type": value_ty},
+                ]
+            }]))
+            .serialize(&items)
+            .also(|it| {
+                let arrays = it.arrays.arrow.as_ref().unwrap();
+                assert_eq!(arrays[0].to_data().child_data()[0].len(), 2);
+            })
+            .deserialize(&items);
+    }
+}
+
+#[test]
+fn nullable_values() {
+    use crate::_impl::{arrow, arrow2};
+
+    // index 1 references a null value
+    let keys = [0_i32, 1, 2, 1];
+    let expected = [
+        Item(Some(String::from("a"))),
+        Item(None),
+        Item(Some(String::from("b"))),
+        Item(None),
+    ];
+
+    {
+        use arrow::{
+            array::{make_array, Array, ArrayData, StringArray},
+            buffer::Buffer,
+            datatypes::{DataType, Field},
+        };
+
+        let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let values = StringArray::from(vec![Some("a"), None, Some("b")]);
+        let data = ArrayData::builder(data_type.clone())
+            .len(keys.len())
+            .add_buffer(Buffer::from_slice_ref(keys))
+            .add_child_data(values.to_data())
+            .build()
+            .unwrap();
+
+        let fields = [Field::new("item", data_type, true)];
+        let arrays = [make_array(data)];
+
+        let actual: Vec<Item<Option<String>>> = crate::from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    {
+        use arrow2::{
+            array::{DictionaryArray, PrimitiveArray, Utf8Array},
+            datatypes::{DataType, Field, IntegerType},
+        };
+
+        let data_type = DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false);
+        let values = Utf8Array::<i32>::from([Some("a"), None, Some("b")]);
+        let array = DictionaryArray::try_new(
+            data_type.clone(),
+            PrimitiveArray::from_slice(keys),
+            values.boxed(),
+        )
+        .unwrap();
+
+        let fields = [Field::new("item", data_type, true)];
+        let arrays = [array.boxed()];
+
+        let actual: Vec<Item<Option<String>>> = crate::from_arrow2(&fields, &arrays).unwrap();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+fn nullable_values_with_null_keys() {
+    use crate::_impl::arrow::{
+        array::{make_array, Array, ArrayData, PrimitiveArray},
+        buffer::Buffer,
+        datatypes::{DataType, Field, Int64Type},
+    };
+
+    let data_type = DataType::Dictionary(Box::new(DataType::UInt8), Box::new(DataType::Int64));
+    let values = PrimitiveArray::<Int64Type>::from(vec![None, Some(42)]);
+    let data = ArrayData::builder(data_type.clone())
+        .len(4)
+        .add_buffer(Buffer::from_slice_ref([1_u8, 0, 0, 1]))
+        // the third key is null
+        .null_bit_buffer(Some(Buffer::from_slice_ref([0b1011_u8])))
+        .add_child_data(values.to_data())
+        .build()
+        .unwrap();
+
+    let fields = [Field::new("item", data_type, true)];
+    let arrays = [make_array(data)];
+
+    let actual: Vec<Item<Option<i64>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(
+        actual,
+        [Item(Some(42)), Item(None), Item(None), Item(Some(42))]
+    );
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use serde_json::json;
+
+use crate::utils::Item;
+
+use super::utils::Test;
+
+#[test]
+fn std_duration_as_duration_second() {
+    let items = [
+        Item(Duration::from_secs(13)),
+        Item(Duration::from_secs(60 * 60 * 24)),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Second)",
+            "strategy": "SecsNanosAsDuration",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn std_duration_as_duration_millisecond() {
+    let items = [
+        Item(Duration::from_millis(1_500)),
+        Item(Duration::from_millis(42)),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Millisecond)",
+            "strategy": "SecsNanosAsDuration",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn std_duration_as_duration_microsecond() {
+    let items = [
+        Item(Duration::from_micros(1_000_001)),
+        Item(Duration::from_micros(7)),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Microsecond)",
+            "strategy": "SecsNanosAsDuration",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn std_duration_as_duration_nanosecond_nullable() {
+    let items = [
+        Item(Some(Duration::new(3, 141_592_653))),
+        Item(None),
+        Item(Some(Duration::from_nanos(1))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Nanosecond)",
+            "strategy": "SecsNanosAsDuration",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn integers_as_duration() {
+    let items = [Item(1_500_i64), Item(-20_i64)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Duration(Millisecond)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn std_duration_is_truncated_to_unit() {
+    let items = [Item(Duration::from_millis(1_500))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Duration(Second)",
+            "strategy": "SecsNanosAsDuration",
+        }]))
+        .serialize(&items)
+        .deserialize(&[Item(Duration::from_secs(1))]);
+}
@@ -61,9 +61,13 @@ test_example!(
             )
         )
         .with_child(
-            GenericField::new("float", GenericDataType::Union, false)
-                .with_child(GenericField::new("F32", GenericDataType::F32, false))
-                .with_child(GenericField::new("F64", GenericDataType::F64, false))
+            GenericField::new(
+                "float",
+                GenericDataType::Union(GenericUnionMode::Dense),
+                false
+            )
+            .with_child(GenericField::new("F32", GenericDataType::F32, false))
+            .with_child(GenericField::new("F64", GenericDataType::F64, false))
         ),
     ty = Item,
     values = [
@@ -227,10 +231,14 @@ test_example!(
     field = GenericField::new("item", GenericDataType::Struct, false)
         .with_child(GenericField::new("foo", GenericDataType::U32, false))
         .with_child(
-            GenericField::new("bar", GenericDataType::Union, false)
-                .with_child(GenericField::new("A", GenericDataType::Null, true))
-                .with_child(GenericField::new("B", GenericDataType::Null, true))
-                .with_child(GenericField::new("C", GenericDataType::Null, true))
+            GenericField::new(
+                "bar",
+                GenericDataType::Union(GenericUnionMode::Dense),
+                false
+            )
+            .with_child(GenericField::new("A", GenericDataType::Null, true))
+            .with_child(GenericField::new("B", GenericDataType::Null, true))
+            .with_child(GenericField::new("C", GenericDataType::Null, true))
         )
         .with_child(GenericField::new("baz", GenericDataType::F32, false)),
     ty = S,
@@ -285,24 +293,32 @@ test_example!(
             false
         ))
         .with_child(
-            GenericField::new("game_type", GenericDataType::Union, false)
-                .with_child(
-                    GenericField::new("", GenericDataType::Null, true)
-                        .with_strategy(Strategy::UnknownVariant)
-                )
-                .with_child(GenericField::new(
-                    "RegularSeason",
-                    GenericDataType::Null,
-                    true
-                ))
+            GenericField::new(
+                "game_type",
+                GenericDataType::Union(GenericUnionMode::Dense),
+                false
+            )
+            .with_child(
+                GenericField::new("", GenericDataType::Null, true)
+                    .with_strategy(Strategy::UnknownVariant)
+            )
+            .with_child(GenericField::new(
+                "RegularSeason",
+                GenericDataType::Null,
+                true
+            ))
         )
         .with_child(
-            GenericField::new("account_type", GenericDataType::Union, false)
-                .with_child(
-                    GenericField::new("", GenericDataType::Null, true)
-                        .with_strategy(Strategy::UnknownVariant)
-                )
-                .with_child(GenericField::new("Deduced", GenericDataType::Null, true))
+            GenericField::new(
+                "account_type",
+                GenericDataType::Union(GenericUnionMode::Dense),
+                false
+            )
+            .with_child(
+                GenericField::new("", GenericDataType::Null, true)
+                    .with_strategy(Strategy::UnknownVariant)
+            )
+            .with_child(GenericField::new("Deduced", GenericDataType::Null, true))
         )
         .with_child(GenericField::new("file_index", GenericDataType::U64, false)),
     ty = FileInfo,
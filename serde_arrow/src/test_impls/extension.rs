@@ -0,0 +1,289 @@
+use serde_json::json;
+
+use crate::{
+    _impl::{arrow, arrow2},
+    internal::schema::{GenericDataType, GenericField},
+    schema::{
+        register_extension_strategy, SchemaLike, SerdeArrowSchema, Strategy,
+        EXTENSION_METADATA_KEY, EXTENSION_NAME_KEY, STRATEGY_KEY,
+    },
+    utils::Item,
+};
+
+use super::utils::{ResultAsserts, Test};
+
+#[test]
+fn uuid_strings() {
+    let items = [
+        Item(Some(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"))),
+        Item(None),
+        Item(Some(String::from("00000000-0000-0000-0000-000000000000"))),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeBinary(16)",
+            "strategy": "UuidStrAsFixedSizeBinary",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn uuid_strings_in_simple_format() {
+    use arrow::array::{Array, FixedSizeBinaryArray};
+
+    let items = [
+        Item("67e5504410b1426f9247bb680e5fe0c8"),
+        Item("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+    ];
+    let expected = [
+        0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0,
+        0xc8,
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeBinary(16)",
+            "strategy": "UuidStrAsFixedSizeBinary",
+        }]))
+        .serialize(&items)
+        .deserialize(&[
+            Item(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+            Item(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8")),
+        ])
+        .also(|test| {
+            let arrays = test.arrays.arrow.as_ref().unwrap();
+            let array = arrays[0]
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            assert_eq!(array.len(), 2);
+            assert_eq!(array.value(0), expected);
+            assert_eq!(array.value(1), expected);
+        });
+}
+
+#[test]
+fn invalid_uuid_strings() {
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "FixedSizeBinary(16)",
+        "strategy": "UuidStrAsFixedSizeBinary",
+    }]));
+
+    for item in [
+        "67e55044-10b1-426f-9247-bb680e5fe0c",
+        "67e55044_10b1_426f_9247_bb680e5fe0c8",
+        "67e55044-10b1-426f-9247-bb680e5fe0cx",
+    ] {
+        test.try_serialize_arrow(&[Item(item)])
+            .assert_error("Invalid UUID");
+        test.try_serialize_arrow2(&[Item(item)])
+            .assert_error("Invalid UUID");
+    }
+}
+
+#[test]
+fn invalid_fields() {
+    let mut field = GenericField::new("item", GenericDataType::LargeUtf8, false);
+    field.extension_metadata = Some(String::from("{}"));
+    field
+        .validate()
+        .assert_error("extension metadata requires an extension name");
+
+    GenericField::new("item", GenericDataType::FixedSizeBinary(4), false)
+        .with_strategy(Strategy::UuidStrAsFixedSizeBinary)
+        .validate()
+        .assert_error("requires FixedSizeBinary(16)");
+}
+
+#[test]
+fn extension_types_are_kept_in_arrow_fields() {
+    let schema = SerdeArrowSchema::from_value(&json!([{
+        "name": "item",
+        "data_type": "LargeUtf8",
+        "extension_name": "arrow.json",
+        "extension_metadata": "{}",
+    }]))
+    .unwrap();
+
+    let fields = schema.to_arrow_fields().unwrap();
+    let metadata = fields[0].metadata();
+    assert_eq!(metadata.get(EXTENSION_NAME_KEY).unwrap(), "arrow.json");
+    assert_eq!(metadata.get(EXTENSION_METADATA_KEY).unwrap(), "{}");
+    assert_eq!(metadata.get(STRATEGY_KEY), None);
+
+    let round_trip = SerdeArrowSchema::from_arrow_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+}
+
+#[test]
+fn extension_types_are_kept_in_arrow2_fields() {
+    use arrow2::datatypes::DataType;
+
+    let schema = SerdeArrowSchema::from_value(&json!([{
+        "name": "item",
+        "data_type": "LargeUtf8",
+        "extension_name": "arrow.json",
+        "extension_metadata": "{}",
+    }]))
+    .unwrap();
+
+    let fields = schema.to_arrow2_fields().unwrap();
+    let DataType::Extension(name, data_type, metadata) = &fields[0].data_type else {
+        panic!(
+            "expected an extension type, found {:?}",
+            fields[0].data_type
+        );
+    };
+    assert_eq!(name, "arrow.json");
+    assert_eq!(data_type.as_ref(), &DataType::LargeUtf8);
+    assert_eq!(metadata.as_deref(), Some("{}"));
+
+    let round_trip = SerdeArrowSchema::from_arrow2_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+}
+
+#[test]
+fn json_extension_columns() {
+    let items = [
+        Item(String::from(r#"{"a": 1}"#)),
+        Item(String::from("[1, 2, 3]")),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "LargeUtf8",
+            "extension_name": "arrow.json",
+        }]))
+        .skip_arrow2()
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn arrow2_arrays_use_the_storage_type() {
+    use arrow2::{
+        array::{Array, Utf8Array},
+        datatypes::DataType,
+    };
+
+    let schema = SerdeArrowSchema::from_value(&json!([{
+        "name": "item",
+        "data_type": "LargeUtf8",
+        "extension_name": "arrow.json",
+    }]))
+    .unwrap();
+    let fields = schema.to_arrow2_fields().unwrap();
+
+    let items = [
+        Item(String::from(r#"{"a": 1}"#)),
+        Item(String::from("null")),
+    ];
+    let arrays = crate::to_arrow2(&fields, &items).unwrap();
+    assert_eq!(arrays[0].data_type(), &DataType::LargeUtf8);
+
+    let round_trip: Vec<Item<String>> = crate::from_arrow2(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items);
+
+    let arrays: Vec<Box<dyn Array>> = vec![Box::new(Utf8Array::<i64>::new(
+        fields[0].data_type.clone(),
+        vec![0, 8, 12].try_into().unwrap(),
+        br#"{"a": 1}null"#.to_vec().into(),
+        None,
+    ))];
+    let round_trip: Vec<Item<String>> = crate::from_arrow2(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items);
+}
+
+#[test]
+fn registered_extension_strategies() {
+    use std::collections::HashMap;
+
+    use arrow::datatypes::{DataType, Field};
+
+    register_extension_strategy("serde_arrow.test.uuid", Strategy::UuidStrAsFixedSizeBinary);
+
+    let field =
+        Field::new("item", DataType::FixedSizeBinary(16), false).with_metadata(HashMap::from([(
+            EXTENSION_NAME_KEY.to_string(),
+            String::from("serde_arrow.test.uuid"),
+        )]));
+
+    let generic_field = GenericField::try_from(&field).unwrap();
+    assert_eq!(
+        generic_field.strategy,
+        Some(Strategy::UuidStrAsFixedSizeBinary)
+    );
+
+    let round_trip = Field::try_from(&generic_field).unwrap();
+    assert_eq!(
+        round_trip.metadata().get(STRATEGY_KEY).unwrap(),
+        "UuidStrAsFixedSizeBinary"
+    );
+    assert_eq!(GenericField::try_from(&round_trip).unwrap(), generic_field);
+
+    let items = [Item(String::from("67e55044-10b1-426f-9247-bb680e5fe0c8"))];
+    let fields = vec![field];
+    let arrays = crate::to_arrow(&fields, &items).unwrap();
+    let round_trip: Vec<Item<String>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items);
+}
+
+#[test]
+fn explicit_strategies_take_precedence_over_registered_ones() {
+    use arrow::datatypes::{DataType, Field};
+
+    register_extension_strategy("serde_arrow.test.bytes", Strategy::UuidStrAsFixedSizeBinary);
+
+    let field = GenericField::new("item", GenericDataType::Null, true)
+        .with_strategy(Strategy::UnknownVariant)
+        .with_extension("serde_arrow.test.bytes", None);
+
+    let arrow_field = Field::try_from(&field).unwrap();
+    assert_eq!(arrow_field.data_type(), &DataType::Null);
+    assert_eq!(
+        arrow_field.metadata().get(STRATEGY_KEY).unwrap(),
+        "UnknownVariant"
+    );
+    assert_eq!(GenericField::try_from(&arrow_field).unwrap(), field);
+}
+
+#[test]
+fn registered_strategies_are_stored_in_the_metadata() {
+    register_extension_strategy(
+        "serde_arrow.test.stored",
+        Strategy::UuidStrAsFixedSizeBinary,
+    );
+
+    let schema = SerdeArrowSchema::from_value(&json!([{
+        "name": "item",
+        "data_type": "FixedSizeBinary(16)",
+        "strategy": "UuidStrAsFixedSizeBinary",
+        "extension_name": "serde_arrow.test.stored",
+    }]))
+    .unwrap();
+
+    let fields = schema.to_arrow_fields().unwrap();
+    assert_eq!(
+        fields[0].metadata().get(STRATEGY_KEY).unwrap(),
+        "UuidStrAsFixedSizeBinary"
+    );
+    let round_trip = SerdeArrowSchema::from_arrow_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+
+    let fields = schema.to_arrow2_fields().unwrap();
+    assert_eq!(
+        fields[0].metadata.get(STRATEGY_KEY).unwrap(),
+        "UuidStrAsFixedSizeBinary"
+    );
+    let round_trip = SerdeArrowSchema::from_arrow2_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+}
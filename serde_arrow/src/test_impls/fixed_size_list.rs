@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{schema::TracingOptions, utils::Item};
+
+use super::utils::Test;
+
+#[test]
+fn f32_arrays() {
+    let items = [Item([1.0_f32, 2.0, 3.0]), Item([4.0, 5.0, 6.0])];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(3)",
+            "children": [{"name": "element", "data_type": "F32"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn vecs() {
+    let items = [Item(vec![1_u32, 2]), Item(vec![3, 4]), Item(vec![5, 6])];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "children": [{"name": "element", "data_type": "U32"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false]]);
+}
+
+#[test]
+fn nullable_arrays() {
+    let items = [
+        Item(Some([1_i64, 2])),
+        Item(None),
+        Item(None),
+        Item(Some([3, 4])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "nullable": true,
+            "children": [{"name": "element", "data_type": "I64"}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, true, false]]);
+}
+
+#[test]
+fn nullable_elements() {
+    let items = [Item([Some(1_u8), None]), Item([None, Some(2)])];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "children": [{"name": "element", "data_type": "U8", "nullable": true}],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[test]
+fn nullable_struct_elements() {
+    let items = [
+        Item(Some([Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }])),
+        Item(None),
+        Item(Some([Point { x: 5.0, y: 6.0 }, Point { x: 7.0, y: 8.0 }])),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(2)",
+            "nullable": true,
+            "children": [{
+                "name": "element",
+                "data_type": "Struct",
+                "children": [
+                    {"name": "x", "data_type": "F64"},
+                    {"name": "y", "data_type": "F64"},
+                ],
+            }],
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn invalid_number_of_elements() {
+    let items = [Item(vec![1_u32, 2]), Item(vec![3])];
+
+    let mut test = Test::new().with_schema(json!([{
+        "name": "item",
+        "data_type": "FixedSizeList(2)",
+        "children": [{"name": "element", "data_type": "U32"}],
+    }]));
+
+    let err = test
+        .try_serialize_arrow(&items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("Invalid number of elements"));
+
+    let err = test
+        .try_serialize_arrow2(&items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("Invalid number of elements"));
+}
+
+#[test]
+fn trace_homogeneous_tuples() {
+    let items = [Item([1.0_f32, 2.0, 3.0]), Item([4.0, 5.0, 6.0])];
+    let options = TracingOptions::default().tuples_as_fixed_size_lists(true);
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "FixedSizeList(3)",
+            "children": [{"name": "element", "data_type": "F32"}],
+        }]))
+        .trace_schema_from_samples(&items, options.clone())
+        .trace_schema_from_type::<Item<[f32; 3]>>(options)
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn trace_heterogeneous_tuples() {
+    let items = [Item((1_u8, 2.0_f32)), Item((3, 4.0))];
+    let options = TracingOptions::default().tuples_as_fixed_size_lists(true);
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Struct",
+            "strategy": "TupleAsStruct",
+            "children": [
+                {"name": "0", "data_type": "U8"},
+                {"name": "1", "data_type": "F32"},
+            ],
+        }]))
+        .trace_schema_from_samples(&items, options.clone())
+        .trace_schema_from_type::<Item<(u8, f32)>>(options)
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
@@ -0,0 +1,122 @@
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{
+    schema::{SchemaLike, SerdeArrowSchema, TracingOptions},
+    utils::Item,
+};
+
+use super::utils::{ResultAsserts, Test};
+
+#[test]
+fn i128_as_decimal() {
+    let items = [
+        Item(0_i128),
+        Item(-1),
+        Item(12_345_678_901_234_567_890_123_456_789),
+        Item(-99_999_999_999_999_999_999_999_999_999_999_999_999),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)"}]))
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .trace_schema_from_type::<Item<i128>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false, false]]);
+}
+
+#[test]
+fn nullable_u128_as_decimal() {
+    let items = [
+        Item(Some(1_u128)),
+        Item(None),
+        Item(Some(99_999_999_999_999_999_999_999_999_999_999_999_999)),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal128(38, 0)",
+            "nullable": true,
+        }]))
+        .trace_schema_from_samples(&items, TracingOptions::default())
+        .trace_schema_from_type::<Item<Option<u128>>>(TracingOptions::default())
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn int128_as_fixed_size_binary() {
+    let items = [Item(u128::MAX), Item(0), Item(1 << 100)];
+    let options = TracingOptions::default().int128_as_fixed_size_binary(true);
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(16)"}]))
+        .trace_schema_from_samples(&items, options.clone())
+        .trace_schema_from_type::<Item<u128>>(options)
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false]]);
+
+    let items = [Item(i128::MIN), Item(-1), Item(i128::MAX)];
+    let options = TracingOptions::default().int128_as_fixed_size_binary(true);
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "FixedSizeBinary(16)"}]))
+        .trace_schema_from_samples(&items, options.clone())
+        .trace_schema_from_type::<Item<i128>>(options)
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false, false]]);
+}
+
+#[test]
+fn int128_in_scaled_decimals() {
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(5, 2)"}]))
+        .serialize(&[Item(12_i128), Item(-999)])
+        .deserialize(&[Item(String::from("12.00")), Item(String::from("-999.00"))])
+        .check_nulls(&[&[false, false]]);
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal128(5, -2)"}]))
+        .skip_arrow2()
+        .serialize(&[Item(1_200_i128), Item(-9_999_900)])
+        .deserialize(&[Item(String::from("1200")), Item(String::from("-9999900"))])
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn int128_out_of_decimal_range() {
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal128(38, 0)"}]));
+
+    test.try_serialize_arrow(&[Item(u128::MAX)])
+        .assert_error("exceeds the range of Decimal128(38, 0)");
+    test.try_serialize_arrow2(&[Item(i128::MIN)])
+        .assert_error("exceeds the range of Decimal128(38, 0)");
+
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal128(5, 2)"}]));
+
+    test.try_serialize_arrow(&[Item(1_000_i128)])
+        .assert_error("exceeds the range of Decimal128(5, 2)");
+    test.try_serialize_arrow2(&[Item(-1_000_i128)])
+        .assert_error("exceeds the range of Decimal128(5, 2)");
+}
+
+#[test]
+fn int128_is_not_coerced_with_other_integers() {
+    #[derive(Serialize)]
+    #[serde(untagged)]
+    enum Number {
+        Small(i64),
+        Large(i128),
+    }
+
+    let items = [Item(Number::Small(1)), Item(Number::Large(2))];
+    SerdeArrowSchema::from_samples(&items, TracingOptions::default().coerce_numbers(true))
+        .assert_error("Cannot accept event");
+}
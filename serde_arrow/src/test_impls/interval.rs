@@ -0,0 +1,144 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::utils::Item;
+
+use super::utils::Test;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct DayTime {
+    days: i32,
+    millis: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MonthDayNano {
+    months: i32,
+    days: i32,
+    nanos: i64,
+}
+
+#[test]
+fn year_month() {
+    let items = [Item(13_i32), Item(-2_i32)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(YearMonth)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn year_month_nullable() {
+    let items = [Item(Some(13_i32)), Item(None), Item(Some(0))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Interval(YearMonth)",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn day_time() {
+    let items = [
+        Item(DayTime {
+            days: 1,
+            millis: 500,
+        }),
+        Item(DayTime {
+            days: -3,
+            millis: -20,
+        }),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(DayTime)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn day_time_tuple() {
+    let items = [Item((1_i32, 500_i32)), Item((-3, -20))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Interval(DayTime)",
+            "strategy": "TupleAsStruct",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn month_day_nano() {
+    let items = [
+        Item(MonthDayNano {
+            months: 1,
+            days: 2,
+            nanos: 3_000_000_000_000,
+        }),
+        Item(MonthDayNano {
+            months: -4,
+            days: 5,
+            nanos: -6,
+        }),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Interval(MonthDayNano)"}]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn month_day_nano_nullable() {
+    let items = [
+        Item(Some(MonthDayNano {
+            months: 1,
+            days: 2,
+            nanos: 3,
+        })),
+        Item(None),
+        Item(Some(MonthDayNano {
+            months: -1,
+            days: -2,
+            nanos: -3,
+        })),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Interval(MonthDayNano)",
+            "nullable": true,
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn month_day_nano_tuple() {
+    let items = [Item((1_i32, 2_i32, 3_i64)), Item((-1, i32::MAX, i64::MIN))];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Interval(MonthDayNano)",
+            "strategy": "TupleAsStruct",
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
@@ -131,3 +131,112 @@ fn bigdecimal_too_small_precision() {
         .expect_err("Expected error");
     assert!(err.to_string().contains("not enough precision"));
 }
+
+fn get_i256_values(test: &Test) -> &[arrow::datatypes::i256] {
+    let arrays = test.arrays.arrow.as_ref().unwrap();
+    let arr = arrays[0]
+        .as_any()
+        .downcast_ref::<arrow::array::PrimitiveArray<arrow::datatypes::Decimal256Type>>()
+        .unwrap();
+    arr.values()
+}
+
+#[test]
+fn bigdecimal_decimal256() {
+    let items = &[
+        Item(BigDecimal::from_str("0.20").unwrap()),
+        Item(BigDecimal::from_str("-0.42").unwrap()),
+        Item(
+            BigDecimal::from_str("123456789012345678901234567890123456789012345678901234.56")
+                .unwrap(),
+        ),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal256(60, 2)"}]))
+        .serialize(items)
+        .also(|it| {
+            assert_eq!(
+                get_i256_values(it),
+                &[
+                    arrow::datatypes::i256::from_i128(20),
+                    arrow::datatypes::i256::from_i128(-42),
+                    arrow::datatypes::i256::from_string(
+                        "12345678901234567890123456789012345678901234567890123456"
+                    )
+                    .unwrap(),
+                ]
+            )
+        })
+        .deserialize(items);
+}
+
+#[test]
+fn str_decimal256() {
+    let items = &[
+        Item(String::from("-1.5")),
+        Item(String::from("0.0")),
+        Item(String::from(
+            "9999999999999999999999999999999999999999999999999999999999999999999999999.9",
+        )),
+    ];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal256(76, 1)"}]))
+        .serialize(items)
+        .deserialize(items);
+}
+
+#[test]
+fn float_decimal256() {
+    let items = &[Item(0.25_f64), Item(-13.5_f64), Item(1e50_f64)];
+
+    Test::new()
+        .with_schema(json!([{"name": "item", "data_type": "Decimal256(76, 2)"}]))
+        .serialize(items)
+        .also(|it| {
+            assert_eq!(
+                get_i256_values(it),
+                &[
+                    arrow::datatypes::i256::from_i128(25),
+                    arrow::datatypes::i256::from_i128(-1350),
+                    arrow::datatypes::i256::from_string(&format!("1{}", "0".repeat(52))).unwrap(),
+                ]
+            )
+        });
+}
+
+#[test]
+fn bigdecimal_decimal256_nullable() {
+    let items = &[
+        Item(Some(BigDecimal::from_str("1.23").unwrap())),
+        Item(None),
+        Item(Some(BigDecimal::from_str("-4.56").unwrap())),
+    ];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "Decimal256(40, 2)",
+            "nullable": true,
+        }]))
+        .serialize(items)
+        .deserialize(items)
+        .check_nulls(&[&[false, true, false]]);
+}
+
+#[test]
+fn bigdecimal_decimal256_too_small_precision() {
+    let items = &[Item(BigDecimal::from_str("1.23").unwrap())];
+
+    let mut test =
+        Test::new().with_schema(json!([{"name": "item", "data_type": "Decimal256(2, 2)"}]));
+
+    let err = test.try_serialize_arrow(items).expect_err("Expected error");
+    assert!(err.to_string().contains("not enough precision"));
+
+    let err = test
+        .try_serialize_arrow2(items)
+        .expect_err("Expected error");
+    assert!(err.to_string().contains("not enough precision"));
+}
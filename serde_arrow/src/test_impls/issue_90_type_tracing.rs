@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use super::macros::expect_error;
 use crate::internal::{
     generic::{Item, Items},
-    schema::{GenericDataType as T, GenericField as F, Strategy},
+    schema::{GenericDataType as T, GenericField as F, GenericUnionMode, Strategy},
     tracing::{Tracer, TracingOptions},
 };
 
@@ -144,13 +144,31 @@ fn trace_union() {
     }
 
     let actual = trace_type::<Example>(TracingOptions::default());
-    let expected = F::new("item", T::Union, false)
+    let expected = F::new("item", T::Union(GenericUnionMode::Dense), false)
         .with_child(F::new("A", T::I8, false))
         .with_child(F::new("B", T::F32, false));
 
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn trace_nullable_union() {
+    #[allow(dead_code)]
+    #[derive(Deserialize)]
+    enum Example {
+        A(i8),
+        B,
+    }
+
+    let actual = trace_type::<Option<Example>>(TracingOptions::default().allow_null_fields(true));
+    let expected = F::new("item", T::Union(GenericUnionMode::Dense), true)
+        .with_child(F::new("A", T::I8, false))
+        .with_child(F::new("B", T::Null, true))
+        .with_child(F::new("null", T::Null, true).with_strategy(Strategy::NullVariant));
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn trace_list() {
     let actual = trace_type::<Vec<String>>(TracingOptions::default());
@@ -300,7 +318,7 @@ mod mixed_tracing_unions {
         let schema = tracer.to_schema().unwrap();
 
         let actual = schema.fields.into_iter().next().unwrap();
-        let expected = F::new("item", T::Union, false)
+        let expected = F::new("item", T::Union(GenericUnionMode::Dense), false)
             .with_child(F::new("A", T::Null, true))
             .with_child(F::new("B", T::Null, true))
             .with_child(F::new("C", T::U32, false));
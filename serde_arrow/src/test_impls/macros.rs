@@ -51,7 +51,9 @@ macro_rules! test_example_impl {
             schema::{TracingOptions, Strategy},
             utils::{Items, Item},
         };
-        use crate::internal::schema::{GenericDataType, GenericField, GenericTimeUnit, SchemaLike};
+        use crate::internal::schema::{
+            GenericDataType, GenericField, GenericTimeUnit, GenericUnionMode, SchemaLike,
+        };
         use crate::test_impls::macros::{btree_map, hash_map};
 
         $(#[ignore = $ignore])?
@@ -357,7 +359,7 @@ macro_rules! test_generic {
                 schema::{SchemaLike, TracingOptions},
                 utils::{Items, Item}
             };
-            use crate::internal::schema::{GenericField, GenericDataType};
+            use crate::internal::schema::{GenericField, GenericDataType, GenericUnionMode};
 
             mod arrow {
                 use super::*;
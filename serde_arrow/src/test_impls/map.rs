@@ -1,4 +1,4 @@
-use super::macros::test_example;
+use super::macros::{test_example, test_generic};
 
 // NOTE: Use BTreeMap to guarantee the order of fields
 
@@ -245,6 +245,155 @@ test_example!(
     ],
 );
 
+test_example!(
+    test_name = sorted_btree_maps,
+    tracing_options = TracingOptions::new().map_as_struct(false),
+    field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(GenericField::new("entries", GenericDataType::Struct, false)
+            .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+            .with_child(GenericField::new("value", GenericDataType::Bool, false))),
+    overwrite_field = GenericField {
+        sorted: true,
+        ..GenericField::new("item", GenericDataType::Map, false)
+            .with_child(GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(GenericField::new("value", GenericDataType::Bool, false)))
+    },
+    ty = BTreeMap<String, bool>,
+    values = [
+        btree_map!{"a" => true, "c" => false, "b" => true},
+        btree_map!{"d" => false},
+        btree_map!{},
+    ],
+);
+
+test_example!(
+    test_name = sorted_hash_maps,
+    tracing_options = TracingOptions::new().map_as_struct(false),
+    field = GenericField::new("item", GenericDataType::Map, false)
+        .with_child(GenericField::new("entries", GenericDataType::Struct, false)
+            .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+            .with_child(GenericField::new("value", GenericDataType::Bool, false))),
+    overwrite_field = GenericField {
+        sorted: true,
+        ..GenericField::new("item", GenericDataType::Map, false)
+            .with_child(GenericField::new("entries", GenericDataType::Struct, false)
+                .with_child(GenericField::new("key", GenericDataType::LargeUtf8, false))
+                .with_child(GenericField::new("value", GenericDataType::Bool, false)))
+    },
+    ty = HashMap<String, bool>,
+    values = [
+        hash_map!{"c" => true, "a" => false, "d" => true, "b" => false},
+        hash_map!{"d" => false},
+        hash_map!{},
+    ],
+);
+
+test_generic!(
+    fn sorted_maps_sort_their_entries() {
+        use serde::{
+            de::{MapAccess, Visitor},
+            Deserialize, Deserializer,
+        };
+
+        let field = GenericField {
+            sorted: true,
+            ..GenericField::new("item", GenericDataType::Map, false).with_child(
+                GenericField::new("entries", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("key", GenericDataType::I64, false))
+                    .with_child(GenericField::new("value", GenericDataType::Bool, false)),
+            )
+        };
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        // (de)serialize the entries as a map in the given order
+        #[derive(Debug, PartialEq)]
+        struct Entries(Vec<(i64, bool)>);
+
+        impl serde::Serialize for Entries {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                use serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(self.0.len()))?;
+                for (key, value) in &self.0 {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Entries {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct EntriesVisitor;
+
+                impl<'de> Visitor<'de> for EntriesVisitor {
+                    type Value = Entries;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                        write!(f, "a map")
+                    }
+
+                    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Entries, A::Error> {
+                        let mut entries = Vec::new();
+                        while let Some(entry) = map.next_entry()? {
+                            entries.push(entry);
+                        }
+                        Ok(Entries(entries))
+                    }
+                }
+
+                deserializer.deserialize_map(EntriesVisitor)
+            }
+        }
+
+        let items = [
+            Entries(vec![(2, true), (0, false), (1, true)]),
+            Entries(vec![]),
+            Entries(vec![(1, false), (0, true)]),
+        ];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(round_trip): Items<Vec<Entries>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(
+            round_trip,
+            [
+                Entries(vec![(0, false), (1, true), (2, true)]),
+                Entries(vec![]),
+                Entries(vec![(0, true), (1, false)]),
+            ]
+        );
+
+        let res = to_arrow(&fields, &Items(&[Entries(vec![(0, true), (0, false)])]));
+        crate::test_impls::macros::expect_error(&res, "must be unique");
+    }
+);
+
+test_generic!(
+    fn sorted_flag_is_kept_in_schema() {
+        let field = GenericField {
+            sorted: true,
+            ..GenericField::new("item", GenericDataType::Map, false).with_child(
+                GenericField::new("entries", GenericDataType::Struct, false)
+                    .with_child(GenericField::new("key", GenericDataType::I64, false))
+                    .with_child(GenericField::new("value", GenericDataType::Bool, false)),
+            )
+        };
+        let arrow_field = Field::try_from(&field).unwrap();
+        let round_trip = GenericField::try_from(&arrow_field).unwrap();
+        assert_eq!(round_trip, field);
+    }
+);
+
+#[test]
+fn sorted_is_only_supported_for_maps() {
+    use crate::internal::schema::{GenericDataType, GenericField};
+
+    let field = GenericField {
+        sorted: true,
+        ..GenericField::new("item", GenericDataType::U8, false)
+    };
+    super::macros::expect_error(&field.validate(), "only supported for Map fields");
+}
+
 // TODO: re-create this test with a custom impl?
 /*
 test_events!(
@@ -0,0 +1,145 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::json;
+
+use crate::{
+    _impl::{arrow, arrow2},
+    internal::schema::{GenericDataType, GenericField},
+    schema::{SchemaLike, SerdeArrowSchema, Strategy, STRATEGY_KEY},
+    utils::Item,
+};
+
+use super::utils::{ResultAsserts, Test};
+
+fn example_schema() -> SerdeArrowSchema {
+    SerdeArrowSchema::from_value(&json!([
+        {
+            "name": "distance",
+            "data_type": "F64",
+            "metadata": {"description": "the distance travelled", "unit": "km"},
+        },
+        {
+            "name": "date",
+            "data_type": "Date64",
+            "strategy": "NaiveStrAsDate64",
+            "metadata": {"description": "the date of the trip"},
+        },
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn metadata_is_parsed_from_values() {
+    let schema = example_schema();
+
+    let expected = GenericField::new("distance", GenericDataType::F64, false)
+        .with_metadata("description", "the distance travelled")
+        .with_metadata("unit", "km");
+    assert_eq!(schema.fields[0], expected);
+    assert_eq!(schema.fields[1].strategy, Some(Strategy::NaiveStrAsDate64));
+}
+
+#[test]
+fn metadata_round_trips_through_arrow_fields() {
+    let schema = example_schema();
+    let fields = schema.to_arrow_fields().unwrap();
+
+    assert_eq!(
+        fields[0].metadata(),
+        &HashMap::from([
+            (
+                String::from("description"),
+                String::from("the distance travelled")
+            ),
+            (String::from("unit"), String::from("km")),
+        ])
+    );
+    assert_eq!(
+        fields[1].metadata(),
+        &HashMap::from([
+            (
+                String::from("description"),
+                String::from("the date of the trip")
+            ),
+            (STRATEGY_KEY.to_string(), String::from("NaiveStrAsDate64")),
+        ])
+    );
+
+    let round_trip = SerdeArrowSchema::from_arrow_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+}
+
+#[test]
+fn metadata_round_trips_through_arrow2_fields() {
+    let schema = example_schema();
+    let fields = schema.to_arrow2_fields().unwrap();
+
+    assert_eq!(
+        fields[0].metadata,
+        BTreeMap::from([
+            (
+                String::from("description"),
+                String::from("the distance travelled")
+            ),
+            (String::from("unit"), String::from("km")),
+        ])
+    );
+    assert_eq!(
+        fields[1].metadata.get(STRATEGY_KEY).map(String::as_str),
+        Some("NaiveStrAsDate64")
+    );
+
+    let round_trip = SerdeArrowSchema::from_arrow2_fields(&fields).unwrap();
+    assert_eq!(round_trip, schema);
+}
+
+#[test]
+fn metadata_of_nested_fields() {
+    let field = arrow::datatypes::Field::new(
+        "item",
+        arrow::datatypes::DataType::LargeList(std::sync::Arc::new(
+            arrow::datatypes::Field::new("element", arrow::datatypes::DataType::UInt8, false)
+                .with_metadata(HashMap::from([(String::from("unit"), String::from("kg"))])),
+        )),
+        false,
+    );
+
+    let generic_field = GenericField::try_from(&field).unwrap();
+    assert_eq!(
+        generic_field.children[0].metadata,
+        BTreeMap::from([(String::from("unit"), String::from("kg"))])
+    );
+    assert_eq!(
+        arrow::datatypes::Field::try_from(&generic_field).unwrap(),
+        field
+    );
+
+    let arrow2_field = arrow2::datatypes::Field::try_from(&generic_field).unwrap();
+    assert_eq!(
+        GenericField::try_from(&arrow2_field).unwrap(),
+        generic_field
+    );
+}
+
+#[test]
+fn metadata_does_not_affect_serialization() {
+    let items = [Item(1.5_f64), Item(2.0)];
+
+    Test::new()
+        .with_schema(json!([{
+            "name": "item",
+            "data_type": "F64",
+            "metadata": {"unit": "km"},
+        }]))
+        .serialize(&items)
+        .deserialize(&items)
+        .check_nulls(&[&[false, false]]);
+}
+
+#[test]
+fn reserved_keys_are_rejected() {
+    GenericField::new("item", GenericDataType::Date64, false)
+        .with_metadata(STRATEGY_KEY, "NaiveStrAsDate64")
+        .validate()
+        .assert_error("reserved key");
+}
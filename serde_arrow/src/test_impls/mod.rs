@@ -1,13 +1,21 @@
 mod utils;
 
+mod binary;
 mod chrono;
 mod dictionary;
+mod duration;
 mod examples;
+mod extension;
+mod fixed_size_list;
+mod int128;
+mod interval;
 mod json_values;
 mod list;
 mod macros;
 mod map;
+mod metadata;
 mod primitives;
+mod run_end_encoded;
 mod r#struct;
 mod tuple;
 mod r#union;
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    _impl::arrow::{
+        array::{Array, ArrayRef, PrimitiveArray, RunArray, StringArray},
+        datatypes::{DataType, Field, Int32Type, UInt32Type},
+    },
+    internal::schema::{GenericDataType, GenericField},
+    utils::{Item, Items},
+};
+
+fn run_end_encoded_field(
+    name: &str,
+    run_ends: GenericDataType,
+    values: GenericDataType,
+    nullable: bool,
+) -> GenericField {
+    GenericField::new(name, GenericDataType::RunEndEncoded, nullable)
+        .with_child(GenericField::new("run_ends", run_ends, false))
+        .with_child(GenericField::new("values", values, nullable))
+}
+
+fn get_run_array(array: &ArrayRef) -> &RunArray<Int32Type> {
+    array
+        .as_any()
+        .downcast_ref::<RunArray<Int32Type>>()
+        .expect("expected a RunArray<Int32Type>")
+}
+
+#[test]
+fn consecutive_values_are_collapsed() {
+    let field = run_end_encoded_field("item", GenericDataType::I32, GenericDataType::U32, false);
+    let fields = vec![Field::try_from(&field).unwrap()];
+
+    let items = [1_u32, 1, 1, 2, 2, 1];
+    let arrays = crate::to_arrow(&fields, &Items(&items)).unwrap();
+
+    assert_eq!(arrays[0].data_type(), fields[0].data_type());
+    assert_eq!(arrays[0].len(), 6);
+
+    let array = get_run_array(&arrays[0]);
+    assert_eq!(array.run_ends().values(), &[3, 5, 6]);
+
+    let values = array
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<UInt32Type>>()
+        .unwrap();
+    assert_eq!(values.values(), &[1, 2, 1]);
+
+    let round_trip: Vec<Item<u32>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items.map(Item));
+}
+
+#[test]
+fn nullable_values() {
+    let field = run_end_encoded_field(
+        "item",
+        GenericDataType::I32,
+        GenericDataType::LargeUtf8,
+        true,
+    );
+    let fields = vec![Field::try_from(&field).unwrap()];
+
+    let items = [
+        Some(String::from("a")),
+        Some(String::from("a")),
+        None,
+        None,
+        None,
+        Some(String::from("b")),
+        None,
+    ];
+    let arrays = crate::to_arrow(&fields, &Items(&items)).unwrap();
+
+    let array = get_run_array(&arrays[0]);
+    assert_eq!(array.run_ends().values(), &[2, 5, 6, 7]);
+    assert_eq!(array.values().null_count(), 2);
+
+    let round_trip: Vec<Item<Option<String>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items.map(Item));
+}
+
+#[test]
+fn run_end_encoded_in_nullable_struct() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        status: String,
+        value: u32,
+    }
+
+    let field = GenericField::new("item", GenericDataType::Struct, true)
+        .with_child(run_end_encoded_field(
+            "status",
+            GenericDataType::I16,
+            GenericDataType::Utf8,
+            false,
+        ))
+        .with_child(GenericField::new("value", GenericDataType::U32, false));
+    let fields = vec![Field::try_from(&field).unwrap()];
+
+    let items = [
+        Some(Record {
+            status: String::from("ok"),
+            value: 1,
+        }),
+        None,
+        Some(Record {
+            status: String::from("ok"),
+            value: 2,
+        }),
+        Some(Record {
+            status: String::from("error"),
+            value: 3,
+        }),
+    ];
+    let arrays = crate::to_arrow(&fields, &Items(&items)).unwrap();
+    let round_trip: Vec<Item<Option<Record>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    assert_eq!(round_trip, items.map(Item));
+}
+
+#[test]
+fn arrays_created_by_arrow_are_expanded() {
+    let run_ends = PrimitiveArray::<Int32Type>::from(vec![2, 3, 6]);
+    let values = StringArray::from(vec![Some("foo"), None, Some("bar")]);
+    let array = RunArray::try_new(&run_ends, &values).unwrap();
+
+    let fields = vec![Field::new("item", array.data_type().clone(), true)];
+    let arrays: Vec<ArrayRef> = vec![std::sync::Arc::new(array)];
+
+    let actual: Vec<Item<Option<String>>> = crate::from_arrow(&fields, &arrays).unwrap();
+    let expected = vec![
+        Item(Some(String::from("foo"))),
+        Item(Some(String::from("foo"))),
+        Item(None),
+        Item(Some(String::from("bar"))),
+        Item(Some(String::from("bar"))),
+        Item(Some(String::from("bar"))),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn schema_round_trip() {
+    let field = run_end_encoded_field("item", GenericDataType::I64, GenericDataType::F64, true);
+    let arrow_field = Field::try_from(&field).unwrap();
+    assert!(matches!(
+        arrow_field.data_type(),
+        DataType::RunEndEncoded(_, _)
+    ));
+
+    let round_trip = GenericField::try_from(&arrow_field).unwrap();
+    assert_eq!(round_trip, field);
+}
+
+#[test]
+fn run_ends_out_of_range() {
+    let field = run_end_encoded_field("item", GenericDataType::I16, GenericDataType::U8, false);
+    let fields = vec![Field::try_from(&field).unwrap()];
+
+    let items = (0..40_000_u32).map(|i| (i % 2) as u8).collect::<Vec<_>>();
+    let res = crate::to_arrow(&fields, &Items(&items));
+    super::macros::expect_error(&res, "exceeds the range of Int16");
+}
+
+#[test]
+fn invalid_fields() {
+    for (field, expected) in [
+        (
+            run_end_encoded_field("item", GenericDataType::U32, GenericDataType::U8, false),
+            "Expected I16, I32 or I64 run ends",
+        ),
+        (
+            run_end_encoded_field("item", GenericDataType::I32, GenericDataType::List, false),
+            "Expected non-nested values",
+        ),
+        (
+            GenericField::new("item", GenericDataType::RunEndEncoded, false)
+                .with_child(GenericField::new("run_ends", GenericDataType::I32, true))
+                .with_child(GenericField::new("values", GenericDataType::U8, false)),
+            "run ends must not be nullable",
+        ),
+    ] {
+        super::macros::expect_error(&field.validate(), expected);
+    }
+}
+
+#[test]
+fn arrow2_is_not_supported() {
+    use crate::_impl::arrow2::datatypes::Field;
+
+    let field = run_end_encoded_field("item", GenericDataType::I32, GenericDataType::U8, false);
+    let res = Field::try_from(&field);
+    super::macros::expect_error(&res, "not supported by arrow2");
+}
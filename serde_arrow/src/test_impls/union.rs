@@ -3,10 +3,14 @@ use super::macros::{test_example, test_generic};
 test_example!(
     test_name = fieldless_unions,
     tracing_options = TracingOptions::default().allow_null_fields(true),
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("A", GenericDataType::Null, true))
-        .with_child(GenericField::new("B", GenericDataType::Null, true))
-        .with_child(GenericField::new("C", GenericDataType::Null, true)),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("A", GenericDataType::Null, true))
+    .with_child(GenericField::new("B", GenericDataType::Null, true))
+    .with_child(GenericField::new("C", GenericDataType::Null, true)),
     ty = U,
     values = [U::A, U::B, U::C, U::A,],
     nulls = [false, false, false, false],
@@ -23,10 +27,14 @@ test_example!(
 test_example!(
     test_name = fieldless_union_out_of_order,
     tracing_options = TracingOptions::default().allow_null_fields(true),
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("A", GenericDataType::Null, true))
-        .with_child(GenericField::new("B", GenericDataType::Null, true))
-        .with_child(GenericField::new("C", GenericDataType::Null, true)),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("A", GenericDataType::Null, true))
+    .with_child(GenericField::new("B", GenericDataType::Null, true))
+    .with_child(GenericField::new("C", GenericDataType::Null, true)),
     ty = U,
     values = [U::B, U::A, U::C],
     nulls = [false, false, false],
@@ -42,10 +50,14 @@ test_example!(
 
 test_example!(
     test_name = union_simple,
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("U32", GenericDataType::U32, false))
-        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
-        .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false)),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+    .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false)),
     ty = U,
     values = [
         U::U32(32),
@@ -65,18 +77,24 @@ test_example!(
 
 test_example!(
     test_name = union_mixed,
-    field =
-        GenericField::new("item", GenericDataType::Union, false)
-            .with_child(
-                GenericField::new("V1", GenericDataType::Struct, false)
-                    .with_child(GenericField::new("a", GenericDataType::U32, false))
-                    .with_child(GenericField::new("b", GenericDataType::U64, false))
-            )
-            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
-            .with_child(
-                GenericField::new("S", GenericDataType::Struct, false)
-                    .with_child(GenericField::new("s", GenericDataType::LargeUtf8, false))
-            ),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(
+        GenericField::new("V1", GenericDataType::Struct, false)
+            .with_child(GenericField::new("a", GenericDataType::U32, false))
+            .with_child(GenericField::new("b", GenericDataType::U64, false))
+    )
+    .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+    .with_child(
+        GenericField::new("S", GenericDataType::Struct, false).with_child(GenericField::new(
+            "s",
+            GenericDataType::LargeUtf8,
+            false
+        ))
+    ),
     ty = U,
     values = [
         U::V1 { a: 32, b: 13 },
@@ -103,13 +121,17 @@ test_example!(
 
 test_example!(
     test_name = union_nested,
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("U32", GenericDataType::U32, false))
-        .with_child(
-            GenericField::new("O", GenericDataType::Union, false)
-                .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
-                .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
-        ),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(
+        GenericField::new("O", GenericDataType::Union(GenericUnionMode::Dense), false)
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    ),
     ty = U,
     values = [
         U::U32(32),
@@ -135,11 +157,15 @@ test_example!(
 
 test_example!(
     test_name = enums,
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("U8", GenericDataType::U8, false))
-        .with_child(GenericField::new("U16", GenericDataType::U16, false))
-        .with_child(GenericField::new("U32", GenericDataType::U32, false))
-        .with_child(GenericField::new("U64", GenericDataType::U64, false)),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U8", GenericDataType::U8, false))
+    .with_child(GenericField::new("U16", GenericDataType::U16, false))
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(GenericField::new("U64", GenericDataType::U64, false)),
     ty = Item,
     values = [Item::U32(2), Item::U64(3), Item::U8(0), Item::U16(1),],
     define = {
@@ -155,19 +181,23 @@ test_example!(
 
 test_example!(
     test_name = enums_tuple,
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(
-            GenericField::new("A", GenericDataType::Struct, false)
-                .with_strategy(Strategy::TupleAsStruct)
-                .with_child(GenericField::new("0", GenericDataType::U8, false))
-                .with_child(GenericField::new("1", GenericDataType::U32, false))
-        )
-        .with_child(
-            GenericField::new("B", GenericDataType::Struct, false)
-                .with_strategy(Strategy::TupleAsStruct)
-                .with_child(GenericField::new("0", GenericDataType::U16, false))
-                .with_child(GenericField::new("1", GenericDataType::U64, false))
-        ),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(
+        GenericField::new("A", GenericDataType::Struct, false)
+            .with_strategy(Strategy::TupleAsStruct)
+            .with_child(GenericField::new("0", GenericDataType::U8, false))
+            .with_child(GenericField::new("1", GenericDataType::U32, false))
+    )
+    .with_child(
+        GenericField::new("B", GenericDataType::Struct, false)
+            .with_strategy(Strategy::TupleAsStruct)
+            .with_child(GenericField::new("0", GenericDataType::U16, false))
+            .with_child(GenericField::new("1", GenericDataType::U64, false))
+    ),
     ty = Item,
     values = [Item::A(2, 3), Item::B(0, 1),],
     define = {
@@ -181,17 +211,21 @@ test_example!(
 
 test_example!(
     test_name = enums_struct,
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(
-            GenericField::new("A", GenericDataType::Struct, false)
-                .with_child(GenericField::new("a", GenericDataType::U8, false))
-                .with_child(GenericField::new("b", GenericDataType::U32, false))
-        )
-        .with_child(
-            GenericField::new("B", GenericDataType::Struct, false)
-                .with_child(GenericField::new("c", GenericDataType::U16, false))
-                .with_child(GenericField::new("d", GenericDataType::U64, false))
-        ),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(
+        GenericField::new("A", GenericDataType::Struct, false)
+            .with_child(GenericField::new("a", GenericDataType::U8, false))
+            .with_child(GenericField::new("b", GenericDataType::U32, false))
+    )
+    .with_child(
+        GenericField::new("B", GenericDataType::Struct, false)
+            .with_child(GenericField::new("c", GenericDataType::U16, false))
+            .with_child(GenericField::new("d", GenericDataType::U64, false))
+    ),
     ty = Item,
     values = [Item::A { a: 2, b: 3 }, Item::B { c: 0, d: 1 },],
     define = {
@@ -206,9 +240,13 @@ test_example!(
 test_example!(
     test_name = enums_union,
     tracing_options = TracingOptions::default().allow_null_fields(true),
-    field = GenericField::new("item", GenericDataType::Union, false)
-        .with_child(GenericField::new("A", GenericDataType::Null, true))
-        .with_child(GenericField::new("B", GenericDataType::Null, true)),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("A", GenericDataType::Null, true))
+    .with_child(GenericField::new("B", GenericDataType::Null, true)),
     ty = Item,
     values = [Item::A, Item::B,],
     define = {
@@ -240,3 +278,602 @@ test_generic!(
         crate::test_impls::macros::expect_error(&res, "Serialization failed: an unknown variant");
     }
 );
+
+test_example!(
+    test_name = sparse_union_simple,
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+    .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false)),
+    overwrite_field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Sparse),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+    .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false)),
+    ty = U,
+    values = [
+        U::U32(32),
+        U::Bool(true),
+        U::Str(String::from("hello world")),
+        U::U32(16),
+        U::Bool(false),
+    ],
+    nulls = [false, false, false, false, false],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            Bool(bool),
+            Str(String),
+        }
+    },
+);
+
+test_example!(
+    test_name = sparse_union_mixed,
+    field =
+        GenericField::new(
+            "item",
+            GenericDataType::Union(GenericUnionMode::Dense),
+            false
+        )
+        .with_child(
+            GenericField::new("V1", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::U32, false))
+                .with_child(GenericField::new("b", GenericDataType::U64, false))
+        )
+        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+        .with_child(
+            GenericField::new("List", GenericDataType::LargeList, false)
+                .with_child(GenericField::new("element", GenericDataType::U8, false))
+        ),
+    overwrite_field =
+        GenericField::new(
+            "item",
+            GenericDataType::Union(GenericUnionMode::Sparse),
+            false
+        )
+        .with_child(
+            GenericField::new("V1", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::U32, false))
+                .with_child(GenericField::new("b", GenericDataType::U64, false))
+        )
+        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+        .with_child(
+            GenericField::new("List", GenericDataType::LargeList, false)
+                .with_child(GenericField::new("element", GenericDataType::U8, false))
+        ),
+    ty = U,
+    values = [
+        U::V1 { a: 32, b: 13 },
+        U::Bool(true),
+        U::List(vec![1, 2, 3]),
+        U::Bool(false),
+        U::V1 { a: 4, b: 2 },
+        U::List(vec![4]),
+    ],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            V1 { a: u32, b: u64 },
+            Bool(bool),
+            List(Vec<u8>),
+        }
+    },
+);
+
+test_example!(
+    test_name = sparse_union_nested,
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(
+        GenericField::new("O", GenericDataType::Union(GenericUnionMode::Dense), false)
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    ),
+    overwrite_field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Sparse),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(
+        GenericField::new("O", GenericDataType::Union(GenericUnionMode::Sparse), false)
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    ),
+    ty = U,
+    values = [
+        U::U32(32),
+        U::O(O::Bool(true)),
+        U::O(O::Str(String::from("hello world"))),
+        U::U32(16),
+        U::O(O::Str(String::from("foo"))),
+    ],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            O(O),
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum O {
+            Bool(bool),
+            Str(String),
+        }
+    },
+);
+
+test_example!(
+    test_name = sparse_union_in_dense_union,
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(
+        GenericField::new("O", GenericDataType::Union(GenericUnionMode::Dense), false)
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    ),
+    overwrite_field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(
+        GenericField::new("O", GenericDataType::Union(GenericUnionMode::Sparse), false)
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    ),
+    ty = U,
+    values = [
+        U::O(O::Str(String::from("hello world"))),
+        U::U32(32),
+        U::O(O::Bool(true)),
+        U::O(O::Str(String::from("foo"))),
+    ],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            O(O),
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum O {
+            Bool(bool),
+            Str(String),
+        }
+    },
+);
+
+test_example!(
+    test_name = sparse_fieldless_unions,
+    tracing_options = TracingOptions::default().allow_null_fields(true),
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("A", GenericDataType::Null, true))
+    .with_child(GenericField::new("B", GenericDataType::Null, true))
+    .with_child(GenericField::new("C", GenericDataType::Null, true)),
+    overwrite_field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Sparse),
+        false
+    )
+    .with_child(GenericField::new("A", GenericDataType::Null, true))
+    .with_child(GenericField::new("B", GenericDataType::Null, true))
+    .with_child(GenericField::new("C", GenericDataType::Null, true)),
+    ty = U,
+    values = [U::A, U::B, U::C, U::A,],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A,
+            B,
+            C,
+        }
+    },
+);
+
+test_generic!(
+    fn sparse_union_with_unknown_variants() {
+        use crate::schema::TracingOptions;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A(u8),
+            B(u16),
+            C(u32),
+        }
+
+        let fields =
+            Vec::<Field>::from_samples(&Items(&[U::A(1), U::C(3)]), TracingOptions::default())
+                .unwrap();
+        let field = GenericField {
+            data_type: GenericDataType::Union(GenericUnionMode::Sparse),
+            ..GenericField::try_from(&fields[0]).unwrap()
+        };
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items = [U::A(1), U::C(3), U::A(2)];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+        assert_eq!(arrays[0].len(), 3);
+
+        let Items(round_trip): Items<Vec<U>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(round_trip, items);
+
+        // NOTE: variant B was never encountered during tracing
+        let res = to_arrow(&fields, &Items(&[U::A(1), U::B(2)]));
+        crate::test_impls::macros::expect_error(&res, "Serialization failed: an unknown variant");
+    }
+);
+
+test_generic!(
+    fn dense_union_defaults_of_unknown_variants() {
+        use crate::schema::Strategy;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct S {
+            u: U,
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A,
+            B(u8),
+        }
+
+        let field = GenericField::new("item", GenericDataType::Struct, true).with_child(
+            GenericField::new("u", GenericDataType::Union(GenericUnionMode::Dense), false)
+                .with_child(
+                    GenericField::new("A", GenericDataType::Null, true)
+                        .with_strategy(Strategy::UnknownVariant),
+                )
+                .with_child(GenericField::new("B", GenericDataType::U8, false)),
+        );
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[Some(S { u: U::B(1) })]));
+        assert!(res.is_ok(), "{res:?}");
+
+        // the default of a dense union is written to its first variant
+        let res = to_arrow(&fields, &Items(&[Some(S { u: U::B(1) }), None]));
+        crate::test_impls::macros::expect_error(&res, "Serialization failed: an unknown variant");
+    }
+);
+
+test_example!(
+    test_name = nullable_union,
+    field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Dense), true)
+        .with_child(GenericField::new("U32", GenericDataType::U32, false))
+        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    ty = Option<U>,
+    values = [Some(U::U32(32)), None, Some(U::Bool(true)), None, Some(U::U32(16))],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            Bool(bool),
+        }
+    },
+);
+
+test_example!(
+    test_name = nullable_fieldless_union,
+    tracing_options = TracingOptions::default().allow_null_fields(true),
+    field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Dense), true)
+        .with_child(GenericField::new("A", GenericDataType::Null, true))
+        .with_child(GenericField::new("B", GenericDataType::Null, true))
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    ty = Option<U>,
+    values = [Some(U::A), None, Some(U::B), None],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A,
+            B,
+        }
+    },
+);
+
+test_example!(
+    test_name = nullable_sparse_union,
+    field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Dense), true)
+        .with_child(GenericField::new("U32", GenericDataType::U32, false))
+        .with_child(
+            GenericField::new("S", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::Bool, false))
+        )
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    overwrite_field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Sparse), true)
+        .with_child(GenericField::new("U32", GenericDataType::U32, false))
+        .with_child(
+            GenericField::new("S", GenericDataType::Struct, false)
+                .with_child(GenericField::new("a", GenericDataType::Bool, false))
+        )
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    ty = Option<U>,
+    values = [None, Some(U::U32(32)), None, Some(U::S { a: true }), Some(U::U32(16))],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            S { a: bool },
+        }
+    },
+);
+
+test_example!(
+    test_name = nullable_union_in_struct,
+    field = GenericField::new("item", GenericDataType::Struct, false)
+        .with_child(GenericField::new("a", GenericDataType::U8, false))
+        .with_child(
+            GenericField::new("b", GenericDataType::Union(GenericUnionMode::Dense), true)
+                .with_child(GenericField::new("U32", GenericDataType::U32, false))
+                .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+                .with_child(
+                    GenericField::new("null", GenericDataType::Null, true)
+                        .with_strategy(Strategy::NullVariant)
+                )
+        ),
+    ty = S,
+    values = [
+        S { a: 1, b: None },
+        S {
+            a: 2,
+            b: Some(U::Str(String::from("foo")))
+        },
+        S {
+            a: 3,
+            b: Some(U::U32(13))
+        },
+        S { a: 4, b: None },
+    ],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct S {
+            a: u8,
+            b: Option<U>,
+        }
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            Str(String),
+        }
+    },
+);
+
+test_generic!(
+    fn nullable_union_without_null_variant() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A(u8),
+            B(u16),
+        }
+
+        let field = GenericField::new(
+            "item",
+            GenericDataType::Union(GenericUnionMode::Dense),
+            true,
+        )
+        .with_child(GenericField::new("A", GenericDataType::U8, false))
+        .with_child(GenericField::new("B", GenericDataType::U16, false));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let res = to_arrow(&fields, &Items(&[Some(U::A(1)), None]));
+        crate::test_impls::macros::expect_error(&res, "without null variant");
+    }
+);
+
+test_example!(
+    test_name = nullable_union_with_trailing_unit_variant,
+    tracing_options = TracingOptions::default().allow_null_fields(true),
+    field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Dense), true)
+        .with_child(GenericField::new("A", GenericDataType::U8, false))
+        .with_child(GenericField::new("B", GenericDataType::Null, true))
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    ty = Option<U>,
+    values = [Some(U::A(1)), Some(U::B), None, Some(U::B)],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A(u8),
+            B,
+        }
+    },
+);
+
+test_generic!(
+    fn trailing_null_child_without_strategy_is_a_variant() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            A(u8),
+            B,
+        }
+
+        let field = GenericField::new(
+            "item",
+            GenericDataType::Union(GenericUnionMode::Dense),
+            true,
+        )
+        .with_child(GenericField::new("A", GenericDataType::U8, false))
+        .with_child(GenericField::new("B", GenericDataType::Null, true));
+        let fields = vec![Field::try_from(&field).unwrap()];
+
+        let items = [Some(U::A(1)), Some(U::B), Some(U::B)];
+        let arrays = to_arrow(&fields, &Items(&items)).unwrap();
+
+        let Items(round_trip): Items<Vec<Option<U>>> = from_arrow(&fields, &arrays).unwrap();
+        assert_eq!(round_trip, items);
+
+        let res = to_arrow(&fields, &Items(&[Some(U::B), None]));
+        crate::test_impls::macros::expect_error(&res, "without null variant");
+    }
+);
+
+test_example!(
+    test_name = union_with_type_ids,
+    field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false
+    )
+    .with_child(GenericField::new("U32", GenericDataType::U32, false))
+    .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+    .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false)),
+    overwrite_field = GenericField {
+        type_ids: Some(vec![5, 2, 7]),
+        ..GenericField::new(
+            "item",
+            GenericDataType::Union(GenericUnionMode::Dense),
+            false
+        )
+        .with_child(GenericField::new("U32", GenericDataType::U32, false))
+        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+        .with_child(GenericField::new("Str", GenericDataType::LargeUtf8, false))
+    },
+    ty = U,
+    values = [
+        U::U32(32),
+        U::Bool(true),
+        U::Str(String::from("hello world")),
+        U::U32(16),
+    ],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            Bool(bool),
+            Str(String),
+        }
+    },
+);
+
+test_example!(
+    test_name = nullable_sparse_union_with_type_ids,
+    field = GenericField::new("item", GenericDataType::Union(GenericUnionMode::Dense), true)
+        .with_child(GenericField::new("U32", GenericDataType::U32, false))
+        .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+        .with_child(
+            GenericField::new("null", GenericDataType::Null, true)
+                .with_strategy(Strategy::NullVariant)
+        ),
+    overwrite_field = GenericField {
+        type_ids: Some(vec![3, 1, 0]),
+        ..GenericField::new("item", GenericDataType::Union(GenericUnionMode::Sparse), true)
+            .with_child(GenericField::new("U32", GenericDataType::U32, false))
+            .with_child(GenericField::new("Bool", GenericDataType::Bool, false))
+            .with_child(
+                GenericField::new("null", GenericDataType::Null, true)
+                    .with_strategy(Strategy::NullVariant)
+            )
+    },
+    ty = Option<U>,
+    values = [Some(U::Bool(false)), None, Some(U::U32(32)), None, Some(U::Bool(true))],
+    define = {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        enum U {
+            U32(u32),
+            Bool(bool),
+        }
+    },
+);
+
+test_generic!(
+    fn union_type_ids_are_kept_in_schema() {
+        let field = GenericField {
+            type_ids: Some(vec![5, 2]),
+            ..GenericField::new(
+                "item",
+                GenericDataType::Union(GenericUnionMode::Dense),
+                false,
+            )
+            .with_child(GenericField::new("A", GenericDataType::U8, false))
+            .with_child(GenericField::new("B", GenericDataType::U16, false))
+        };
+        let arrow_field = Field::try_from(&field).unwrap();
+        let round_trip = GenericField::try_from(&arrow_field).unwrap();
+        assert_eq!(round_trip, field);
+
+        // contiguous type ids are equivalent to no type ids
+        let field = GenericField {
+            type_ids: Some(vec![0, 1]),
+            ..field
+        };
+        let arrow_field = Field::try_from(&field).unwrap();
+        let round_trip = GenericField::try_from(&arrow_field).unwrap();
+        assert_eq!(round_trip.type_ids, None);
+    }
+);
+
+#[test]
+fn invalid_union_type_ids() {
+    use crate::internal::schema::{GenericDataType, GenericField, GenericUnionMode};
+
+    let field = GenericField::new(
+        "item",
+        GenericDataType::Union(GenericUnionMode::Dense),
+        false,
+    )
+    .with_child(GenericField::new("A", GenericDataType::U8, false))
+    .with_child(GenericField::new("B", GenericDataType::U16, false));
+
+    for (type_ids, expected) in [
+        (vec![0], "invalid number of type ids"),
+        (vec![1, -1], "must not be negative"),
+        (vec![3, 3], "duplicate type id 3"),
+    ] {
+        let field = GenericField {
+            type_ids: Some(type_ids),
+            ..field.clone()
+        };
+        super::macros::expect_error(&field.validate(), expected);
+    }
+
+    let field = GenericField {
+        type_ids: Some(vec![0]),
+        ..GenericField::new("item", GenericDataType::U8, false)
+    };
+    super::macros::expect_error(&field.validate(), "only supported for Union fields");
+}